@@ -106,6 +106,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::identity_op)]
     fn special() {
         assert_eq!(Intents::privileged().0, 1 << 1 | 1 << 8);
         assert_eq!(Intents::non_privileged().0, !(u64::MAX & (1 << 1 | 1 << 8))); // !(a intersect b) == (a bitxor b) (in this case)
@@ -1,14 +1,11 @@
-use reqwest::{
-    header::{HeaderMap, HeaderValue},
-    ClientBuilder,
-};
+use std::sync::Arc;
 
-///! todo!
+// todo!
 use self::{
     intents::Intents,
     state::{Build, Starting, State},
 };
-use crate::error::Error;
+use crate::{collector::Events, commands::Commands, error::Error, http::Http};
 
 /// Gateway intents that are used when establishing a client connection with discord.
 /// These determine what events the bot recieves and some other ways data works.
@@ -23,6 +20,9 @@ where
     S: State,
 {
     state: S,
+    http: Option<Arc<Http>>,
+    events: Events,
+    commands: Commands,
 }
 
 impl Default for Client<Build> {
@@ -36,6 +36,9 @@ impl Client<Build> {
     pub fn new() -> Self {
         Client {
             state: Build::default(),
+            http: None,
+            events: Events::new(),
+            commands: Commands::new(),
         }
     }
     /// Sets the token for the client's connection.
@@ -54,22 +57,48 @@ impl Client<Build> {
         self
     }
 
+    /// Sets the commands of the bot, which are synced with discord when the client starts.
+    pub fn commands(&mut self, commands: Commands) -> &mut Self {
+        self.commands = commands;
+        self
+    }
+
     pub async fn start(self) -> Result<Client<Starting>, Error> {
-        Ok(Client::<Starting>::new(self.state.start()?).await?)
+        let mut client = Client::<Starting>::new(self.state.start()?).await?;
+        // collectors made before starting keep working
+        client.events = self.events;
+        if !self.commands.is_empty() {
+            if let Some(http) = &client.http {
+                self.commands.sync(http).await?;
+            }
+        }
+        client.commands = self.commands;
+        Ok(client)
     }
 }
 
 impl Client<Starting> {
     pub async fn new(state: Starting) -> Result<Self, Error> {
-        let builder = ClientBuilder::new().default_headers({
-            let mut x = HeaderMap::new();
-            x.insert(
-                "Authorization",
-                HeaderValue::from_str(&state.token)
-                    .map_err(|_errr| Error::ConfigurationError("Invalid token passed."))?,
-            );
-            x
-        });
-        Ok(Self { state })
+        let http = Http::new(&state.token)?;
+        Ok(Self {
+            state,
+            http: Some(Arc::new(http)),
+            events: Events::new(),
+            commands: Commands::new(),
+        })
+    }
+}
+
+impl<S> Client<S>
+where
+    S: State,
+{
+    /// The http client used to make requests to discord, once the client has been started.
+    pub fn http(&self) -> Option<&Arc<Http>> {
+        self.http.as_ref()
+    }
+    /// The events received by the client, which collectors wait on.
+    pub fn events(&self) -> &Events {
+        &self.events
     }
 }
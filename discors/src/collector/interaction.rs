@@ -0,0 +1,304 @@
+use std::{
+    fmt::{self, Debug},
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use futures::Stream;
+use tokio::{
+    sync::mpsc,
+    time::{timeout_at, Instant},
+};
+
+use crate::{
+    interactions::{Context, Responder},
+    models::{
+        application::interaction::{Interaction, InteractionType},
+        channel::message::Message,
+        user::User,
+    },
+    snowflake::Snowflake,
+};
+
+type Filter = Box<dyn Fn(&Interaction) -> bool + Send + Sync>;
+
+// what a collector waits for, shared with the context so it can be changed after the collector is registered
+pub(crate) struct Criteria {
+    target: Target,
+    custom_ids: Vec<String>,
+    users: Vec<Snowflake<User>>,
+    filter: Option<Filter>,
+    // after which interactions go to the handler, even if the collector is not being polled
+    deadline: Option<Instant>,
+}
+
+enum Target {
+    // the components of a message
+    Message(Snowflake<Message>),
+    // a modal, by its custom id
+    Modal(String),
+}
+
+impl Criteria {
+    pub(crate) fn is_expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| deadline <= Instant::now())
+    }
+    pub(crate) fn matches(&self, interaction: &Interaction) -> bool {
+        let custom_id = match (&self.target, interaction.kind) {
+            (Target::Message(message), InteractionType::MessageComponent)
+                if interaction.message.as_ref().map(|m| m.id) == Some(*message) =>
+            {
+                interaction.component().map(|data| &data.custom_id)
+            }
+            (Target::Modal(custom_id), InteractionType::ModalSubmit) => interaction
+                .modal_submit()
+                .map(|data| &data.custom_id)
+                .filter(|id| *id == custom_id),
+            _ => None,
+        };
+        custom_id.is_some_and(|id| self.custom_ids.is_empty() || self.custom_ids.contains(id))
+            && (self.users.is_empty()
+                || interaction
+                    .user()
+                    .is_some_and(|user| self.users.contains(&user.id)))
+            && self.filter.as_ref().is_none_or(|f| f(interaction))
+    }
+}
+
+// A collector registered with a context, which interactions are given to instead of the handler.
+pub(crate) struct Waiter {
+    pub(crate) criteria: Arc<Mutex<Criteria>>,
+    pub(crate) sender: mpsc::UnboundedSender<Responder>,
+}
+
+/// Collects the component interactions of a message, or the submissions of a modal, which can be filtered by custom id and user.
+///
+/// The collected interactions are not given to the [`InteractionHandler`](crate::interactions::InteractionHandler),
+/// and have to be responded to by the collector. Only interactions received after the collector is created are collected.
+///
+/// ```rust,no_run
+/// # async fn run(context: discors::interactions::Context, message: discors::Snowflake<discors::models::channel::message::Message>, user: discors::Snowflake<discors::models::user::User>) -> Result<(), discors::error::Error> {
+/// use std::time::Duration;
+///
+/// use discors::models::{
+///     application::interaction::InteractionResponse, channel::message::EditMessage,
+/// };
+///
+/// let click = context
+///     .components(message)
+///     .custom_id("confirm")
+///     .custom_id("cancel")
+///     .user(user)
+///     .timeout(Duration::from_secs(30))
+///     .next()
+///     .await;
+/// if let Some(click) = click {
+///     let confirmed = click.interaction().component().unwrap().custom_id == "confirm";
+///     let content = if confirmed { "Confirmed" } else { "Cancelled" };
+///     let edit = EditMessage::new().content(content).components(Vec::new());
+///     click.respond(InteractionResponse::update(edit)).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct InteractionCollector {
+    receiver: mpsc::UnboundedReceiver<Responder>,
+    criteria: Arc<Mutex<Criteria>>,
+    remaining: Option<usize>,
+}
+
+impl Debug for InteractionCollector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InteractionCollector")
+            .field("deadline", &self.criteria().deadline)
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+impl InteractionCollector {
+    /// Creates a collector for the components of a message.
+    pub fn components(context: &Context, message: Snowflake<Message>) -> Self {
+        Self::new(context, Target::Message(message))
+    }
+    /// Creates a collector for the submissions of the modal with a custom id.
+    pub fn modal(context: &Context, custom_id: impl Into<String>) -> Self {
+        Self::new(context, Target::Modal(custom_id.into()))
+    }
+    fn new(context: &Context, target: Target) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let criteria = Arc::new(Mutex::new(Criteria {
+            target,
+            custom_ids: Vec::new(),
+            users: Vec::new(),
+            filter: None,
+            deadline: None,
+        }));
+        context.register(Waiter {
+            criteria: criteria.clone(),
+            sender,
+        });
+        Self {
+            receiver,
+            criteria,
+            remaining: None,
+        }
+    }
+    fn criteria(&self) -> std::sync::MutexGuard<'_, Criteria> {
+        self.criteria.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    /// Only collects components with a custom id, this can be called more than once to allow more components.
+    pub fn custom_id(self, custom_id: impl Into<String>) -> Self {
+        self.criteria().custom_ids.push(custom_id.into());
+        self
+    }
+    /// Only collects interactions by a user, this can be called more than once to allow more users.
+    pub fn user(self, user: Snowflake<User>) -> Self {
+        self.criteria().users.push(user);
+        self
+    }
+    /// Only collects interactions the filter gives `true` for.
+    pub fn filter(self, filter: impl Fn(&Interaction) -> bool + Send + Sync + 'static) -> Self {
+        self.criteria().filter = Some(Box::new(filter));
+        self
+    }
+    /// Stops collecting after a duration, from when this is called.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.criteria().deadline = Some(Instant::now() + timeout);
+        self
+    }
+    /// Stops collecting after an amount of interactions.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.remaining = Some(limit);
+        self
+    }
+
+    /// Waits for the next interaction, `None` when the collector timed out or reached its limit.
+    pub async fn next(&mut self) -> Option<Responder> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let deadline = self.criteria().deadline;
+        let responder = match deadline {
+            Some(deadline) => timeout_at(deadline, self.receiver.recv())
+                .await
+                .ok()
+                .flatten(),
+            None => self.receiver.recv().await,
+        };
+        self.remaining = self.remaining.map(|r| r.saturating_sub(1));
+        if responder.is_none() || self.remaining == Some(0) {
+            // later interactions go to the handler again
+            self.receiver.close();
+        }
+        responder
+    }
+    /// Turns the collector into a stream of interactions.
+    pub fn stream(self) -> impl Stream<Item = Responder> {
+        futures::stream::unfold(self, |mut collector| async move {
+            collector.next().await.map(|r| (r, collector))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, time::Duration};
+
+    use crate::{
+        http::Http,
+        interactions::{Context, Responder},
+        models::application::interaction::{test_interaction, Interaction},
+    };
+
+    fn responder(context: &Context, data: serde_json::Value, kind: u8, user: u64) -> Responder {
+        let mut interaction = test_interaction(kind, data);
+        interaction["user"]["id"] = user.to_string().into();
+        interaction["message"] = serde_json::json!({
+            "id": "3", "channel_id": "4", "content": "", "timestamp": "2023-01-01T00:00:00+00:00",
+            "edited_timestamp": null, "tts": false, "mention_everyone": false, "mentions": [],
+            "mention_roles": [], "attachments": [], "embeds": [], "pinned": false, "type": 0,
+            "author": {"id": "5", "username": "bot", "discriminator": "0", "avatar": null},
+        });
+        let interaction = serde_json::from_value::<Interaction>(interaction).unwrap();
+        Responder::new(context.http().clone(), interaction)
+    }
+
+    fn click(context: &Context, custom_id: &str, user: u64) -> Responder {
+        responder(
+            context,
+            serde_json::json!({"custom_id": custom_id, "component_type": 2}),
+            3,
+            user,
+        )
+    }
+
+    #[tokio::test]
+    async fn components() {
+        let context = Context::new(Arc::new(Http::new("token").unwrap()));
+        let mut collector = context
+            .components(3.into())
+            .custom_id("yes")
+            .user(6.into())
+            .limit(1)
+            .timeout(Duration::from_secs(5));
+        // another user and another button go to the handler
+        assert!(context.route(click(&context, "yes", 7)).is_some());
+        assert!(context.route(click(&context, "no", 6)).is_some());
+        assert!(context.route(click(&context, "yes", 6)).is_none());
+        let collected = collector.next().await.unwrap();
+        assert_eq!(
+            collected.interaction().component().unwrap().custom_id,
+            "yes"
+        );
+        // the limit was reached, so the collector stopped claiming interactions
+        assert!(collector.next().await.is_none());
+        assert!(context.route(click(&context, "yes", 6)).is_some());
+    }
+
+    #[tokio::test]
+    async fn modal() {
+        let context = Context::new(Arc::new(Http::new("token").unwrap()));
+        let mut collector = context.modal("form").timeout(Duration::from_millis(50));
+        let submit = |custom_id| {
+            responder(
+                &context,
+                serde_json::json!({"custom_id": custom_id, "components": []}),
+                5,
+                6,
+            )
+        };
+        assert!(context.route(submit("other")).is_some());
+        assert!(context.route(submit("form")).is_none());
+        assert!(collector.next().await.is_some());
+        assert!(collector.next().await.is_none());
+        // dropped collectors are removed
+        drop(collector);
+        assert!(context.route(submit("form")).is_some());
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        let context = Context::new(Arc::new(Http::new("token").unwrap()));
+        let mut collector = context
+            .components(3.into())
+            .timeout(Duration::from_millis(10));
+        assert!(collector.next().await.is_none());
+        // the collector is still alive, but interactions after its timeout go to the handler
+        assert!(context.route(click(&context, "yes", 6)).is_some());
+    }
+
+    #[tokio::test]
+    async fn timeout_without_polling() {
+        let context = Context::new(Arc::new(Http::new("token").unwrap()));
+        let collector = context
+            .components(3.into())
+            .timeout(Duration::from_millis(10));
+        assert!(context.route(click(&context, "yes", 6)).is_none());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        // nothing is waiting on the collector anymore, but it is not dropped either
+        assert!(context.route(click(&context, "yes", 6)).is_some());
+        drop(collector);
+    }
+}
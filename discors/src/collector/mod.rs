@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    time::{timeout_at, Instant},
+};
+
+use crate::models::{channel::message::Message, event::Event};
+
+pub use self::{interaction::InteractionCollector, reaction::ReactionCollector};
+
+pub(crate) use self::interaction::Waiter;
+
+mod interaction;
+mod reaction;
+
+/// A hub which gateway events are dispatched to, collectors subscribe to it to wait for events.
+///
+/// Cloning this gives a handle to the same hub.
+///
+/// ```rust
+/// # #[tokio::main]
+/// # async fn main() {
+/// use discors::{collector::Events, models::event::Event};
+///
+/// let events = Events::new();
+/// let mut receiver = events.subscribe();
+/// events.dispatch(Event::from_dispatch("TYPING_START", serde_json::json!({})).unwrap());
+/// assert!(receiver.recv().await.is_ok());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Events {
+    sender: broadcast::Sender<Arc<Event>>,
+}
+
+impl Default for Events {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Events {
+    /// How many events are kept for subscribers which are behind, older events are skipped for them.
+    pub const CAPACITY: usize = 1024;
+
+    /// Creates a hub with no subscribers.
+    pub fn new() -> Self {
+        Self {
+            sender: broadcast::channel(Self::CAPACITY).0,
+        }
+    }
+    /// Sends an event to every subscriber.
+    pub fn dispatch(&self, event: Event) {
+        // there being no subscribers is not an error
+        let _ = self.sender.send(Arc::new(event));
+    }
+    /// Subscribes to the events dispatched after this is called.
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<Event>> {
+        self.sender.subscribe()
+    }
+    /// Collects the reactions added to a message, see [`ReactionCollector`].
+    pub fn reactions(&self, message: crate::Snowflake<Message>) -> ReactionCollector {
+        ReactionCollector::new(self, message)
+    }
+}
+
+// The part of a collector that waits for events, until it times out or has collected enough.
+#[derive(Debug)]
+pub(crate) struct Listener {
+    receiver: broadcast::Receiver<Arc<Event>>,
+    deadline: Option<Instant>,
+    remaining: Option<usize>,
+}
+
+impl Listener {
+    pub(crate) fn new(events: &Events) -> Self {
+        Self {
+            receiver: events.subscribe(),
+            deadline: None,
+            remaining: None,
+        }
+    }
+    pub(crate) fn timeout(&mut self, timeout: std::time::Duration) {
+        self.deadline = Some(Instant::now() + timeout);
+    }
+    pub(crate) fn limit(&mut self, limit: usize) {
+        self.remaining = Some(limit);
+    }
+
+    // Waits for the next event `f` gives a value for.
+    pub(crate) async fn next<T>(&mut self, mut f: impl FnMut(&Event) -> Option<T>) -> Option<T> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        loop {
+            let event = match self.deadline {
+                Some(deadline) => timeout_at(deadline, self.receiver.recv()).await.ok()?,
+                None => self.receiver.recv().await,
+            };
+            match event {
+                Ok(event) => {
+                    if let Some(value) = f(&event) {
+                        self.remaining = self.remaining.map(|r| r - 1);
+                        return Some(value);
+                    }
+                }
+                // events missed while behind can't be collected anymore
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    fmt::{self, Debug},
+    time::Duration,
+};
+
+use futures::Stream;
+
+use super::{Events, Listener};
+use crate::{
+    models::{
+        channel::{message::Message, reaction::ReactionAdd},
+        emoji::Emoji,
+        event::Event,
+        user::User,
+    },
+    snowflake::Snowflake,
+};
+
+type Filter = Box<dyn Fn(&ReactionAdd) -> bool + Send + Sync>;
+
+/// Collects the `MESSAGE_REACTION_ADD` events of a message, which can be filtered by emoji and user.
+///
+/// This needs the `GUILD_MESSAGE_REACTIONS` or `DIRECT_MESSAGE_REACTIONS` intent. Only reactions added after the collector is
+/// created are collected.
+///
+/// ```rust,no_run
+/// # async fn run(events: discors::collector::Events, message: discors::Snowflake<discors::models::channel::message::Message>) {
+/// use std::time::Duration;
+///
+/// let votes = events
+///     .reactions(message)
+///     .emoji("👍")
+///     .timeout(Duration::from_secs(60))
+///     .collect()
+///     .await;
+/// println!("{} votes", votes.len());
+/// # }
+/// ```
+pub struct ReactionCollector {
+    listener: Listener,
+    message: Snowflake<Message>,
+    emojis: Vec<Emoji>,
+    users: Vec<Snowflake<User>>,
+    filter: Option<Filter>,
+}
+
+impl Debug for ReactionCollector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReactionCollector")
+            .field("listener", &self.listener)
+            .field("message", &self.message)
+            .field("emojis", &self.emojis)
+            .field("users", &self.users)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ReactionCollector {
+    /// Creates a collector for the reactions of a message.
+    pub fn new(events: &Events, message: Snowflake<Message>) -> Self {
+        Self {
+            listener: Listener::new(events),
+            message,
+            emojis: Vec::new(),
+            users: Vec::new(),
+            filter: None,
+        }
+    }
+    /// Only collects reactions with an emoji, this can be called more than once to allow more emojis.
+    pub fn emoji(mut self, emoji: impl Into<Emoji>) -> Self {
+        self.emojis.push(emoji.into());
+        self
+    }
+    /// Only collects reactions by a user, this can be called more than once to allow more users.
+    pub fn user(mut self, user: Snowflake<User>) -> Self {
+        self.users.push(user);
+        self
+    }
+    /// Only collects reactions the filter gives `true` for.
+    pub fn filter(mut self, filter: impl Fn(&ReactionAdd) -> bool + Send + Sync + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+    /// Stops collecting after a duration, from when this is called.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.listener.timeout(timeout);
+        self
+    }
+    /// Stops collecting after an amount of reactions.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.listener.limit(limit);
+        self
+    }
+
+    /// Waits for the next reaction, `None` when the collector timed out or reached its limit.
+    pub async fn next(&mut self) -> Option<ReactionAdd> {
+        let Self {
+            listener,
+            message,
+            emojis,
+            users,
+            filter,
+        } = self;
+        listener
+            .next(|event| match event {
+                Event::ReactionAdd(reaction)
+                    if reaction.message_id == *message
+                        && (emojis.is_empty()
+                            || emojis.iter().any(|e| e.matches(&reaction.emoji)))
+                        && (users.is_empty() || users.contains(&reaction.user_id))
+                        && filter.as_ref().is_none_or(|f| f(reaction)) =>
+                {
+                    Some(ReactionAdd::clone(reaction))
+                }
+                _ => None,
+            })
+            .await
+    }
+    /// Collects every reaction until the collector times out or reaches its limit.
+    ///
+    /// Without a timeout or limit this never finishes.
+    pub async fn collect(mut self) -> Vec<ReactionAdd> {
+        let mut reactions = Vec::new();
+        while let Some(reaction) = self.next().await {
+            reactions.push(reaction);
+        }
+        reactions
+    }
+    /// Turns the collector into a stream of reactions.
+    pub fn stream(self) -> impl Stream<Item = ReactionAdd> {
+        futures::stream::unfold(self, |mut collector| async move {
+            collector.next().await.map(|r| (r, collector))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        collector::Events,
+        models::{emoji::Emoji, event::Event},
+    };
+
+    fn reaction(message: u64, user: u64, emoji: &str) -> Event {
+        Event::from_dispatch(
+            "MESSAGE_REACTION_ADD",
+            serde_json::json!({
+                "user_id": user.to_string(),
+                "channel_id": "1",
+                "message_id": message.to_string(),
+                "emoji": {"id": null, "name": emoji}
+            }),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn collect() {
+        let events = Events::new();
+        let collector = events
+            .reactions(3.into())
+            .emoji("👍")
+            .user(5.into())
+            .limit(2)
+            .timeout(Duration::from_secs(5));
+        // another message, another emoji and another user
+        events.dispatch(reaction(4, 5, "👍"));
+        events.dispatch(reaction(3, 5, "👎"));
+        events.dispatch(reaction(3, 6, "👍"));
+        events.dispatch(reaction(3, 5, "👍"));
+        events.dispatch(reaction(3, 5, "👍"));
+        events.dispatch(reaction(3, 5, "👍"));
+        let reactions = collector.collect().await;
+        assert_eq!(reactions.len(), 2);
+        assert!(reactions.iter().all(|r| r.emoji == Emoji::from("👍")));
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        let events = Events::new();
+        let mut collector = events
+            .reactions(3.into())
+            .filter(|r| r.user_id == 7.into())
+            .timeout(Duration::from_millis(50));
+        events.dispatch(reaction(3, 5, "👍"));
+        assert_eq!(collector.next().await, None);
+    }
+}
@@ -1,10 +1,20 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
 /// An alias for all the brits out there.
 pub type Colour = Color;
 
 /// Represents a Discord color. This is similar to storing information in an rgb tuple like, `(u8, u8, u8)`
 ///
 /// Note: an alias exsits: [`Colour`] and for all methods there is an alternative spelling avalible for convience.
-#[derive(Debug, Hash, PartialEq, Clone, Copy, PartialOrd)]
+///
+/// This (de-) serializes as the integer discord uses, and parses from hex codes and css color names.
+#[derive(Debug, Hash, PartialEq, Clone, Copy, PartialOrd, Serialize, Deserialize)]
 pub struct Color(pub u32);
 
 macro_rules! colors {
@@ -114,8 +124,376 @@ impl Color {
     }
 }
 
-impl ToString for Color {
-    /// Converts the color to a [`String`] as a hex code.
+impl Color {
+    /// Converts the color into hue (in degrees, `0.0..360.0`), saturation and lightness (both `0.0..=1.0`).
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let (r, g, b) = self.unit_rgb();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let chroma = max - min;
+        let saturation = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        (hue(r, g, b, max, chroma), saturation, lightness)
+    }
+    /// Converts hue (in degrees), saturation and lightness (both `0.0..=1.0`) into a Color.
+    ///
+    /// ```rust
+    /// use discors::color::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::new(0xff0000));
+    /// assert_eq!(Color::from_hsl(120.0, 1.0, 0.25), Color::new(0x008000));
+    /// ```
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        Self::from_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+    /// Converts the color into hue (in degrees, `0.0..360.0`), saturation and value (both `0.0..=1.0`).
+    pub fn to_hsv(&self) -> (f64, f64, f64) {
+        let (r, g, b) = self.unit_rgb();
+        let max = r.max(g).max(b);
+        let chroma = max - r.min(g).min(b);
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+        (hue(r, g, b, max, chroma), saturation, max)
+    }
+    /// Converts hue (in degrees), saturation and value (both `0.0..=1.0`) into a Color.
+    pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let value = value.clamp(0.0, 1.0);
+        let chroma = value * saturation.clamp(0.0, 1.0);
+        Self::from_chroma(hue, chroma, value - chroma)
+    }
+    /// Makes the color lighter, by adding `amount` (`0.0..=1.0`) to its lightness.
+    ///
+    /// ```rust
+    /// use discors::color::Color;
+    ///
+    /// assert_eq!(Color::new(0x000000).lighten(1.0), Color::new(0xffffff));
+    /// assert_eq!(Color::new(0x800000).lighten(0.25), Color::new(0xff0000));
+    /// ```
+    pub fn lighten(&self, amount: f64) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount)
+    }
+    /// Makes the color darker, by removing `amount` (`0.0..=1.0`) from its lightness.
+    pub fn darken(&self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+    /// Mixes two colors, `ratio` is how much of `other` to use (`0.0` gives this color, `1.0` gives `other`).
+    ///
+    /// ```rust
+    /// use discors::color::Color;
+    ///
+    /// assert_eq!(Color::new(0x000000).blend(Color::new(0xff8000), 0.5), Color::new(0x804000));
+    /// ```
+    pub fn blend(&self, other: Color, ratio: f64) -> Self {
+        let ratio = ratio.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * ratio).round() as u8;
+        Self::from_rgb(
+            mix(self.r(), other.r()),
+            mix(self.g(), other.g()),
+            mix(self.b(), other.b()),
+        )
+    }
+    /// Gives the color without any saturation, keeping its lightness.
+    pub fn grayscale(&self) -> Self {
+        let (h, _, l) = self.to_hsl();
+        Self::from_hsl(h, 0.0, l)
+    }
+    /// An alias to [`Color::grayscale`].
+    pub fn greyscale(&self) -> Self {
+        self.grayscale()
+    }
+    /// Gives the relative luminance of the color, as defined by [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance).
+    pub fn luminance(&self) -> f64 {
+        let linear = |c: f64| {
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = self.unit_rgb();
+        0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+    }
+    /// Gives the contrast ratio between two colors, from `1.0` (the same) to `21.0` (black and white).
+    ///
+    /// ```rust
+    /// use discors::color::Color;
+    ///
+    /// assert_eq!(Color::new(0x000000).contrast_ratio(Color::new(0xffffff)), 21.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+    /// Gives black or white, whichever is more readable on top of this color.
+    pub fn contrasting_color(&self) -> Self {
+        const BLACK: Color = Color(0x000000);
+        const WHITE: Color = Color(0xffffff);
+        if self.contrast_ratio(BLACK) >= self.contrast_ratio(WHITE) {
+            BLACK
+        } else {
+            WHITE
+        }
+    }
+    /// An alias to [`Color::contrasting_color`].
+    pub fn contrasting_colour(&self) -> Self {
+        self.contrasting_color()
+    }
+
+    // red, green and blue as 0.0..=1.0
+    fn unit_rgb(&self) -> (f64, f64, f64) {
+        (
+            self.r() as f64 / 255.0,
+            self.g() as f64 / 255.0,
+            self.b() as f64 / 255.0,
+        )
+    }
+    // shared last step of hsl and hsv conversions
+    fn from_chroma(hue: f64, chroma: f64, offset: f64) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let byte = |c: f64| ((c + offset) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::from_rgb(byte(r), byte(g), byte(b))
+    }
+}
+
+fn hue(r: f64, g: f64, b: f64, max: f64, chroma: f64) -> f64 {
+    if chroma == 0.0 {
+        return 0.0;
+    }
+    let hue = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    hue * 60.0
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+    /// Parses a color from `#abc`, `#aabbcc`, `0xaabbcc` or a css color name, like `rebeccapurple`.
+    ///
+    /// ```rust
+    /// use discors::color::Color;
+    ///
+    /// assert_eq!("#f0a".parse(), Ok(Color::new(0xff00aa)));
+    /// assert_eq!("0x7289DA".parse(), Ok(Color::BLURPLE));
+    /// assert_eq!("Crimson".parse(), Ok(Color::new(0xdc143c)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let hex = match s.strip_prefix('#').or_else(|| s.strip_prefix("0x")) {
+            Some(hex) => hex,
+            None => {
+                return CSS_COLORS
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(s))
+                    .map(|(_, color)| Color(*color))
+                    .ok_or(ParseColorError);
+            }
+        };
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseColorError);
+        }
+        let value = u32::from_str_radix(hex, 16).map_err(|_| ParseColorError)?;
+        match hex.len() {
+            // #abc is short for #aabbcc
+            3 => {
+                let (r, g, b) = (
+                    (value >> 8) as u8,
+                    (value >> 4 & 0xf) as u8,
+                    (value & 0xf) as u8,
+                );
+                Ok(Self::from_rgb(r * 0x11, g * 0x11, b * 0x11))
+            }
+            6 => Ok(Self(value)),
+            _ => Err(ParseColorError),
+        }
+    }
+}
+
+/// The error given when a string could not be parsed as a [`Color`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseColorError;
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the string provided is not a hex code or css color name")
+    }
+}
+
+impl StdError for ParseColorError {}
+
+// https://www.w3.org/TR/css-color-4/#named-colors
+const CSS_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+impl Display for Color {
+    /// Formats the color as a hex code.
     ///
     /// ```rust
     /// use discors::color::Color;
@@ -124,9 +502,9 @@ impl ToString for Color {
     ///
     /// assert_eq!(my_color.to_string(), "#abc123")
     /// ```
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // evil bit hack
-        format!("#{:0>6x}", self.0)
+        write!(f, "#{:0>6x}", self.0)
     }
 }
 
@@ -176,4 +554,59 @@ mod test {
         // 0xe74c3c in rgb is 231, 76, 60
         assert_eq!(Color::RED, Color::from_rgb_tuple((231, 76, 60)))
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("#12bcde".parse(), Ok(MY_COL));
+        assert_eq!("0x12BCDE".parse(), Ok(MY_COL));
+        assert_eq!(" #fff ".parse(), Ok(WHITE));
+        assert_eq!("white".parse(), Ok(WHITE));
+        assert_eq!("DarkSlateGrey".parse::<Color>(), "darkslategray".parse());
+        assert!("#ffff".parse::<Color>().is_err());
+        assert!("#+fffff".parse::<Color>().is_err());
+        assert!("12bcde".parse::<Color>().is_err());
+        assert!("not a color".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&MY_COL).unwrap(), "1227998");
+        assert_eq!(serde_json::from_str::<Color>("1227998").unwrap(), MY_COL);
+    }
+
+    #[test]
+    fn hsl_hsv() {
+        assert_eq!(WHITE.to_hsl(), (0.0, 0.0, 1.0));
+        assert_eq!(Color::new(0x00ff00).to_hsv(), (120.0, 1.0, 1.0));
+        assert_eq!(Color::from_hsv(240.0, 1.0, 1.0), Color::new(0x0000ff));
+        // converting back and forth keeps the color
+        for color in [
+            MY_COL,
+            Color::BLURPLE,
+            Color::DARK_THEME,
+            Color::GOLD,
+            WHITE,
+        ] {
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color);
+            let (h, s, v) = color.to_hsv();
+            assert_eq!(Color::from_hsv(h, s, v), color);
+        }
+    }
+
+    #[test]
+    fn adjustments() {
+        assert_eq!(WHITE.darken(1.0), Color::default());
+        assert_eq!(MY_COL.lighten(0.0), MY_COL);
+        assert_eq!(MY_COL.blend(WHITE, 1.0), WHITE);
+        assert_eq!(Color::new(0xff0000).greyscale(), Color::new(0x808080));
+        assert_eq!(Colour::RED.grayscale(), Colour::RED.greyscale());
+    }
+
+    #[test]
+    fn contrast() {
+        assert_eq!(WHITE.contrast_ratio(WHITE), 1.0);
+        assert_eq!(Color::DARK_THEME.contrasting_color(), WHITE);
+        assert_eq!(Colour::GOLD.contrasting_colour(), Color::default());
+    }
 }
@@ -0,0 +1,221 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use crate::{
+    models::{
+        application::{
+            command::{CommandOption, CommandOptionType},
+            interaction::{CommandDataOption, OptionValue, ResolvedData},
+        },
+        channel::{attachment::Attachment, Channel},
+        guild::role::Role,
+        user::User,
+    },
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// A type a command option can be extracted as.
+///
+/// `Option<T>` is an optional option, every other type is required.
+pub trait CommandArgument: Sized {
+    /// The type of option the value is given as.
+    const KIND: CommandOptionType;
+    /// Whether the option has to be given.
+    const REQUIRED: bool = true;
+
+    /// Extracts the value from the option the user gave, `None` if it is not of this type.
+    fn from_option(option: &CommandDataOption, resolved: &ResolvedData) -> Option<Self>;
+
+    /// The value when the user did not give the option.
+    fn missing(name: &str) -> Result<Self, ArgumentError> {
+        Err(ArgumentError::Missing(name.to_string()))
+    }
+}
+
+/// Creates the definition of an option which is extracted as `T`, with its type and whether it is required set.
+///
+/// ```rust
+/// use discors::{commands::argument, models::application::command::CommandOptionType};
+///
+/// let amount = argument::<Option<i64>>("amount", "How many messages to delete")
+///     .min_value(1)
+///     .max_value(100);
+/// assert_eq!(amount.kind, CommandOptionType::Integer);
+/// assert!(!amount.required);
+/// ```
+pub fn argument<T>(name: impl Into<String>, description: impl Into<String>) -> CommandOption
+where
+    T: CommandArgument,
+{
+    CommandOption::new(T::KIND, name, description).required(T::REQUIRED)
+}
+
+impl<T> CommandArgument for Option<T>
+where
+    T: CommandArgument,
+{
+    const KIND: CommandOptionType = T::KIND;
+    const REQUIRED: bool = false;
+
+    fn from_option(option: &CommandDataOption, resolved: &ResolvedData) -> Option<Self> {
+        T::from_option(option, resolved).map(Some)
+    }
+
+    fn missing(_name: &str) -> Result<Self, ArgumentError> {
+        Ok(None)
+    }
+}
+
+impl CommandArgument for String {
+    const KIND: CommandOptionType = CommandOptionType::String;
+
+    fn from_option(option: &CommandDataOption, _resolved: &ResolvedData) -> Option<Self> {
+        match &option.value {
+            Some(OptionValue::String(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl CommandArgument for i64 {
+    const KIND: CommandOptionType = CommandOptionType::Integer;
+
+    fn from_option(option: &CommandDataOption, _resolved: &ResolvedData) -> Option<Self> {
+        match option.value {
+            Some(OptionValue::Integer(i)) => Some(i),
+            _ => None,
+        }
+    }
+}
+
+impl CommandArgument for f64 {
+    const KIND: CommandOptionType = CommandOptionType::Number;
+
+    fn from_option(option: &CommandDataOption, _resolved: &ResolvedData) -> Option<Self> {
+        match option.value {
+            Some(OptionValue::Number(f)) => Some(f),
+            // whole numbers are sent without a fraction
+            Some(OptionValue::Integer(i)) => Some(i as f64),
+            _ => None,
+        }
+    }
+}
+
+impl CommandArgument for bool {
+    const KIND: CommandOptionType = CommandOptionType::Boolean;
+
+    fn from_option(option: &CommandDataOption, _resolved: &ResolvedData) -> Option<Self> {
+        match option.value {
+            Some(OptionValue::Boolean(b)) => Some(b),
+            _ => None,
+        }
+    }
+}
+
+// users, channels, roles and attachments are given as their id
+fn id<T>(option: &CommandDataOption, kind: CommandOptionType) -> Option<Snowflake<T>>
+where
+    T: Snowflakable,
+{
+    match &option.value {
+        Some(OptionValue::String(id)) if option.kind == kind => id.parse().ok(),
+        _ => None,
+    }
+}
+
+impl CommandArgument for Snowflake<User> {
+    const KIND: CommandOptionType = CommandOptionType::User;
+
+    fn from_option(option: &CommandDataOption, _resolved: &ResolvedData) -> Option<Self> {
+        id(option, Self::KIND)
+    }
+}
+
+impl CommandArgument for User {
+    const KIND: CommandOptionType = CommandOptionType::User;
+
+    fn from_option(option: &CommandDataOption, resolved: &ResolvedData) -> Option<Self> {
+        resolved.users.get(&id(option, Self::KIND)?).cloned()
+    }
+}
+
+impl CommandArgument for Snowflake<Channel> {
+    const KIND: CommandOptionType = CommandOptionType::Channel;
+
+    fn from_option(option: &CommandDataOption, _resolved: &ResolvedData) -> Option<Self> {
+        id(option, Self::KIND)
+    }
+}
+
+impl CommandArgument for Channel {
+    const KIND: CommandOptionType = CommandOptionType::Channel;
+
+    fn from_option(option: &CommandDataOption, resolved: &ResolvedData) -> Option<Self> {
+        resolved.channels.get(&id(option, Self::KIND)?).cloned()
+    }
+}
+
+impl CommandArgument for Snowflake<Role> {
+    const KIND: CommandOptionType = CommandOptionType::Role;
+
+    fn from_option(option: &CommandDataOption, _resolved: &ResolvedData) -> Option<Self> {
+        id(option, Self::KIND)
+    }
+}
+
+impl CommandArgument for Role {
+    const KIND: CommandOptionType = CommandOptionType::Role;
+
+    fn from_option(option: &CommandDataOption, resolved: &ResolvedData) -> Option<Self> {
+        resolved.roles.get(&id(option, Self::KIND)?).cloned()
+    }
+}
+
+impl CommandArgument for Attachment {
+    const KIND: CommandOptionType = CommandOptionType::Attachment;
+
+    fn from_option(option: &CommandDataOption, resolved: &ResolvedData) -> Option<Self> {
+        resolved.attachments.get(&id(option, Self::KIND)?).cloned()
+    }
+}
+
+/// The error given when an option can not be extracted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArgumentError {
+    /// A required option, with this name, was not given.
+    Missing(String),
+    /// An option, with this name, is not of the type it was extracted as.
+    Invalid {
+        name: String,
+        expected: CommandOptionType,
+    },
+    /// No subcommand was used, or the subcommand with this name is not known.
+    UnknownSubcommand(Option<String>),
+    /// The user or message a context menu command was used on is not in the interaction, or it is another type of command.
+    MissingTarget,
+}
+
+impl Display for ArgumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgumentError::Missing(name) => write!(f, "the option {:?} was not given", name),
+            ArgumentError::Invalid { name, expected } => write!(
+                f,
+                "the option {:?} could not be read as a {:?} option",
+                name, expected
+            ),
+            ArgumentError::UnknownSubcommand(Some(name)) => {
+                write!(f, "the subcommand {:?} is not known", name)
+            }
+            ArgumentError::UnknownSubcommand(None) => f.write_str("no subcommand was used"),
+            ArgumentError::MissingTarget => {
+                f.write_str("the target of the context menu command was not given")
+            }
+        }
+    }
+}
+
+impl StdError for ArgumentError {}
@@ -0,0 +1,271 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug},
+    future::Future,
+    sync::Arc,
+};
+
+use futures::future::BoxFuture;
+
+use super::Arguments;
+use crate::{
+    interactions::Responder,
+    models::application::{
+        command::{command_limits, CommandChoiceValue, CommandOptionChoice},
+        interaction::{
+            CommandData, CommandDataOption, InteractionResponse, InteractionType, OptionValue,
+        },
+    },
+};
+
+type Callback =
+    Arc<dyn Fn(AutocompleteInput) -> BoxFuture<'static, Vec<CommandOptionChoice>> + Send + Sync>;
+
+/// The autocomplete callbacks of a bot's commands, by command and option name.
+///
+/// Commands are named as users see them, with their subcommand group and subcommand, like `shop buy`,
+/// so options with the same name in different subcommands have their own callbacks.
+///
+/// The choices a callback gives are cut down to 25, and choices outside of discord's limits are left out,
+/// before they are sent as the response.
+///
+/// ```rust
+/// use discors::{
+///     commands::Autocomplete,
+///     models::application::command::CommandOptionChoice,
+/// };
+///
+/// const FRUITS: [&str; 3] = ["apple", "banana", "cherry"];
+///
+/// let autocomplete = Autocomplete::new().option("fruit", "name", |input| async move {
+///     FRUITS
+///         .iter()
+///         .filter(|fruit| fruit.starts_with(input.value()))
+///         .map(|fruit| CommandOptionChoice::new(*fruit, *fruit))
+///         .collect()
+/// });
+/// assert!(!autocomplete.is_empty());
+/// ```
+#[derive(Clone, Default)]
+pub struct Autocomplete {
+    // keyed by the full name of the command, with its group and subcommand, and the name of the option
+    callbacks: HashMap<(String, String), Callback>,
+}
+
+impl Debug for Autocomplete {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.callbacks.keys()).finish()
+    }
+}
+
+impl Autocomplete {
+    /// Creates a set with no callbacks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the callback of an option of a command, the command includes its subcommand group and subcommand, like `shop buy`.
+    ///
+    /// The option has to be registered with [`CommandOption::autocomplete`](crate::models::application::command::CommandOption::autocomplete) set.
+    pub fn option<F, Fut>(
+        mut self,
+        command: impl Into<String>,
+        option: impl Into<String>,
+        callback: F,
+    ) -> Self
+    where
+        F: Fn(AutocompleteInput) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<CommandOptionChoice>> + Send + 'static,
+    {
+        self.callbacks.insert(
+            (command.into(), option.into()),
+            Arc::new(move |input| Box::pin(callback(input))),
+        );
+        self
+    }
+    /// Checks whether there are no callbacks.
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+    // The callback for an autocomplete interaction, with its input, if one is set for the option being typed.
+    fn find(&self, responder: &Responder) -> Option<(Callback, AutocompleteInput)> {
+        let interaction = responder.interaction();
+        if interaction.kind != InteractionType::ApplicationCommandAutocomplete {
+            return None;
+        }
+        let data = interaction.command()?;
+        let mut command = data.name.clone();
+        let mut arguments = Arguments::new(data);
+        while let Some((name, options)) = arguments.subcommand() {
+            command.push(' ');
+            command.push_str(name);
+            arguments = options;
+        }
+        let focused = arguments.focused()?;
+        let callback = self.callbacks.get(&(command, focused.name.clone()))?;
+        Some((
+            callback.clone(),
+            AutocompleteInput::new(data.clone(), focused),
+        ))
+    }
+    // Answers an autocomplete interaction in its own task, or gives it back if no callback is set for it.
+    pub(crate) fn dispatch(&self, responder: Responder) -> Option<Responder> {
+        let Some((callback, input)) = self.find(&responder) else {
+            return Some(responder);
+        };
+        tokio::spawn(async move {
+            let choices = clean_choices(callback(input).await);
+            // the only errors are the 3 seconds passing or another response being sent
+            let _ = responder
+                .respond(InteractionResponse::autocomplete(choices))
+                .await;
+        });
+        None
+    }
+}
+
+/// What the user is typing in an option with autocomplete, with the other options they gave.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutocompleteInput {
+    data: CommandData,
+    option: String,
+    value: String,
+}
+
+impl AutocompleteInput {
+    fn new(data: CommandData, focused: &CommandDataOption) -> Self {
+        // numbers are sent as typed, which might not be a number yet
+        let value = match &focused.value {
+            Some(OptionValue::String(s)) => s.clone(),
+            Some(OptionValue::Integer(i)) => i.to_string(),
+            Some(OptionValue::Number(f)) => f.to_string(),
+            Some(OptionValue::Boolean(b)) => b.to_string(),
+            None => String::new(),
+        };
+        Self {
+            option: focused.name.clone(),
+            data,
+            value,
+        }
+    }
+    /// The name of the command.
+    pub fn command(&self) -> &str {
+        &self.data.name
+    }
+    /// The name of the option being typed.
+    pub fn option(&self) -> &str {
+        &self.option
+    }
+    /// What has been typed so far.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+    /// The options given alongside the one being typed, in the same subcommand.
+    pub fn arguments(&self) -> Arguments<'_> {
+        let mut arguments = Arguments::new(&self.data);
+        while let Some((_, options)) = arguments.subcommand() {
+            arguments = options;
+        }
+        arguments
+    }
+    /// The data of the interaction.
+    pub fn data(&self) -> &CommandData {
+        &self.data
+    }
+}
+
+// cuts the choices down to discord's limits, choices that can't be cut down are left out
+fn clean_choices(choices: Vec<CommandOptionChoice>) -> Vec<CommandOptionChoice> {
+    choices
+        .into_iter()
+        .filter(|choice| match &choice.value {
+            CommandChoiceValue::String(value) => {
+                value.chars().count() <= command_limits::CHOICE_VALUE
+            }
+            _ => true,
+        })
+        .filter_map(|mut choice| {
+            let len = choice.name.chars().count();
+            if len == 0 {
+                return None;
+            }
+            if len > *command_limits::CHOICE_NAME.end() {
+                choice.name = choice
+                    .name
+                    .chars()
+                    .take(*command_limits::CHOICE_NAME.end())
+                    .collect();
+            }
+            Some(choice)
+        })
+        .take(command_limits::CHOICES)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::{clean_choices, Autocomplete};
+    use crate::{
+        commands::argument,
+        http::Http,
+        interactions::Responder,
+        models::application::{
+            command::CommandOptionChoice,
+            interaction::{test_interaction, Interaction},
+        },
+    };
+
+    fn responder(kind: u8) -> Responder {
+        let data = serde_json::json!({"id": "3", "name": "shop", "type": 1, "options": [
+            {"name": "buy", "type": 1, "options": [
+                {"name": "amount", "type": 4, "value": 2},
+                {"name": "item", "type": 3, "value": "app", "focused": true},
+            ]},
+        ]});
+        let interaction =
+            serde_json::from_value::<Interaction>(test_interaction(kind, data)).unwrap();
+        Responder::new(Arc::new(Http::new("token").unwrap()), interaction)
+    }
+
+    #[test]
+    fn find() {
+        let autocomplete = Autocomplete::new()
+            .option("shop buy", "item", |_| async { Vec::new() })
+            .option("shop sell", "item", |_| async { Vec::new() });
+        let (_, input) = autocomplete.find(&responder(4)).unwrap();
+        assert_eq!(input.command(), "shop");
+        assert_eq!(input.option(), "item");
+        assert_eq!(input.value(), "app");
+        assert_eq!(input.arguments().get::<i64>("amount"), Ok(2));
+        // not an autocomplete interaction
+        assert!(autocomplete.find(&responder(2)).is_none());
+        let other = Autocomplete::new().option("shop buy", "amount", |_| async { Vec::new() });
+        assert!(other.find(&responder(4)).is_none());
+        // the same option of another subcommand, or without the subcommand, is not matched
+        let other = Autocomplete::new()
+            .option("shop sell", "item", |_| async { Vec::new() })
+            .option("shop", "item", |_| async { Vec::new() });
+        assert!(other.find(&responder(4)).is_none());
+        // the builder gives the option autocomplete
+        assert!(
+            argument::<String>("item", "What to buy")
+                .autocomplete(true)
+                .autocomplete
+        );
+    }
+
+    #[test]
+    fn clean() {
+        let mut choices = vec![
+            CommandOptionChoice::new("", "empty"),
+            CommandOptionChoice::new("long value", "v".repeat(101)),
+            CommandOptionChoice::new("n".repeat(120), "long name"),
+        ];
+        choices.extend((0..30).map(|i| CommandOptionChoice::new(i.to_string(), i)));
+        let choices = clean_choices(choices);
+        assert_eq!(choices.len(), 25);
+        assert_eq!(choices[0].name.len(), 100);
+        assert_eq!(choices[1].name, "0");
+    }
+}
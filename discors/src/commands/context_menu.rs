@@ -0,0 +1,182 @@
+use super::ArgumentError;
+use crate::models::{
+    application::{command::CreateCommand, interaction::CommandData},
+    channel::message::Message,
+    guild::member::Member,
+    user::User,
+};
+
+/// A command in the context menu of users, defined as a rust type which is parsed from the user it was used on.
+///
+/// ```rust
+/// use discors::{
+///     commands::{ArgumentError, UserCommand, UserTarget},
+///     models::{application::command::CreateCommand, user::User},
+/// };
+///
+/// struct Avatar {
+///     user: User,
+/// }
+///
+/// impl UserCommand for Avatar {
+///     fn command() -> CreateCommand {
+///         CreateCommand::user("Show avatar")
+///     }
+///
+///     fn parse(target: UserTarget<'_>) -> Result<Self, ArgumentError> {
+///         Ok(Self {
+///             user: target.user.clone(),
+///         })
+///     }
+/// }
+/// ```
+pub trait UserCommand: Sized {
+    /// The definition of the command which is registered with discord, made with [`CreateCommand::user`].
+    fn command() -> CreateCommand;
+
+    /// Parses the user the command was used on.
+    fn parse(target: UserTarget<'_>) -> Result<Self, ArgumentError>;
+
+    /// Parses the command from the data of an interaction.
+    fn from_data(data: &CommandData) -> Result<Self, ArgumentError> {
+        let user = data.target_user().ok_or(ArgumentError::MissingTarget)?;
+        Self::parse(UserTarget {
+            user,
+            member: data.target_member(),
+        })
+    }
+}
+
+/// The user a [`UserCommand`] was used on.
+#[derive(Debug, Clone, Copy)]
+pub struct UserTarget<'a> {
+    /// The user the command was used on.
+    pub user: &'a User,
+    /// The member of the user, without its user, only given in guilds.
+    pub member: Option<&'a Member>,
+}
+
+/// A command in the context menu of messages, defined as a rust type which is parsed from the message it was used on.
+///
+/// ```rust
+/// use discors::{
+///     commands::{ArgumentError, MessageCommand},
+///     models::{application::command::CreateCommand, channel::message::Message},
+/// };
+///
+/// struct Quote {
+///     content: String,
+/// }
+///
+/// impl MessageCommand for Quote {
+///     fn command() -> CreateCommand {
+///         CreateCommand::message("Quote")
+///     }
+///
+///     fn parse(message: &Message) -> Result<Self, ArgumentError> {
+///         Ok(Self {
+///             content: message.content.clone(),
+///         })
+///     }
+/// }
+/// ```
+pub trait MessageCommand: Sized {
+    /// The definition of the command which is registered with discord, made with [`CreateCommand::message`].
+    fn command() -> CreateCommand;
+
+    /// Parses the message the command was used on.
+    fn parse(message: &Message) -> Result<Self, ArgumentError>;
+
+    /// Parses the command from the data of an interaction.
+    fn from_data(data: &CommandData) -> Result<Self, ArgumentError> {
+        Self::parse(data.target_message().ok_or(ArgumentError::MissingTarget)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MessageCommand, UserCommand, UserTarget};
+    use crate::{
+        commands::{ArgumentError, Commands},
+        models::{
+            application::{
+                command::{CommandType, CreateCommand},
+                interaction::CommandData,
+            },
+            channel::message::Message,
+        },
+        snowflake::Snowflake,
+    };
+
+    struct Whois {
+        name: String,
+        nick: Option<String>,
+    }
+
+    impl UserCommand for Whois {
+        fn command() -> CreateCommand {
+            CreateCommand::user("Who is")
+        }
+
+        fn parse(target: UserTarget<'_>) -> Result<Self, ArgumentError> {
+            Ok(Self {
+                name: target.user.name.clone(),
+                nick: target.member.and_then(|m| m.nick.clone()),
+            })
+        }
+    }
+
+    struct Pin(Snowflake<Message>);
+
+    impl MessageCommand for Pin {
+        fn command() -> CreateCommand {
+            CreateCommand::message("Pin message")
+        }
+
+        fn parse(message: &Message) -> Result<Self, ArgumentError> {
+            Ok(Self(message.id))
+        }
+    }
+
+    #[test]
+    fn user() {
+        let data = serde_json::from_value::<CommandData>(serde_json::json!({
+            "id": "1", "name": "Who is", "type": 2, "target_id": "5",
+            "resolved": {
+                "users": {"5": {"id": "5", "username": "ferris", "discriminator": "0", "avatar": null}},
+                "members": {"5": {"roles": [], "joined_at": "2023-01-01T00:00:00+00:00", "nick": "crab", "deaf": false, "mute": false}},
+            },
+        }))
+        .unwrap();
+        let whois = Whois::from_data(&data).unwrap();
+        assert_eq!(whois.name, "ferris");
+        assert_eq!(whois.nick.as_deref(), Some("crab"));
+        // a message command has no user target
+        assert!(matches!(
+            Pin::from_data(&data),
+            Err(ArgumentError::MissingTarget)
+        ));
+        assert!(Whois::command().validate().is_ok());
+        assert_eq!(Whois::command().get_kind(), CommandType::User);
+    }
+
+    #[test]
+    fn message() {
+        let data = serde_json::from_value::<CommandData>(serde_json::json!({
+            "id": "1", "name": "Pin message", "type": 3, "target_id": "3",
+            "resolved": {"messages": {"3": {
+                "id": "3", "channel_id": "4", "content": "hi", "timestamp": "2023-01-01T00:00:00+00:00",
+                "edited_timestamp": null, "tts": false, "mention_everyone": false, "mentions": [],
+                "mention_roles": [], "attachments": [], "embeds": [], "pinned": false, "type": 0,
+                "author": {"id": "5", "username": "ferris", "discriminator": "0", "avatar": null},
+            }}},
+        }))
+        .unwrap();
+        assert_eq!(Pin::from_data(&data).unwrap().0, 3.into());
+        assert!(Pin::command().validate().is_ok());
+        let commands = Commands::new()
+            .register_user::<Whois>()
+            .register_message_in::<Pin>(1.into());
+        assert!(!commands.is_empty());
+    }
+}
@@ -0,0 +1,468 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::Error,
+    http::Http,
+    models::{
+        application::{
+            command::{
+                ApplicationCommand, CommandChoiceValue, CommandNumber, CommandOption,
+                CreateCommand, Localizations,
+            },
+            interaction::{CommandData, CommandDataOption, ResolvedData},
+        },
+        guild::Guild,
+    },
+    snowflake::Snowflake,
+};
+
+pub use self::{
+    argument::{argument, ArgumentError, CommandArgument},
+    autocomplete::{Autocomplete, AutocompleteInput},
+    context_menu::{MessageCommand, UserCommand, UserTarget},
+};
+
+mod argument;
+mod autocomplete;
+mod context_menu;
+
+/// A slash command defined as a rust type, which is parsed from the options the user gave.
+///
+/// ```rust
+/// use discors::{
+///     commands::{argument, ArgumentError, Arguments, SlashCommand},
+///     models::{application::command::CreateCommand, user::User},
+///     snowflake::Snowflake,
+/// };
+///
+/// struct Ban {
+///     user: Snowflake<User>,
+///     reason: Option<String>,
+/// }
+///
+/// impl SlashCommand for Ban {
+///     fn command() -> CreateCommand {
+///         CreateCommand::new("ban", "Bans a user")
+///             .option(argument::<Snowflake<User>>("user", "Who to ban"))
+///             .option(argument::<Option<String>>("reason", "Why").max_length(512))
+///     }
+///
+///     fn parse(arguments: &Arguments<'_>) -> Result<Self, ArgumentError> {
+///         Ok(Self {
+///             user: arguments.get("user")?,
+///             reason: arguments.get("reason")?,
+///         })
+///     }
+/// }
+/// ```
+pub trait SlashCommand: Sized {
+    /// The definition of the command which is registered with discord.
+    fn command() -> CreateCommand;
+
+    /// Parses the options the user gave.
+    fn parse(arguments: &Arguments<'_>) -> Result<Self, ArgumentError>;
+
+    /// Parses the command from the data of an interaction.
+    fn from_data(data: &CommandData) -> Result<Self, ArgumentError> {
+        Self::parse(&Arguments::new(data))
+    }
+}
+
+/// The options a user gave to a command or subcommand, which are extracted by name.
+#[derive(Debug, Clone, Copy)]
+pub struct Arguments<'a> {
+    options: &'a [CommandDataOption],
+    resolved: &'a ResolvedData,
+}
+
+impl<'a> Arguments<'a> {
+    /// The options given to a command.
+    pub fn new(data: &'a CommandData) -> Self {
+        Self {
+            options: &data.options,
+            resolved: &data.resolved,
+        }
+    }
+    /// Extracts an option as a type, use `Option<T>` for options which are not required.
+    pub fn get<T>(&self, name: &str) -> Result<T, ArgumentError>
+    where
+        T: CommandArgument,
+    {
+        match self.options.iter().find(|o| o.name == name) {
+            Some(option) => {
+                T::from_option(option, self.resolved).ok_or_else(|| ArgumentError::Invalid {
+                    name: name.to_string(),
+                    expected: T::KIND,
+                })
+            }
+            None => T::missing(name),
+        }
+    }
+    /// The subcommand or subcommand group that was used, with its options.
+    pub fn subcommand(&self) -> Option<(&'a str, Arguments<'a>)> {
+        let option = self.options.iter().find(|o| o.kind.is_subcommand())?;
+        Some((
+            &option.name,
+            Arguments {
+                options: &option.options,
+                resolved: self.resolved,
+            },
+        ))
+    }
+    /// The option the user is typing, in autocomplete interactions.
+    pub fn focused(&self) -> Option<&'a CommandDataOption> {
+        self.options.iter().find_map(|o| {
+            if o.focused {
+                Some(o)
+            } else if o.kind.is_subcommand() {
+                Arguments {
+                    options: &o.options,
+                    resolved: self.resolved,
+                }
+                .focused()
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// The commands of a bot, which are registered with discord by [`Commands::sync`].
+///
+/// ```rust
+/// use discors::{commands::Commands, models::application::command::CreateCommand};
+///
+/// let commands = Commands::new()
+///     .global(CreateCommand::new("ping", "Checks the bot is alive"))
+///     .guild(1.into(), CreateCommand::new("deploy", "Deploys the bot"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Commands {
+    global: Vec<CreateCommand>,
+    guilds: HashMap<Snowflake<Guild>, Vec<CreateCommand>>,
+}
+
+impl Commands {
+    /// Creates an empty set of commands.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds a command that can be used in every guild.
+    pub fn global(mut self, command: CreateCommand) -> Self {
+        self.global.push(command);
+        self
+    }
+    /// Adds a command that can only be used in a guild.
+    pub fn guild(mut self, guild: Snowflake<Guild>, command: CreateCommand) -> Self {
+        self.guilds.entry(guild).or_default().push(command);
+        self
+    }
+    /// Adds a [`SlashCommand`] that can be used in every guild.
+    pub fn register<C>(self) -> Self
+    where
+        C: SlashCommand,
+    {
+        self.global(C::command())
+    }
+    /// Adds a [`SlashCommand`] that can only be used in a guild.
+    pub fn register_in<C>(self, guild: Snowflake<Guild>) -> Self
+    where
+        C: SlashCommand,
+    {
+        self.guild(guild, C::command())
+    }
+    /// Adds a [`UserCommand`] that can be used in every guild.
+    pub fn register_user<C>(self) -> Self
+    where
+        C: UserCommand,
+    {
+        self.global(C::command())
+    }
+    /// Adds a [`UserCommand`] that can only be used in a guild.
+    pub fn register_user_in<C>(self, guild: Snowflake<Guild>) -> Self
+    where
+        C: UserCommand,
+    {
+        self.guild(guild, C::command())
+    }
+    /// Adds a [`MessageCommand`] that can be used in every guild.
+    pub fn register_message<C>(self) -> Self
+    where
+        C: MessageCommand,
+    {
+        self.global(C::command())
+    }
+    /// Adds a [`MessageCommand`] that can only be used in a guild.
+    pub fn register_message_in<C>(self, guild: Snowflake<Guild>) -> Self
+    where
+        C: MessageCommand,
+    {
+        self.guild(guild, C::command())
+    }
+    /// Checks whether there are no commands.
+    pub fn is_empty(&self) -> bool {
+        self.global.is_empty() && self.guilds.is_empty()
+    }
+    /// Registers the commands with discord, only overwriting them where they differ from the registered commands.
+    ///
+    /// Overwriting creates any new command, which counts towards discord's limit of 200 created a day.
+    pub async fn sync(&self, http: &Http) -> Result<SyncReport, Error> {
+        let mut report = SyncReport::default();
+        let registered = http.get_global_commands(true).await?;
+        if !commands_match(&self.global, &registered, true) {
+            http.bulk_overwrite_global_commands(&self.global).await?;
+            report.global = true;
+        }
+        for (guild, commands) in &self.guilds {
+            let registered = http.get_guild_commands(*guild, true).await?;
+            if !commands_match(commands, &registered, false) {
+                http.bulk_overwrite_guild_commands(*guild, commands).await?;
+                report.guilds.push(*guild);
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// What was overwritten by [`Commands::sync`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SyncReport {
+    /// Whether the global commands were overwritten.
+    pub global: bool,
+    /// The guilds whose commands were overwritten.
+    pub guilds: Vec<Snowflake<Guild>>,
+}
+
+// whether the registered commands are the same as the definitions, ignoring the fields discord fills in
+fn commands_match(
+    definitions: &[CreateCommand],
+    registered: &[ApplicationCommand],
+    global: bool,
+) -> bool {
+    definitions.len() == registered.len()
+        && definitions.iter().all(|definition| {
+            registered
+                .iter()
+                .find(|r| r.name == definition.name && r.kind == definition.kind)
+                .is_some_and(|r| command_matches(definition, r, global))
+        })
+}
+
+fn command_matches(
+    definition: &CreateCommand,
+    registered: &ApplicationCommand,
+    global: bool,
+) -> bool {
+    definition.description == registered.description
+        && localizations_match(&definition.name_localizations, &registered.name_localizations)
+        && localizations_match(
+            &definition.description_localizations,
+            &registered.description_localizations,
+        )
+        && options_match(&definition.options, &registered.options)
+        && definition.default_member_permissions == registered.default_member_permissions
+        && definition.nsfw == registered.nsfw
+        // discord only gives this for global commands, where it defaults to true
+        && (!global || definition.dm_permission.unwrap_or(true) == registered.dm_permission.unwrap_or(true))
+}
+
+fn options_match(definitions: &[CommandOption], registered: &[CommandOption]) -> bool {
+    definitions.len() == registered.len()
+        && definitions.iter().zip(registered).all(|(d, r)| {
+            d.kind == r.kind
+                && d.name == r.name
+                && d.description == r.description
+                && localizations_match(&d.name_localizations, &r.name_localizations)
+                && localizations_match(&d.description_localizations, &r.description_localizations)
+                && d.required == r.required
+                && d.choices.len() == r.choices.len()
+                && d.choices.iter().zip(&r.choices).all(|(d, r)| {
+                    d.name == r.name
+                        && localizations_match(&d.name_localizations, &r.name_localizations)
+                        && choice_values_match(&d.value, &r.value)
+                })
+                && options_match(&d.options, &r.options)
+                && d.channel_types == r.channel_types
+                && numbers_match(d.min_value, r.min_value)
+                && numbers_match(d.max_value, r.max_value)
+                && d.min_length == r.min_length
+                && d.max_length == r.max_length
+                && d.autocomplete == r.autocomplete
+        })
+}
+
+// no localizations can be given as null or as an empty map
+fn localizations_match(a: &Option<Localizations>, b: &Option<Localizations>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        (Some(l), None) | (None, Some(l)) => l.is_empty(),
+        (None, None) => true,
+    }
+}
+
+// a number like `1.0` is given back as `1`
+fn numbers_match(a: Option<CommandNumber>, b: Option<CommandNumber>) -> bool {
+    fn float(n: CommandNumber) -> f64 {
+        match n {
+            CommandNumber::Integer(i) => i as f64,
+            CommandNumber::Number(f) => f,
+        }
+    }
+    a.map(float) == b.map(float)
+}
+
+fn choice_values_match(a: &CommandChoiceValue, b: &CommandChoiceValue) -> bool {
+    match (a, b) {
+        (CommandChoiceValue::String(a), CommandChoiceValue::String(b)) => a == b,
+        (CommandChoiceValue::String(_), _) | (_, CommandChoiceValue::String(_)) => false,
+        (a, b) => numbers_match(number(a), number(b)),
+    }
+}
+
+fn number(value: &CommandChoiceValue) -> Option<CommandNumber> {
+    match value {
+        CommandChoiceValue::Integer(i) => Some(CommandNumber::Integer(*i)),
+        CommandChoiceValue::Number(f) => Some(CommandNumber::Number(*f)),
+        CommandChoiceValue::String(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{argument, commands_match, ArgumentError, Arguments, SlashCommand};
+    use crate::{
+        models::{
+            application::{
+                command::{ApplicationCommand, CommandOptionType, CreateCommand},
+                interaction::CommandData,
+            },
+            channel::Channel,
+            guild::role::Role,
+            user::User,
+        },
+        permissions::Permissions,
+        snowflake::Snowflake,
+    };
+
+    #[derive(Debug)]
+    struct Purge {
+        amount: i64,
+        channel: Option<Snowflake<Channel>>,
+        role: Option<Role>,
+        user: Option<Snowflake<User>>,
+        ratio: Option<f64>,
+    }
+
+    impl SlashCommand for Purge {
+        fn command() -> CreateCommand {
+            CreateCommand::new("purge", "Deletes messages")
+                .option(
+                    argument::<i64>("amount", "How many")
+                        .min_value(1)
+                        .max_value(100),
+                )
+                .option(argument::<Option<Snowflake<Channel>>>("channel", "Where"))
+                .option(argument::<Option<Role>>(
+                    "role",
+                    "Only from members with a role",
+                ))
+                .option(argument::<Option<Snowflake<User>>>(
+                    "user",
+                    "Only from a user",
+                ))
+                .option(argument::<Option<f64>>("ratio", "How many to keep").min_value(0.5))
+                .default_member_permissions(Permissions::MANAGE_MESSAGES)
+        }
+
+        fn parse(arguments: &Arguments<'_>) -> Result<Self, ArgumentError> {
+            Ok(Self {
+                amount: arguments.get("amount")?,
+                channel: arguments.get("channel")?,
+                role: arguments.get("role")?,
+                user: arguments.get("user")?,
+                ratio: arguments.get("ratio")?,
+            })
+        }
+    }
+
+    fn data(options: &str) -> CommandData {
+        serde_json::from_str(&format!(
+            r#"{{
+                "id": "1", "name": "purge", "type": 1, "options": {},
+                "resolved": {{"roles": {{"5": {{
+                    "id": "5", "name": "mods", "color": 0, "hoist": false, "position": 1,
+                    "permissions": "0", "managed": false, "mentionable": false
+                }}}}}}
+            }}"#,
+            options
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let purge = Purge::from_data(&data(
+            r#"[{"name": "amount", "type": 4, "value": 10}, {"name": "role", "type": 8, "value": "5"}, {"name": "ratio", "type": 10, "value": 1}]"#,
+        ))
+        .unwrap();
+        assert_eq!(purge.amount, 10);
+        assert_eq!(purge.channel, None);
+        assert_eq!(purge.role.unwrap().name, "mods");
+        assert_eq!(purge.user, None);
+        assert_eq!(purge.ratio, Some(1.0));
+        assert_eq!(
+            Purge::from_data(&data("[]")).unwrap_err(),
+            ArgumentError::Missing("amount".to_string())
+        );
+        assert_eq!(
+            Purge::from_data(&data(r#"[{"name": "amount", "type": 3, "value": "a"}]"#))
+                .unwrap_err(),
+            ArgumentError::Invalid {
+                name: "amount".to_string(),
+                expected: CommandOptionType::Integer
+            }
+        );
+    }
+
+    #[test]
+    fn subcommand() {
+        let data = data(
+            r#"[{"name": "messages", "type": 1, "options": [{"name": "amount", "type": 4, "value": 3, "focused": true}]}]"#,
+        );
+        let arguments = Arguments::new(&data);
+        let (name, options) = arguments.subcommand().unwrap();
+        assert_eq!(name, "messages");
+        assert_eq!(options.get::<i64>("amount").unwrap(), 3);
+        assert_eq!(arguments.focused().unwrap().name, "amount");
+    }
+
+    #[test]
+    fn sync() {
+        let registered = serde_json::from_value::<Vec<ApplicationCommand>>(serde_json::json!([{
+            "id": "1", "type": 1, "application_id": "2", "version": "3",
+            "name": "purge", "description": "Deletes messages",
+            "name_localizations": null, "description_localizations": {},
+            "default_member_permissions": "8192", "dm_permission": true, "nsfw": false,
+            "options": [
+                {"type": 4, "name": "amount", "description": "How many", "required": true, "min_value": 1, "max_value": 100},
+                {"type": 7, "name": "channel", "description": "Where"},
+                {"type": 8, "name": "role", "description": "Only from members with a role"},
+                {"type": 6, "name": "user", "description": "Only from a user"},
+                {"type": 10, "name": "ratio", "description": "How many to keep", "min_value": 0.5}
+            ]
+        }]))
+        .unwrap();
+        assert!(commands_match(&[Purge::command()], &registered, true));
+        assert!(!commands_match(
+            &[Purge::command().dm_permission(false)],
+            &registered,
+            true
+        ));
+        assert!(!commands_match(
+            &[Purge::command(), CreateCommand::new("ping", "Pong")],
+            &registered,
+            true
+        ));
+        assert!(!commands_match(&[], &registered, true));
+    }
+}
@@ -5,6 +5,24 @@ use std::{
     num::ParseIntError,
 };
 
+use crate::{
+    color::ParseColorError,
+    commands::ArgumentError,
+    http::ApiError,
+    mention::ParseMentionError,
+    models::{
+        application::{command::CommandError, interaction::InteractionError},
+        channel::{
+            component::ComponentError, embed::EmbedError, forum::ForumError,
+            message::CreateMessageError, thread::ThreadError,
+        },
+        guild::{audit_log::ReasonTooLong, ban::BanError, member::ModerationError},
+        image::ImageError,
+        sticker::StickerError,
+    },
+    timestamp::ParseTimestampError,
+};
+
 macro_rules! error_impl {
     ($($name:ident($x:ty) $( Std $($std:expr)?;)? $( From $($from:expr)?;)?,)*) => {
         #[derive(Debug)]
@@ -68,5 +86,26 @@ error_impl! {
     Format(FormatError) Std; From;,
     Io(IoError) Std; From;,
     ParseInt(ParseIntError) Std; From;,
+    ParseColor(ParseColorError) Std; From;,
+    ParseMention(ParseMentionError) Std; From;,
+    ParseTimestamp(ParseTimestampError) Std; From;,
+    Embed(EmbedError) Std; From;,
+    Component(ComponentError) Std; From;,
+    CreateMessage(CreateMessageError) Std; From;,
+    Moderation(ModerationError) Std; From;,
+    Ban(BanError) Std; From;,
+    ReasonTooLong(ReasonTooLong) Std; From;,
+    Image(ImageError) Std; From;,
+    Sticker(StickerError) Std; From;,
+    Thread(ThreadError) Std; From;,
+    Forum(ForumError) Std; From;,
+    Command(CommandError) Std; From;,
+    Argument(ArgumentError) Std; From;,
+    Interaction(InteractionError) Std; From;,
+    Http(reqwest::Error) Std; From;,
+    Json(serde_json::Error) Std; From;,
+    Api(ApiError) Std; From;,
+    // the bucket that kept on being ratelimited
+    Ratelimited(String),
     ConfigurationError(&'static str),
 }
@@ -0,0 +1,409 @@
+use futures::{stream, Stream, TryStreamExt};
+use serde::Serialize;
+
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        channel::{
+            message::{
+                BulkDeletePlan, BulkDeleteReport, CreateMessage, EditMessage, Message,
+                MessageCursor, MessageHistory,
+            },
+            Channel,
+        },
+        emoji::Emoji,
+        user::User,
+    },
+    snowflake::Snowflake,
+    timestamp::Timestamp,
+};
+
+// the json error code given for messages that do not exist
+const UNKNOWN_MESSAGE: u32 = 10008;
+
+#[derive(Serialize)]
+struct BulkDelete<'a> {
+    messages: &'a [Snowflake<Message>],
+}
+
+impl Http {
+    /// Gets a channel by its id.
+    pub async fn get_channel(&self, channel: Snowflake<Channel>) -> Result<Channel, Error> {
+        self.fire(Request::get(route!("/channels/{}", channel)))
+            .await
+    }
+
+    /// Gets a page of at most 100 messages from a channel, this requires `READ_MESSAGE_HISTORY` in guilds.
+    ///
+    /// Without a cursor the newest messages are given. See [`Http::messages`] to go through more of the history.
+    pub async fn get_messages(
+        &self,
+        channel: Snowflake<Channel>,
+        cursor: Option<MessageCursor>,
+        limit: Option<u8>,
+    ) -> Result<Vec<Message>, Error> {
+        let mut request = Request::get(route!("/channels/{}/messages", channel));
+        request = match cursor {
+            Some(MessageCursor::Before(before)) => request.query("before", before),
+            Some(MessageCursor::After(after)) => request.query("after", after),
+            Some(MessageCursor::Around(around)) => request.query("around", around),
+            None => request,
+        };
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+
+    /// Streams the history of a channel, requesting the messages 100 at a time as the stream is read.
+    ///
+    /// The messages are given in the order they are read in, see [`MessageHistory`]. Rate limits are waited out between the pages.
+    pub fn messages(
+        &self,
+        channel: Snowflake<Channel>,
+        history: MessageHistory,
+    ) -> impl Stream<Item = Result<Message, Error>> + '_ {
+        // the state is the cursor of the next page and how many messages are left, or `None` when the history is done
+        stream::try_unfold(
+            Some((history.cursor, history.limit)),
+            move |state| async move {
+                let (cursor, remaining) = match state {
+                    Some(state) => state,
+                    None => return Ok::<_, Error>(None),
+                };
+                let limit = remaining.map_or(100, |r| r.min(100));
+                if limit == 0 {
+                    return Ok(None);
+                }
+                let mut page = self
+                    .get_messages(channel, cursor, Some(limit as u8))
+                    .await?;
+                let full = page.len() == limit;
+                // discord gives pages newest first, except for `after` pages which are not documented to be sorted
+                if history.forwards() {
+                    page.sort_by_key(|m| m.id);
+                } else {
+                    page.sort_by_key(|m| std::cmp::Reverse(m.id));
+                }
+                let in_bound = page
+                    .iter()
+                    .position(|m| history.is_past_bound(m))
+                    .unwrap_or(page.len());
+                let done = !full || in_bound < page.len();
+                page.truncate(in_bound);
+                let next = match (cursor, page.last()) {
+                    (Some(MessageCursor::Around(_)), _) | (_, None) => None,
+                    _ if done => None,
+                    (Some(MessageCursor::After(_)), Some(last)) => Some((
+                        Some(MessageCursor::After(last.id)),
+                        remaining.map(|r| r - page.len()),
+                    )),
+                    (_, Some(last)) => Some((
+                        Some(MessageCursor::Before(last.id)),
+                        remaining.map(|r| r - page.len()),
+                    )),
+                };
+                Ok(Some((stream::iter(page.into_iter().map(Ok)), next)))
+            },
+        )
+        .try_flatten()
+    }
+
+    /// Gets a single message, this requires `READ_MESSAGE_HISTORY` in guilds.
+    pub async fn get_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+    ) -> Result<Message, Error> {
+        self.fire(Request::get(route!(
+            "/channels/{}/messages/{}",
+            channel,
+            message
+        )))
+        .await
+    }
+
+    /// Sends a message in a channel, this requires the `SEND_MESSAGES` permission in guilds.
+    ///
+    /// The message is checked with [`CreateMessage::validate`] before it is sent.
+    pub async fn create_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: &CreateMessage,
+    ) -> Result<Message, Error> {
+        message.validate()?;
+        self.fire(
+            Request::post(route!("/channels/{}/messages", channel))
+                .json_with_files(message, &message.files)?,
+        )
+        .await
+    }
+
+    /// Edits a message, only messages sent by the bot can have their content changed.
+    ///
+    /// The edit is checked with [`EditMessage::validate`] before it is sent.
+    pub async fn edit_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        edit: &EditMessage,
+    ) -> Result<Message, Error> {
+        edit.validate()?;
+        self.fire(
+            Request::patch(route!("/channels/{}/messages/{}", channel, message))
+                .json_with_files(edit, &edit.files)?,
+        )
+        .await
+    }
+
+    /// Deletes a message, messages sent by other users require `MANAGE_MESSAGES`.
+    pub async fn delete_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::delete(route!("/channels/{}/messages/{}", channel, message)).reason(reason)?,
+        )
+        .await
+    }
+
+    /// Deletes messages, discord requires `MANAGE_MESSAGES` for messages by other users.
+    ///
+    /// The messages are deleted in bulk 100 at a time. Messages older than 14 days can not be deleted in bulk,
+    /// so they, and any message left over on its own, are deleted one by one. Messages which do not exist are skipped.
+    ///
+    /// Deleting stops at the first request that fails, the report then has the error and the messages which were not deleted,
+    /// alongside the ones that were.
+    pub async fn bulk_delete(
+        &self,
+        channel: Snowflake<Channel>,
+        messages: &[Snowflake<Message>],
+        reason: Option<&str>,
+    ) -> Result<BulkDeleteReport, Error> {
+        let plan = BulkDeletePlan::new(messages, Timestamp::now());
+        let mut report = BulkDeleteReport {
+            skipped: plan.duplicates,
+            ..BulkDeleteReport::default()
+        };
+        let mut chunks = plan.chunks.into_iter();
+        while let Some(chunk) = chunks.next() {
+            let deleted = self
+                .fire_empty(
+                    Request::post(route!("/channels/{}/messages/bulk-delete", channel))
+                        .reason(reason)?
+                        .json(&BulkDelete { messages: &chunk })?,
+                )
+                .await;
+            match deleted {
+                Ok(()) => report.bulk_deleted.extend(chunk),
+                Err(error) => {
+                    report.failed.extend(chunk);
+                    report.failed.extend(chunks.flatten().chain(plan.single));
+                    report.error = Some(error);
+                    return Ok(report);
+                }
+            }
+        }
+        let mut single = plan.single.into_iter();
+        while let Some(message) = single.next() {
+            match self.delete_message(channel, message, reason).await {
+                Ok(()) => report.single_deleted.push(message),
+                Err(Error::Api(error)) if error.code == UNKNOWN_MESSAGE => {
+                    report.skipped.push(message)
+                }
+                Err(error) => {
+                    report.failed.push(message);
+                    report.failed.extend(single);
+                    report.error = Some(error);
+                    return Ok(report);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Publishes a message in an announcement channel to the channels following it.
+    ///
+    /// Messages sent by other users require `MANAGE_MESSAGES`.
+    pub async fn crosspost_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+    ) -> Result<Message, Error> {
+        self.fire(Request::post(route!(
+            "/channels/{}/messages/{}/crosspost",
+            channel,
+            message
+        )))
+        .await
+    }
+
+    /// Gets the pinned messages of a channel, newest first.
+    pub async fn get_pinned_messages(
+        &self,
+        channel: Snowflake<Channel>,
+    ) -> Result<Vec<Message>, Error> {
+        self.fire(Request::get(route!("/channels/{}/pins", channel)))
+            .await
+    }
+
+    /// Pins a message, this requires `MANAGE_MESSAGES`. A channel can have at most 50 pinned messages.
+    pub async fn pin_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::put(route!("/channels/{}/pins/{}", channel, message)).reason(reason)?,
+        )
+        .await
+    }
+
+    /// Unpins a message, this requires `MANAGE_MESSAGES`.
+    pub async fn unpin_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::delete(route!("/channels/{}/pins/{}", channel, message)).reason(reason)?,
+        )
+        .await
+    }
+
+    /// Reacts to a message, this requires `READ_MESSAGE_HISTORY`, and `ADD_REACTIONS` when no one has reacted with the emoji yet.
+    pub async fn create_reaction(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        emoji: &Emoji,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::put(route!(
+            "/channels/{}/messages/{}/reactions/{}/@me",
+            channel,
+            message,
+            emoji.to_reaction()
+        )))
+        .await
+    }
+
+    /// Removes the bot's reaction from a message.
+    pub async fn delete_own_reaction(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        emoji: &Emoji,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::delete(route!(
+            "/channels/{}/messages/{}/reactions/{}/@me",
+            channel,
+            message,
+            emoji.to_reaction()
+        )))
+        .await
+    }
+
+    /// Removes another user's reaction from a message, this requires `MANAGE_MESSAGES`.
+    pub async fn delete_user_reaction(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        emoji: &Emoji,
+        user: Snowflake<User>,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::delete(route!(
+            "/channels/{}/messages/{}/reactions/{}/{}",
+            channel,
+            message,
+            emoji.to_reaction(),
+            user
+        )))
+        .await
+    }
+
+    /// Gets the users who reacted with an emoji, at most 100 at a time. `after` gives the users after a user id.
+    ///
+    /// See [`Http::reactors`] to go through all of them.
+    pub async fn get_reactions(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        emoji: &Emoji,
+        after: Option<Snowflake<User>>,
+        limit: Option<u8>,
+    ) -> Result<Vec<User>, Error> {
+        let mut request = Request::get(route!(
+            "/channels/{}/messages/{}/reactions/{}",
+            channel,
+            message,
+            emoji.to_reaction()
+        ));
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+
+    /// Streams every user who reacted with an emoji, requesting them 100 at a time as the stream is read.
+    pub fn reactors<'a>(
+        &'a self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        emoji: &'a Emoji,
+    ) -> impl Stream<Item = Result<User, Error>> + 'a {
+        // the state is the user to start after, or `None` when there are no users left
+        stream::try_unfold(Some(None), move |after| async move {
+            let after = match after {
+                Some(after) => after,
+                None => return Ok::<_, Error>(None),
+            };
+            let page = self
+                .get_reactions(channel, message, emoji, after, Some(100))
+                .await?;
+            let next = match page.last() {
+                Some(user) if page.len() == 100 => Some(Some(user.id)),
+                _ => None,
+            };
+            Ok(Some((stream::iter(page.into_iter().map(Ok)), next)))
+        })
+        .try_flatten()
+    }
+
+    /// Removes every reaction from a message, this requires `MANAGE_MESSAGES`.
+    pub async fn delete_all_reactions(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::delete(route!(
+            "/channels/{}/messages/{}/reactions",
+            channel,
+            message
+        )))
+        .await
+    }
+
+    /// Removes every reaction with an emoji from a message, this requires `MANAGE_MESSAGES`.
+    pub async fn delete_all_reactions_for_emoji(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        emoji: &Emoji,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::delete(route!(
+            "/channels/{}/messages/{}/reactions/{}",
+            channel,
+            message,
+            emoji.to_reaction()
+        )))
+        .await
+    }
+}
@@ -0,0 +1,218 @@
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        application::{
+            command::{ApplicationCommand, CreateCommand},
+            Application,
+        },
+        guild::Guild,
+    },
+    snowflake::Snowflake,
+};
+
+impl Http {
+    /// Gets the application of the bot.
+    pub async fn get_current_application(&self) -> Result<Application, Error> {
+        self.fire(Request::get(route!("/applications/@me"))).await
+    }
+
+    /// Lists the global commands of the bot, `with_localizations` gives every localization instead of only the bot's locale.
+    pub async fn get_global_commands(
+        &self,
+        with_localizations: bool,
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        let application = self.application_id().await?;
+        self.fire(
+            Request::get(route!("/applications/{}/commands", application))
+                .query("with_localizations", with_localizations),
+        )
+        .await
+    }
+
+    /// Creates a global command, replacing the command with the same name and type if there is one.
+    ///
+    /// New commands count towards a limit of 200 created a day. The command is checked with [`CreateCommand::validate`] before it is created.
+    pub async fn create_global_command(
+        &self,
+        command: &CreateCommand,
+    ) -> Result<ApplicationCommand, Error> {
+        command.validate()?;
+        let application = self.application_id().await?;
+        self.fire(Request::post(route!("/applications/{}/commands", application)).json(command)?)
+            .await
+    }
+
+    /// Gets a global command.
+    pub async fn get_global_command(
+        &self,
+        command: Snowflake<ApplicationCommand>,
+    ) -> Result<ApplicationCommand, Error> {
+        let application = self.application_id().await?;
+        self.fire(Request::get(route!(
+            "/applications/{}/commands/{}",
+            application,
+            command
+        )))
+        .await
+    }
+
+    /// Edits a global command.
+    ///
+    /// The command is checked with [`CreateCommand::validate`] before it is edited.
+    pub async fn edit_global_command(
+        &self,
+        command: Snowflake<ApplicationCommand>,
+        edit: &CreateCommand,
+    ) -> Result<ApplicationCommand, Error> {
+        edit.validate()?;
+        let application = self.application_id().await?;
+        self.fire(
+            Request::patch(route!("/applications/{}/commands/{}", application, command))
+                .json(edit)?,
+        )
+        .await
+    }
+
+    /// Deletes a global command.
+    pub async fn delete_global_command(
+        &self,
+        command: Snowflake<ApplicationCommand>,
+    ) -> Result<(), Error> {
+        let application = self.application_id().await?;
+        self.fire_empty(Request::delete(route!(
+            "/applications/{}/commands/{}",
+            application,
+            command
+        )))
+        .await
+    }
+
+    /// Replaces every global command with these commands, commands which are not given are deleted.
+    ///
+    /// Commands which already exist do not count towards the daily limit. The commands are checked with [`CreateCommand::validate`] first.
+    pub async fn bulk_overwrite_global_commands(
+        &self,
+        commands: &[CreateCommand],
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        commands.iter().try_for_each(CreateCommand::validate)?;
+        let application = self.application_id().await?;
+        self.fire(Request::put(route!("/applications/{}/commands", application)).json(commands)?)
+            .await
+    }
+
+    /// Lists the commands of the bot in a guild, `with_localizations` gives every localization instead of only the bot's locale.
+    pub async fn get_guild_commands(
+        &self,
+        guild: Snowflake<Guild>,
+        with_localizations: bool,
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        let application = self.application_id().await?;
+        self.fire(
+            Request::get(route!(
+                "/applications/{}/guilds/{}/commands",
+                application,
+                guild
+            ))
+            .query("with_localizations", with_localizations),
+        )
+        .await
+    }
+
+    /// Creates a command in a guild, replacing the command with the same name and type if there is one.
+    ///
+    /// The command is checked with [`CreateCommand::validate`] before it is created.
+    pub async fn create_guild_command(
+        &self,
+        guild: Snowflake<Guild>,
+        command: &CreateCommand,
+    ) -> Result<ApplicationCommand, Error> {
+        command.validate()?;
+        let application = self.application_id().await?;
+        self.fire(
+            Request::post(route!(
+                "/applications/{}/guilds/{}/commands",
+                application,
+                guild
+            ))
+            .json(command)?,
+        )
+        .await
+    }
+
+    /// Gets a command in a guild.
+    pub async fn get_guild_command(
+        &self,
+        guild: Snowflake<Guild>,
+        command: Snowflake<ApplicationCommand>,
+    ) -> Result<ApplicationCommand, Error> {
+        let application = self.application_id().await?;
+        self.fire(Request::get(route!(
+            "/applications/{}/guilds/{}/commands/{}",
+            application,
+            guild,
+            command
+        )))
+        .await
+    }
+
+    /// Edits a command in a guild.
+    ///
+    /// The command is checked with [`CreateCommand::validate`] before it is edited.
+    pub async fn edit_guild_command(
+        &self,
+        guild: Snowflake<Guild>,
+        command: Snowflake<ApplicationCommand>,
+        edit: &CreateCommand,
+    ) -> Result<ApplicationCommand, Error> {
+        edit.validate()?;
+        let application = self.application_id().await?;
+        self.fire(
+            Request::patch(route!(
+                "/applications/{}/guilds/{}/commands/{}",
+                application,
+                guild,
+                command
+            ))
+            .json(edit)?,
+        )
+        .await
+    }
+
+    /// Deletes a command in a guild.
+    pub async fn delete_guild_command(
+        &self,
+        guild: Snowflake<Guild>,
+        command: Snowflake<ApplicationCommand>,
+    ) -> Result<(), Error> {
+        let application = self.application_id().await?;
+        self.fire_empty(Request::delete(route!(
+            "/applications/{}/guilds/{}/commands/{}",
+            application,
+            guild,
+            command
+        )))
+        .await
+    }
+
+    /// Replaces every command in a guild with these commands, commands which are not given are deleted.
+    ///
+    /// The commands are checked with [`CreateCommand::validate`] first.
+    pub async fn bulk_overwrite_guild_commands(
+        &self,
+        guild: Snowflake<Guild>,
+        commands: &[CreateCommand],
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        commands.iter().try_for_each(CreateCommand::validate)?;
+        let application = self.application_id().await?;
+        self.fire(
+            Request::put(route!(
+                "/applications/{}/guilds/{}/commands",
+                application,
+                guild
+            ))
+            .json(commands)?,
+        )
+        .await
+    }
+}
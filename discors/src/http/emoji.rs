@@ -0,0 +1,72 @@
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        emoji::{CreateEmoji, CustomEmoji, EditEmoji},
+        guild::Guild,
+    },
+    snowflake::Snowflake,
+};
+
+impl Http {
+    /// Lists the custom emojis of a guild.
+    pub async fn list_guild_emojis(
+        &self,
+        guild: Snowflake<Guild>,
+    ) -> Result<Vec<CustomEmoji>, Error> {
+        self.fire(Request::get(route!("/guilds/{}/emojis", guild)))
+            .await
+    }
+
+    /// Gets a custom emoji of a guild.
+    pub async fn get_guild_emoji(
+        &self,
+        guild: Snowflake<Guild>,
+        emoji: Snowflake<CustomEmoji>,
+    ) -> Result<CustomEmoji, Error> {
+        self.fire(Request::get(route!("/guilds/{}/emojis/{}", guild, emoji)))
+            .await
+    }
+
+    /// Uploads a custom emoji to a guild, this requires `MANAGE_GUILD_EXPRESSIONS`.
+    pub async fn create_guild_emoji(
+        &self,
+        guild: Snowflake<Guild>,
+        emoji: &CreateEmoji,
+    ) -> Result<CustomEmoji, Error> {
+        self.fire(
+            Request::post(route!("/guilds/{}/emojis", guild))
+                .reason(emoji.reason.as_deref())?
+                .json(emoji)?,
+        )
+        .await
+    }
+
+    /// Modifies a custom emoji, this requires `MANAGE_GUILD_EXPRESSIONS`.
+    pub async fn modify_guild_emoji(
+        &self,
+        guild: Snowflake<Guild>,
+        emoji: Snowflake<CustomEmoji>,
+        edit: &EditEmoji,
+    ) -> Result<CustomEmoji, Error> {
+        self.fire(
+            Request::patch(route!("/guilds/{}/emojis/{}", guild, emoji))
+                .reason(edit.reason.as_deref())?
+                .json(edit)?,
+        )
+        .await
+    }
+
+    /// Deletes a custom emoji, this requires `MANAGE_GUILD_EXPRESSIONS`.
+    pub async fn delete_guild_emoji(
+        &self,
+        guild: Snowflake<Guild>,
+        emoji: Snowflake<CustomEmoji>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::delete(route!("/guilds/{}/emojis/{}", guild, emoji)).reason(reason)?,
+        )
+        .await
+    }
+}
@@ -0,0 +1,397 @@
+use std::time::Duration;
+
+use futures::{stream, Stream, TryStreamExt};
+use serde_json::json;
+
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        channel::Channel,
+        guild::{
+            audit_log::{AuditLog, AuditLogEntry, AuditLogEvent},
+            ban::{Ban, BulkBan, BulkBanResponse, CreateBan},
+            member::{AddMember, EditMember, Member, ModerationError},
+            role::Role,
+            Guild,
+        },
+        user::User,
+    },
+    snowflake::Snowflake,
+    timestamp::Timestamp,
+};
+
+impl Http {
+    /// Gets a guild by its id, `with_counts` sets the approximate member and presence counts.
+    pub async fn get_guild(
+        &self,
+        guild: Snowflake<Guild>,
+        with_counts: bool,
+    ) -> Result<Guild, Error> {
+        self.fire(Request::get(route!("/guilds/{}", guild)).query("with_counts", with_counts))
+            .await
+    }
+
+    /// Gets the channels of a guild, not including threads.
+    pub async fn get_guild_channels(&self, guild: Snowflake<Guild>) -> Result<Vec<Channel>, Error> {
+        self.fire(Request::get(route!("/guilds/{}/channels", guild)))
+            .await
+    }
+
+    /// Gets a member of a guild.
+    pub async fn get_member(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+    ) -> Result<Member, Error> {
+        self.fire(Request::get(route!("/guilds/{}/members/{}", guild, user)))
+            .await
+    }
+
+    /// Lists the members of a guild sorted by user id, at most 1000 at a time. `after` gives the members after a user id.
+    ///
+    /// This requires the `GUILD_MEMBERS` intent.
+    pub async fn list_members(
+        &self,
+        guild: Snowflake<Guild>,
+        after: Option<Snowflake<User>>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Member>, Error> {
+        let mut request = Request::get(route!("/guilds/{}/members", guild));
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+
+    /// Gets every member of a guild, by listing them 1000 at a time.
+    ///
+    /// This requires the `GUILD_MEMBERS` intent, and can take many requests in large guilds.
+    /// Listing stops early if a page ends with a member without a user, as the next page can't be found.
+    pub async fn get_all_members(&self, guild: Snowflake<Guild>) -> Result<Vec<Member>, Error> {
+        let mut members = Vec::new();
+        let mut after = None;
+        loop {
+            let page = self.list_members(guild, after, Some(1000)).await?;
+            let done = page.len() < 1000;
+            // the next page starts after the last user, stop if that can't move forward
+            let next = page.last().and_then(|m| m.user.as_ref()).map(|u| u.id);
+            members.extend(page);
+            if done || next.is_none() || next <= after {
+                return Ok(members);
+            }
+            after = next;
+        }
+    }
+
+    /// Searches for members whose username or nickname starts with `query`, giving at most `limit` (up to 1000) members.
+    pub async fn search_members(
+        &self,
+        guild: Snowflake<Guild>,
+        query: &str,
+        limit: Option<u16>,
+    ) -> Result<Vec<Member>, Error> {
+        let mut request =
+            Request::get(route!("/guilds/{}/members/search", guild)).query("query", query);
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+
+    /// Adds a user to a guild with an OAuth2 access token, giving `None` if they were already a member.
+    ///
+    /// This requires the bot to have `CREATE_INSTANT_INVITE` in the guild.
+    pub async fn add_member(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+        member: &AddMember,
+    ) -> Result<Option<Member>, Error> {
+        let bytes = self
+            .request(Request::put(route!("/guilds/{}/members/{}", guild, user)).json(member)?)
+            .await?
+            .bytes()
+            .await?;
+        // discord responds with no content when the user is already a member
+        if bytes.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_slice(&bytes)?))
+    }
+
+    /// Modifies a member of a guild, the permissions needed depend on what is changed.
+    pub async fn modify_member(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+        edit: &EditMember,
+    ) -> Result<Member, Error> {
+        self.fire(
+            Request::patch(route!("/guilds/{}/members/{}", guild, user))
+                .reason(edit.reason.as_deref())?
+                .json(edit)?,
+        )
+        .await
+    }
+
+    /// Sets the nickname of the bot in a guild, `None` removes it.
+    pub async fn modify_current_member(
+        &self,
+        guild: Snowflake<Guild>,
+        nick: Option<&str>,
+        reason: Option<&str>,
+    ) -> Result<Member, Error> {
+        self.fire(
+            Request::patch(route!("/guilds/{}/members/@me", guild))
+                .reason(reason)?
+                .json(&json!({ "nick": nick }))?,
+        )
+        .await
+    }
+
+    /// Removes (kicks) a member from a guild, this requires `KICK_MEMBERS`.
+    pub async fn remove_member(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::delete(route!("/guilds/{}/members/{}", guild, user)).reason(reason)?,
+        )
+        .await
+    }
+
+    /// Gives a member a role, this requires `MANAGE_ROLES`.
+    pub async fn add_member_role(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+        role: Snowflake<Role>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::put(route!("/guilds/{}/members/{}/roles/{}", guild, user, role))
+                .reason(reason)?,
+        )
+        .await
+    }
+
+    /// Takes a role from a member, this requires `MANAGE_ROLES`.
+    pub async fn remove_member_role(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+        role: Snowflake<Role>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::delete(route!("/guilds/{}/members/{}/roles/{}", guild, user, role))
+                .reason(reason)?,
+        )
+        .await
+    }
+
+    /// Times a member out for a duration, at most [`Member::MAX_TIMEOUT`], this requires `MODERATE_MEMBERS`.
+    ///
+    /// Members which discord would not let the bot time out, like administrators or members with a higher top role, are refused
+    /// with a [`ModerationError`] before making the request.
+    pub async fn timeout(
+        &self,
+        guild: &Guild,
+        member: &Member,
+        duration: Duration,
+        reason: Option<&str>,
+    ) -> Result<Member, Error> {
+        if duration > Member::MAX_TIMEOUT {
+            return Err(ModerationError::TimeoutTooLong(duration).into());
+        }
+        self.moderate(
+            guild,
+            member,
+            &EditMember::new().timeout_until(Some(Timestamp::now() + duration)),
+            reason,
+        )
+        .await
+    }
+
+    /// Removes the timeout of a member, this requires `MODERATE_MEMBERS`.
+    ///
+    /// This is refused the same way as [`Http::timeout`].
+    pub async fn remove_timeout(
+        &self,
+        guild: &Guild,
+        member: &Member,
+        reason: Option<&str>,
+    ) -> Result<Member, Error> {
+        self.moderate(
+            guild,
+            member,
+            &EditMember::new().timeout_until(None),
+            reason,
+        )
+        .await
+    }
+
+    // Modifies a member after checking the bot can moderate them.
+    async fn moderate(
+        &self,
+        guild: &Guild,
+        member: &Member,
+        edit: &EditMember,
+        reason: Option<&str>,
+    ) -> Result<Member, Error> {
+        let user = member.user.as_ref().ok_or(ModerationError::MissingUser)?.id;
+        let bot = self
+            .get_member(guild.id, self.current_user_id().await?)
+            .await?;
+        guild.can_moderate(&bot, member)?;
+        self.fire(
+            Request::patch(route!("/guilds/{}/members/{}", guild.id, user))
+                .reason(reason)?
+                .json(edit)?,
+        )
+        .await
+    }
+
+    /// Lists the bans of a guild sorted by user id, at most 1000 at a time. This requires `BAN_MEMBERS`.
+    ///
+    /// `before` and `after` give the bans before or after a user id, see [`Http::bans`] to go through all bans.
+    pub async fn list_bans(
+        &self,
+        guild: Snowflake<Guild>,
+        before: Option<Snowflake<User>>,
+        after: Option<Snowflake<User>>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Ban>, Error> {
+        let mut request = Request::get(route!("/guilds/{}/bans", guild));
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+
+    /// Streams every ban of a guild, requesting them 1000 at a time as the stream is read. This requires `BAN_MEMBERS`.
+    ///
+    /// ```rust,no_run
+    /// # async fn run(http: discors::http::Http, guild: discors::Snowflake<discors::models::guild::Guild>) -> Result<(), discors::error::Error> {
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut bans = Box::pin(http.bans(guild));
+    /// while let Some(ban) = bans.try_next().await? {
+    ///     println!("{} is banned", ban.user.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bans(&self, guild: Snowflake<Guild>) -> impl Stream<Item = Result<Ban, Error>> + '_ {
+        // the state is the user to start after, or `None` when there are no bans left
+        stream::try_unfold(Some(None), move |after| async move {
+            let after = match after {
+                Some(after) => after,
+                None => return Ok::<_, Error>(None),
+            };
+            let page = self.list_bans(guild, None, after, Some(1000)).await?;
+            let next = match page.last() {
+                Some(ban) if page.len() == 1000 => Some(Some(ban.user.id)),
+                _ => None,
+            };
+            Ok(Some((stream::iter(page.into_iter().map(Ok)), next)))
+        })
+        .try_flatten()
+    }
+
+    /// Gets the ban of a user, this requires `BAN_MEMBERS`.
+    pub async fn get_ban(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+    ) -> Result<Ban, Error> {
+        self.fire(Request::get(route!("/guilds/{}/bans/{}", guild, user)))
+            .await
+    }
+
+    /// Bans a user from a guild, this requires `BAN_MEMBERS`.
+    ///
+    /// The ban is checked with [`CreateBan::validate`] before it is sent.
+    pub async fn create_ban(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+        ban: &CreateBan,
+    ) -> Result<(), Error> {
+        ban.validate()?;
+        self.fire_empty(
+            Request::put(route!("/guilds/{}/bans/{}", guild, user))
+                .reason(ban.reason.as_deref())?
+                .json(ban)?,
+        )
+        .await
+    }
+
+    /// Unbans a user, this requires `BAN_MEMBERS`.
+    pub async fn remove_ban(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Snowflake<User>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::delete(route!("/guilds/{}/bans/{}", guild, user)).reason(reason)?)
+            .await
+    }
+
+    /// Bans up to 200 users at once, this requires `BAN_MEMBERS` and `MANAGE_GUILD`.
+    ///
+    /// The ban is checked with [`BulkBan::validate`] before it is sent.
+    pub async fn bulk_ban(
+        &self,
+        guild: Snowflake<Guild>,
+        ban: &BulkBan,
+    ) -> Result<BulkBanResponse, Error> {
+        ban.validate()?;
+        self.fire(
+            Request::post(route!("/guilds/{}/bulk-ban", guild))
+                .reason(ban.reason.as_deref())?
+                .json(ban)?,
+        )
+        .await
+    }
+
+    /// Gets the audit log of a guild, newest first, this requires `VIEW_AUDIT_LOG`.
+    ///
+    /// The entries can be filtered by who made them and which action they are, `before` gives the entries before an entry,
+    /// and at most `limit` (up to 100) entries are given.
+    pub async fn get_audit_log(
+        &self,
+        guild: Snowflake<Guild>,
+        user: Option<Snowflake<User>>,
+        action: Option<AuditLogEvent>,
+        before: Option<Snowflake<AuditLogEntry>>,
+        limit: Option<u8>,
+    ) -> Result<AuditLog, Error> {
+        let mut request = Request::get(route!("/guilds/{}/audit-logs", guild));
+        if let Some(user) = user {
+            request = request.query("user_id", user);
+        }
+        if let Some(action) = action {
+            request = request.query("action_type", u16::from(action));
+        }
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+}
@@ -0,0 +1,143 @@
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        application::interaction::{Interaction, InteractionResponse},
+        channel::message::{CreateMessage, EditMessage, Message},
+    },
+    snowflake::Snowflake,
+};
+
+impl Http {
+    /// Sends the initial response to an interaction, which has to be done within 3 seconds of it being created.
+    ///
+    /// The response is checked with [`InteractionResponse::validate`] before it is sent.
+    pub async fn create_interaction_response(
+        &self,
+        interaction: Snowflake<Interaction>,
+        token: &str,
+        response: &InteractionResponse,
+    ) -> Result<(), Error> {
+        response.validate()?;
+        self.fire_empty(
+            Request::post(route!("/interactions/{}/{}/callback", interaction, token))
+                .json_with_files_in(response, Some("data"), response.files())?,
+        )
+        .await
+    }
+
+    /// Gets the message sent as the initial response to an interaction.
+    pub async fn get_original_response(&self, token: &str) -> Result<Message, Error> {
+        let application = self.application_id().await?;
+        self.fire(Request::get(route!(
+            "/webhooks/{}/{}/messages/@original",
+            application,
+            token
+        )))
+        .await
+    }
+
+    /// Edits the initial response to an interaction, this also sends the message of a deferred response.
+    ///
+    /// The edit is checked with [`EditMessage::validate`] before it is sent.
+    pub async fn edit_original_response(
+        &self,
+        token: &str,
+        edit: &EditMessage,
+    ) -> Result<Message, Error> {
+        edit.validate()?;
+        let application = self.application_id().await?;
+        self.fire(
+            Request::patch(route!(
+                "/webhooks/{}/{}/messages/@original",
+                application,
+                token
+            ))
+            .json_with_files(edit, &edit.files)?,
+        )
+        .await
+    }
+
+    /// Deletes the initial response to an interaction.
+    pub async fn delete_original_response(&self, token: &str) -> Result<(), Error> {
+        let application = self.application_id().await?;
+        self.fire_empty(Request::delete(route!(
+            "/webhooks/{}/{}/messages/@original",
+            application,
+            token
+        )))
+        .await
+    }
+
+    /// Sends a follow-up message to an interaction, which can be done for 15 minutes after it was created.
+    ///
+    /// The first follow-up to a deferred response replaces its loading state. The message is checked with [`CreateMessage::validate`] before it is sent.
+    pub async fn create_followup(
+        &self,
+        token: &str,
+        message: &CreateMessage,
+    ) -> Result<Message, Error> {
+        message.validate()?;
+        let application = self.application_id().await?;
+        self.fire(
+            Request::post(route!("/webhooks/{}/{}", application, token))
+                .json_with_files(message, &message.files)?,
+        )
+        .await
+    }
+
+    /// Gets a follow-up message of an interaction.
+    pub async fn get_followup(
+        &self,
+        token: &str,
+        message: Snowflake<Message>,
+    ) -> Result<Message, Error> {
+        let application = self.application_id().await?;
+        self.fire(Request::get(route!(
+            "/webhooks/{}/{}/messages/{}",
+            application,
+            token,
+            message
+        )))
+        .await
+    }
+
+    /// Edits a follow-up message of an interaction.
+    ///
+    /// The edit is checked with [`EditMessage::validate`] before it is sent.
+    pub async fn edit_followup(
+        &self,
+        token: &str,
+        message: Snowflake<Message>,
+        edit: &EditMessage,
+    ) -> Result<Message, Error> {
+        edit.validate()?;
+        let application = self.application_id().await?;
+        self.fire(
+            Request::patch(route!(
+                "/webhooks/{}/{}/messages/{}",
+                application,
+                token,
+                message
+            ))
+            .json_with_files(edit, &edit.files)?,
+        )
+        .await
+    }
+
+    /// Deletes a follow-up message of an interaction.
+    pub async fn delete_followup(
+        &self,
+        token: &str,
+        message: Snowflake<Message>,
+    ) -> Result<(), Error> {
+        let application = self.application_id().await?;
+        self.fire_empty(Request::delete(route!(
+            "/webhooks/{}/{}/messages/{}",
+            application,
+            token,
+            message
+        )))
+        .await
+    }
+}
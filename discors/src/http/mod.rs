@@ -0,0 +1,454 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+    time::Duration,
+};
+
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT},
+    multipart::Form,
+    Client, Method, Response, StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use self::ratelimit::Ratelimiter;
+use crate::{
+    error::Error,
+    models::{
+        application::Application,
+        channel::attachment::AttachmentFile,
+        guild::audit_log::{ReasonTooLong, REASON_LIMIT},
+        user::User,
+    },
+    snowflake::Snowflake,
+};
+
+mod channel;
+mod command;
+mod emoji;
+mod guild;
+mod interaction;
+mod ratelimit;
+mod sticker;
+mod thread;
+mod user;
+
+// Builds a `Route` from a path template, the first parameter is the major parameter which ratelimits are tied to.
+macro_rules! route {
+    ($template:literal) => {
+        $crate::http::Route::new($template, String::new(), $template.to_string())
+    };
+    ($template:literal, $major:expr $(, $param:expr)* $(,)?) => {
+        $crate::http::Route::new($template, $major.to_string(), format!($template, $major $(, $param)*))
+    };
+}
+pub(crate) use route;
+
+/// The http client used to make requests to discord's REST api.
+///
+/// It keeps track of discord's ratelimits, waiting when a ratelimit is hit instead of failing.
+///
+/// [Read more](https://discord.com/developers/docs/reference#http-api)
+pub struct Http {
+    client: Client,
+    ratelimiter: Ratelimiter,
+    // the id of the bot, fetched when it is first needed
+    current_user: tokio::sync::OnceCell<Snowflake<User>>,
+    // the id of the bot's application, fetched when it is first needed
+    application: tokio::sync::OnceCell<Snowflake<Application>>,
+}
+
+impl Debug for Http {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // don't leak the token
+        f.debug_struct("Http").finish_non_exhaustive()
+    }
+}
+
+impl Http {
+    /// Creates a new http client for a bot token. The `Bot ` prefix is added if it is missing.
+    pub fn new(token: &str) -> Result<Self, Error> {
+        let token = if token.starts_with("Bot ") || token.starts_with("Bearer ") {
+            token.to_string()
+        } else {
+            format!("Bot {}", token)
+        };
+        let mut headers = HeaderMap::new();
+        let mut authorization = HeaderValue::from_str(&token)
+            .map_err(|_errr| Error::ConfigurationError("Invalid token passed."))?;
+        authorization.set_sensitive(true);
+        headers.insert(AUTHORIZATION, authorization);
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_static(concat!(
+                "DiscordBot (https://github.com/NiteBlock/discors, ",
+                env!("CARGO_PKG_VERSION"),
+                ")"
+            )),
+        );
+        Ok(Self {
+            client: Client::builder().default_headers(headers).build()?,
+            ratelimiter: Ratelimiter::default(),
+            current_user: tokio::sync::OnceCell::new(),
+            application: tokio::sync::OnceCell::new(),
+        })
+    }
+
+    /// The id of the bot's user, this is only requested once.
+    pub async fn current_user_id(&self) -> Result<Snowflake<User>, Error> {
+        self.current_user
+            .get_or_try_init(|| async { Ok(self.get_current_user().await?.id) })
+            .await
+            .copied()
+    }
+
+    /// The id of the bot's application, this is only requested once.
+    pub async fn application_id(&self) -> Result<Snowflake<Application>, Error> {
+        self.application
+            .get_or_try_init(|| async { Ok(self.get_current_application().await?.id) })
+            .await
+            .copied()
+    }
+
+    // Makes a request, waiting on and retrying ratelimits, and turning error statuses into errors.
+    pub(crate) async fn request(&self, request: Request) -> Result<Response, Error> {
+        let bucket = format!("{} {}", request.method, request.route.bucket);
+        // a ratelimit can be hit even when waiting on the headers, when another client shares the token.
+        for _ in 0..5 {
+            let mut guard = self.ratelimiter.acquire(&bucket).await;
+            let response = self.build(&request).await?.send().await?;
+            let retry_after = self.ratelimiter.update(&mut guard, &response);
+            drop(guard);
+            match response.status() {
+                StatusCode::TOO_MANY_REQUESTS => {
+                    tokio::time::sleep(retry_after.unwrap_or(Duration::from_secs(1))).await;
+                }
+                status if status.is_success() => return Ok(response),
+                status => {
+                    let mut error =
+                        response
+                            .json::<ApiError>()
+                            .await
+                            .unwrap_or_else(|_| ApiError {
+                                status: 0,
+                                code: 0,
+                                message: status.canonical_reason().unwrap_or_default().to_string(),
+                                errors: None,
+                            });
+                    error.status = status.as_u16();
+                    return Err(error.into());
+                }
+            }
+        }
+        Err(Error::Ratelimited(bucket))
+    }
+
+    /// Makes a request, deserializing the response.
+    pub(crate) async fn fire<T>(&self, request: Request) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self.request(request).await?.bytes().await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Makes a request, ignoring the response.
+    pub(crate) async fn fire_empty(&self, request: Request) -> Result<(), Error> {
+        self.request(request).await?;
+        Ok(())
+    }
+
+    async fn build(&self, request: &Request) -> Result<reqwest::RequestBuilder, Error> {
+        let mut builder = self
+            .client
+            .request(
+                request.method.clone(),
+                format!("{}{}", crate::BASE_URL, request.route.path),
+            )
+            .query(&request.query);
+        if let Some(reason) = &request.reason {
+            // headers can only be ascii
+            builder = builder.header(
+                "X-Audit-Log-Reason",
+                utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string(),
+            );
+        }
+        builder = match &request.body {
+            Body::None => builder,
+            Body::Json(json) => builder
+                .header("Content-Type", "application/json")
+                .body(json.clone()),
+            Body::Multipart { fields, files } => {
+                let mut form = Form::new();
+                for (name, value) in fields {
+                    form = form.text(*name, value.clone());
+                }
+                // files are opened again for every attempt, as the form is consumed when sent
+                for (name, file) in files {
+                    form = form.part(name.clone(), file.part().await?);
+                }
+                builder.multipart(form)
+            }
+        };
+        Ok(builder)
+    }
+}
+
+/// A route (path) of the api, with the key of the ratelimit bucket it belongs to.
+#[derive(Debug, Clone)]
+pub(crate) struct Route {
+    pub(crate) path: String,
+    pub(crate) bucket: String,
+}
+
+impl Route {
+    pub(crate) fn new(template: &'static str, major: String, path: String) -> Self {
+        Self {
+            bucket: format!("{} {}", template, major),
+            path,
+        }
+    }
+}
+
+/// A request to the api, before it is sent.
+#[derive(Debug)]
+pub(crate) struct Request {
+    pub(crate) method: Method,
+    pub(crate) route: Route,
+    pub(crate) query: Vec<(&'static str, String)>,
+    // shown in the guild's audit log
+    pub(crate) reason: Option<String>,
+    pub(crate) body: Body,
+}
+
+#[derive(Debug)]
+pub(crate) enum Body {
+    None,
+    Json(Vec<u8>),
+    Multipart {
+        fields: Vec<(&'static str, String)>,
+        // the name of the part, with the file
+        files: Vec<(String, AttachmentFile)>,
+    },
+}
+
+impl Request {
+    pub(crate) fn new(method: Method, route: Route) -> Self {
+        Self {
+            method,
+            route,
+            query: Vec::new(),
+            reason: None,
+            body: Body::None,
+        }
+    }
+    pub(crate) fn get(route: Route) -> Self {
+        Self::new(Method::GET, route)
+    }
+    pub(crate) fn post(route: Route) -> Self {
+        Self::new(Method::POST, route)
+    }
+    pub(crate) fn patch(route: Route) -> Self {
+        Self::new(Method::PATCH, route)
+    }
+    pub(crate) fn put(route: Route) -> Self {
+        Self::new(Method::PUT, route)
+    }
+    pub(crate) fn delete(route: Route) -> Self {
+        Self::new(Method::DELETE, route)
+    }
+    /// Adds a query parameter.
+    pub(crate) fn query(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.query.push((key, value.to_string()));
+        self
+    }
+    /// Sets the reason shown in the audit log, at most 512 characters.
+    pub(crate) fn reason(mut self, reason: Option<&str>) -> Result<Self, Error> {
+        if let Some(reason) = reason {
+            let len = reason.chars().count();
+            if len > REASON_LIMIT {
+                return Err(ReasonTooLong(len).into());
+            }
+        }
+        self.reason = reason.map(str::to_string);
+        Ok(self)
+    }
+    /// Sets the body of the request as json.
+    pub(crate) fn json<T>(mut self, body: &T) -> Result<Self, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.body = Body::Json(serde_json::to_vec(body)?);
+        Ok(self)
+    }
+    /// Sets the body as json, or as a multipart form with the json in `payload_json` when there are files.
+    pub(crate) fn json_with_files<T>(
+        self,
+        body: &T,
+        files: &[AttachmentFile],
+    ) -> Result<Self, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.json_with_files_in(body, None, files)
+    }
+    /// Like [`Request::json_with_files`], but the attachments are listed in a field of the body, like the message of a forum post.
+    pub(crate) fn json_with_files_in<T>(
+        self,
+        body: &T,
+        field: Option<&str>,
+        files: &[AttachmentFile],
+    ) -> Result<Self, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if files.is_empty() {
+            return self.json(body);
+        }
+        let mut payload = serde_json::to_value(body)?;
+        let object = match field {
+            Some(field) => payload.get_mut(field),
+            None => Some(&mut payload),
+        };
+        if let Some(serde_json::Value::Object(object)) = object {
+            // files are referenced by their index in the form, after any attachments that are kept
+            let attachments = object
+                .entry("attachments")
+                .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+            if let serde_json::Value::Array(attachments) = attachments {
+                attachments.extend(files.iter().enumerate().map(|(i, file)| {
+                    let mut attachment =
+                        serde_json::json!({"id": i, "filename": file.upload_filename()});
+                    if let Some(description) = file.get_description() {
+                        attachment["description"] = description.into();
+                    }
+                    attachment
+                }));
+            }
+        }
+        Ok(self.multipart(
+            vec![("payload_json", payload.to_string())],
+            files
+                .iter()
+                .enumerate()
+                .map(|(i, file)| (format!("files[{}]", i), file.clone()))
+                .collect(),
+        ))
+    }
+    /// Sets the body as a multipart form.
+    pub(crate) fn multipart(
+        self,
+        fields: Vec<(&'static str, String)>,
+        files: Vec<(String, AttachmentFile)>,
+    ) -> Self {
+        Self {
+            body: Body::Multipart { fields, files },
+            ..self
+        }
+    }
+}
+
+/// An error returned by discord's api.
+///
+/// [Read more](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ApiError {
+    // the http status code, this is not part of discord's json
+    #[serde(default)]
+    pub status: u16,
+    // discord's json error code
+    #[serde(default)]
+    pub code: u32,
+    pub message: String,
+    // details on which fields were invalid
+    #[serde(default)]
+    pub errors: Option<serde_json::Value>,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (error {}, status {})",
+            self.message, self.code, self.status
+        )
+    }
+}
+
+impl StdError for ApiError {}
+
+#[cfg(test)]
+mod test {
+    use super::{Body, Request};
+    use crate::{
+        models::channel::{attachment::AttachmentFile, Channel},
+        Snowflake,
+    };
+
+    const CHANNEL: Snowflake<Channel> = Snowflake::new(290926798999357250);
+
+    #[test]
+    fn routes() {
+        let route = route!("/channels/{}/messages/{}", CHANNEL, 1);
+        assert_eq!(route.path, "/channels/290926798999357250/messages/1");
+        // the bucket only depends on the major parameter
+        assert_eq!(
+            route.bucket,
+            route!("/channels/{}/messages/{}", CHANNEL, 2).bucket
+        );
+        assert_ne!(
+            route.bucket,
+            route!("/channels/{}/messages/{}", 3, 1).bucket
+        );
+    }
+
+    #[test]
+    fn files() {
+        let request = Request::get(route!("/"))
+            .json_with_files(
+                &serde_json::json!({"content": "hi"}),
+                &[AttachmentFile::from_bytes("a.txt", "a").description("a")],
+            )
+            .unwrap();
+        match request.body {
+            Body::Multipart { fields, files } => {
+                assert_eq!(
+                    fields,
+                    [(
+                        "payload_json",
+                        r#"{"attachments":[{"description":"a","filename":"a.txt","id":0}],"content":"hi"}"#
+                            .to_string()
+                    )]
+                );
+                assert_eq!(files[0].0, "files[0]");
+            }
+            body => panic!("expected a multipart body, got {:?}", body),
+        }
+        let request = Request::get(route!("/"))
+            .json_with_files_in(
+                &serde_json::json!({"name": "post", "message": {}}),
+                Some("message"),
+                &[AttachmentFile::from_bytes("a.txt", "a")],
+            )
+            .unwrap();
+        match request.body {
+            Body::Multipart { fields, .. } => assert_eq!(
+                fields[0].1,
+                r#"{"message":{"attachments":[{"filename":"a.txt","id":0}]},"name":"post"}"#
+            ),
+            body => panic!("expected a multipart body, got {:?}", body),
+        }
+    }
+
+    #[test]
+    fn reason() {
+        let request = Request::get(route!("/"))
+            .reason(Some("spam & raids"))
+            .unwrap();
+        assert_eq!(request.reason.as_deref(), Some("spam & raids"));
+        assert!(Request::get(route!("/"))
+            .reason(Some(&"a".repeat(513)))
+            .is_err());
+    }
+}
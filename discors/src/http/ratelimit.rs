@@ -0,0 +1,99 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex as StdMutex},
+    time::Duration,
+};
+
+use reqwest::{header::HeaderMap, Response};
+use tokio::{
+    sync::{Mutex, OwnedMutexGuard},
+    time::{sleep_until, Instant},
+};
+
+// https://discord.com/developers/docs/topics/rate-limits
+#[derive(Debug, Default)]
+pub(crate) struct Ratelimiter {
+    buckets: StdMutex<HashMap<String, Arc<Mutex<Bucket>>>>,
+    // when the global ratelimit is over, if it was hit
+    global: StdMutex<Option<Instant>>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Bucket {
+    remaining: Option<u64>,
+    reset: Option<Instant>,
+}
+
+impl Ratelimiter {
+    /// Waits until a request can be made in the bucket. Requests in one bucket are made one at a time, by holding the guard.
+    pub(crate) async fn acquire(&self, key: &str) -> OwnedMutexGuard<Bucket> {
+        let bucket = {
+            let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+            buckets.entry(key.to_string()).or_default().clone()
+        };
+        let bucket = bucket.lock_owned().await;
+        let global = *self.global.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(global) = global {
+            sleep_until(global).await;
+        }
+        if let (Some(0), Some(reset)) = (bucket.remaining, bucket.reset) {
+            sleep_until(reset).await;
+        }
+        bucket
+    }
+
+    /// Updates a bucket from the ratelimit headers of a response, giving how long to wait before retrying when it was ratelimited.
+    pub(crate) fn update(&self, bucket: &mut Bucket, response: &Response) -> Option<Duration> {
+        let headers = response.headers();
+        bucket.remaining = header(headers, "x-ratelimit-remaining");
+        bucket.reset =
+            seconds(headers, "x-ratelimit-reset-after").map(|after| Instant::now() + after);
+        if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+        let retry_after = seconds(headers, "retry-after");
+        if headers.contains_key("x-ratelimit-global") {
+            if let Some(retry_after) = retry_after {
+                *self.global.lock().unwrap_or_else(|e| e.into_inner()) =
+                    Some(Instant::now() + retry_after);
+            }
+        }
+        retry_after
+    }
+}
+
+fn header<T>(headers: &HeaderMap, name: &str) -> Option<T>
+where
+    T: std::str::FromStr,
+{
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+// a header of seconds, values which are negative or not finite are ignored instead of panicking
+fn seconds(headers: &HeaderMap, name: &str) -> Option<Duration> {
+    Duration::try_from_secs_f64(header(headers, name)?).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    use super::seconds;
+
+    #[test]
+    fn bad_headers() {
+        let mut headers = HeaderMap::new();
+        let mut after = |value| {
+            headers.insert("retry-after", HeaderValue::from_static(value));
+            seconds(&headers, "retry-after")
+        };
+        assert_eq!(after("1.5"), Some(Duration::from_millis(1500)));
+        assert_eq!(after("-1"), None);
+        assert_eq!(after("NaN"), None);
+        assert_eq!(after("inf"), None);
+        assert_eq!(after("1e400"), None);
+        assert_eq!(after("soon"), None);
+    }
+}
@@ -0,0 +1,106 @@
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        guild::Guild,
+        sticker::{CreateSticker, EditSticker, Sticker, StickerPack},
+    },
+    snowflake::Snowflake,
+};
+
+#[derive(serde::Deserialize)]
+struct StickerPacks {
+    sticker_packs: Vec<StickerPack>,
+}
+
+impl Http {
+    /// Gets a sticker by its id, this can be any standard or guild sticker.
+    pub async fn get_sticker(&self, sticker: Snowflake<Sticker>) -> Result<Sticker, Error> {
+        self.fire(Request::get(route!("/stickers/{}", sticker)))
+            .await
+    }
+
+    /// Lists the packs of standard stickers.
+    pub async fn list_sticker_packs(&self) -> Result<Vec<StickerPack>, Error> {
+        let packs: StickerPacks = self.fire(Request::get(route!("/sticker-packs"))).await?;
+        Ok(packs.sticker_packs)
+    }
+
+    /// Lists the stickers of a guild.
+    pub async fn list_guild_stickers(
+        &self,
+        guild: Snowflake<Guild>,
+    ) -> Result<Vec<Sticker>, Error> {
+        self.fire(Request::get(route!("/guilds/{}/stickers", guild)))
+            .await
+    }
+
+    /// Gets a sticker of a guild.
+    pub async fn get_guild_sticker(
+        &self,
+        guild: Snowflake<Guild>,
+        sticker: Snowflake<Sticker>,
+    ) -> Result<Sticker, Error> {
+        self.fire(Request::get(route!(
+            "/guilds/{}/stickers/{}",
+            guild,
+            sticker
+        )))
+        .await
+    }
+
+    /// Uploads a sticker to a guild, this requires `MANAGE_GUILD_EXPRESSIONS`.
+    ///
+    /// The sticker is checked with [`CreateSticker::validate`] before it is sent.
+    pub async fn create_guild_sticker(
+        &self,
+        guild: Snowflake<Guild>,
+        sticker: &CreateSticker,
+    ) -> Result<Sticker, Error> {
+        sticker.validate()?;
+        self.fire(
+            Request::post(route!("/guilds/{}/stickers", guild))
+                .reason(sticker.reason.as_deref())?
+                .multipart(
+                    vec![
+                        ("name", sticker.name.clone()),
+                        ("description", sticker.description.clone()),
+                        ("tags", sticker.tags.clone()),
+                    ],
+                    vec![("file".to_string(), sticker.file.clone())],
+                ),
+        )
+        .await
+    }
+
+    /// Modifies a guild sticker, this requires `MANAGE_GUILD_EXPRESSIONS`.
+    ///
+    /// The edit is checked with [`EditSticker::validate`] before it is sent.
+    pub async fn modify_guild_sticker(
+        &self,
+        guild: Snowflake<Guild>,
+        sticker: Snowflake<Sticker>,
+        edit: &EditSticker,
+    ) -> Result<Sticker, Error> {
+        edit.validate()?;
+        self.fire(
+            Request::patch(route!("/guilds/{}/stickers/{}", guild, sticker))
+                .reason(edit.reason.as_deref())?
+                .json(edit)?,
+        )
+        .await
+    }
+
+    /// Deletes a guild sticker, this requires `MANAGE_GUILD_EXPRESSIONS`.
+    pub async fn delete_guild_sticker(
+        &self,
+        guild: Snowflake<Guild>,
+        sticker: Snowflake<Sticker>,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.fire_empty(
+            Request::delete(route!("/guilds/{}/stickers/{}", guild, sticker)).reason(reason)?,
+        )
+        .await
+    }
+}
@@ -0,0 +1,246 @@
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        channel::{
+            forum::{forum_limits, CreateForumPost, ForumError, ForumPost, ForumTag},
+            message::Message,
+            thread::{StartThread, ThreadList, ThreadMember},
+            Channel,
+        },
+        guild::Guild,
+        user::User,
+    },
+    snowflake::Snowflake,
+    timestamp::Timestamp,
+};
+
+impl Http {
+    /// Starts a public thread from a message, or an announcement thread in an announcement channel.
+    ///
+    /// The thread is checked with [`StartThread::validate`] before it is created.
+    pub async fn start_thread_from_message(
+        &self,
+        channel: Snowflake<Channel>,
+        message: Snowflake<Message>,
+        thread: &StartThread,
+    ) -> Result<Channel, Error> {
+        thread.validate()?;
+        self.fire(
+            Request::post(route!("/channels/{}/messages/{}/threads", channel, message))
+                .reason(thread.reason.as_deref())?
+                .json(thread)?,
+        )
+        .await
+    }
+
+    /// Starts a thread which is not attached to a message, this is a private thread unless another type is set.
+    ///
+    /// The thread is checked with [`StartThread::validate`] before it is created.
+    pub async fn start_thread(
+        &self,
+        channel: Snowflake<Channel>,
+        thread: &StartThread,
+    ) -> Result<Channel, Error> {
+        thread.validate()?;
+        self.fire(
+            Request::post(route!("/channels/{}/threads", channel))
+                .reason(thread.reason.as_deref())?
+                .json(thread)?,
+        )
+        .await
+    }
+
+    /// Creates a post in a forum or media channel, this requires `SEND_MESSAGES`.
+    ///
+    /// The post is checked with [`CreateForumPost::validate`] before it is created, [`Channel::create_post`] also checks its tags.
+    pub async fn create_forum_post(
+        &self,
+        channel: Snowflake<Channel>,
+        post: &CreateForumPost,
+    ) -> Result<ForumPost, Error> {
+        post.validate()?;
+        self.fire(
+            Request::post(route!("/channels/{}/threads", channel))
+                .reason(post.thread.reason.as_deref())?
+                .json_with_files_in(post, Some("message"), &post.message.files)?,
+        )
+        .await
+    }
+
+    /// Replaces the tags applied to a forum post, at most 5. This requires `MANAGE_THREADS` unless the bot created the post.
+    pub async fn set_applied_tags(
+        &self,
+        post: Snowflake<Channel>,
+        tags: &[Snowflake<ForumTag>],
+        reason: Option<&str>,
+    ) -> Result<Channel, Error> {
+        if tags.len() > forum_limits::APPLIED_TAGS {
+            return Err(ForumError::TooManyTags(tags.len()).into());
+        }
+        self.fire(
+            Request::patch(route!("/channels/{}", post))
+                .reason(reason)?
+                .json(&serde_json::json!({ "applied_tags": tags }))?,
+        )
+        .await
+    }
+
+    /// Joins a thread, which must not be archived.
+    pub async fn join_thread(&self, thread: Snowflake<Channel>) -> Result<(), Error> {
+        self.fire_empty(Request::put(route!(
+            "/channels/{}/thread-members/@me",
+            thread
+        )))
+        .await
+    }
+
+    /// Leaves a thread, which must not be archived.
+    pub async fn leave_thread(&self, thread: Snowflake<Channel>) -> Result<(), Error> {
+        self.fire_empty(Request::delete(route!(
+            "/channels/{}/thread-members/@me",
+            thread
+        )))
+        .await
+    }
+
+    /// Adds a member to a thread, this requires being able to send messages in the thread.
+    pub async fn add_thread_member(
+        &self,
+        thread: Snowflake<Channel>,
+        user: Snowflake<User>,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::put(route!(
+            "/channels/{}/thread-members/{}",
+            thread,
+            user
+        )))
+        .await
+    }
+
+    /// Removes a member from a thread, this requires `MANAGE_THREADS` unless the bot created the private thread.
+    pub async fn remove_thread_member(
+        &self,
+        thread: Snowflake<Channel>,
+        user: Snowflake<User>,
+    ) -> Result<(), Error> {
+        self.fire_empty(Request::delete(route!(
+            "/channels/{}/thread-members/{}",
+            thread,
+            user
+        )))
+        .await
+    }
+
+    /// Gets a member of a thread, `with_member` also gives their guild member.
+    pub async fn get_thread_member(
+        &self,
+        thread: Snowflake<Channel>,
+        user: Snowflake<User>,
+        with_member: bool,
+    ) -> Result<ThreadMember, Error> {
+        self.fire(
+            Request::get(route!("/channels/{}/thread-members/{}", thread, user))
+                .query("with_member", with_member),
+        )
+        .await
+    }
+
+    /// Lists the members of a thread, this requires the `GUILD_MEMBERS` intent.
+    ///
+    /// With `with_member` the guild members are given too, and the list is paginated: `after` gives the members after a user id,
+    /// and at most `limit` (up to 100) members are given.
+    pub async fn list_thread_members(
+        &self,
+        thread: Snowflake<Channel>,
+        with_member: bool,
+        after: Option<Snowflake<User>>,
+        limit: Option<u8>,
+    ) -> Result<Vec<ThreadMember>, Error> {
+        let mut request = Request::get(route!("/channels/{}/thread-members", thread))
+            .query("with_member", with_member);
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+
+    /// Lists the active threads of a guild which the bot can see, newest first.
+    pub async fn list_active_threads(&self, guild: Snowflake<Guild>) -> Result<ThreadList, Error> {
+        self.fire(Request::get(route!("/guilds/{}/threads/active", guild)))
+            .await
+    }
+
+    /// Lists the archived public threads of a channel, most recently archived first. This requires `READ_MESSAGE_HISTORY`.
+    ///
+    /// `before` gives the threads archived before a time, and at most `limit` threads are given.
+    pub async fn list_public_archived_threads(
+        &self,
+        channel: Snowflake<Channel>,
+        before: Option<Timestamp>,
+        limit: Option<u8>,
+    ) -> Result<ThreadList, Error> {
+        self.archived_threads(
+            Request::get(route!("/channels/{}/threads/archived/public", channel)),
+            before.map(|b| b.to_string()),
+            limit,
+        )
+        .await
+    }
+
+    /// Lists the archived private threads of a channel, most recently archived first.
+    /// This requires `READ_MESSAGE_HISTORY` and `MANAGE_THREADS`.
+    ///
+    /// `before` gives the threads archived before a time, and at most `limit` threads are given.
+    pub async fn list_private_archived_threads(
+        &self,
+        channel: Snowflake<Channel>,
+        before: Option<Timestamp>,
+        limit: Option<u8>,
+    ) -> Result<ThreadList, Error> {
+        self.archived_threads(
+            Request::get(route!("/channels/{}/threads/archived/private", channel)),
+            before.map(|b| b.to_string()),
+            limit,
+        )
+        .await
+    }
+
+    /// Lists the archived private threads of a channel which the bot has joined, newest first.
+    ///
+    /// `before` gives the threads before a thread id, and at most `limit` threads are given.
+    pub async fn list_joined_private_archived_threads(
+        &self,
+        channel: Snowflake<Channel>,
+        before: Option<Snowflake<Channel>>,
+        limit: Option<u8>,
+    ) -> Result<ThreadList, Error> {
+        self.archived_threads(
+            Request::get(route!(
+                "/channels/{}/users/@me/threads/archived/private",
+                channel
+            )),
+            before.map(|b| b.to_string()),
+            limit,
+        )
+        .await
+    }
+
+    async fn archived_threads(
+        &self,
+        mut request: Request,
+        before: Option<String>,
+        limit: Option<u8>,
+    ) -> Result<ThreadList, Error> {
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+}
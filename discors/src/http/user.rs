@@ -0,0 +1,48 @@
+use serde_json::json;
+
+use super::{route, Http, Request};
+use crate::{
+    error::Error,
+    models::{
+        channel::Channel,
+        guild::{Guild, PartialGuild},
+        user::User,
+    },
+    snowflake::Snowflake,
+};
+
+impl Http {
+    /// Gets the user of the bot.
+    pub async fn get_current_user(&self) -> Result<User, Error> {
+        self.fire(Request::get(route!("/users/@me"))).await
+    }
+
+    /// Gets a user by their id.
+    pub async fn get_user(&self, user: Snowflake<User>) -> Result<User, Error> {
+        self.fire(Request::get(route!("/users/{}", user))).await
+    }
+
+    /// Opens a direct message channel with a user, or gives the one that is already open.
+    pub async fn create_dm(&self, user: Snowflake<User>) -> Result<Channel, Error> {
+        self.fire(
+            Request::post(route!("/users/@me/channels")).json(&json!({ "recipient_id": user }))?,
+        )
+        .await
+    }
+
+    /// Gets the guilds the bot is in, at most 200 at a time. Guilds are sorted by id, `after` gives the guilds after a guild id.
+    pub async fn get_current_user_guilds(
+        &self,
+        after: Option<Snowflake<Guild>>,
+        limit: Option<u8>,
+    ) -> Result<Vec<PartialGuild>, Error> {
+        let mut request = Request::get(route!("/users/@me/guilds")).query("with_counts", true);
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.fire(request).await
+    }
+}
@@ -0,0 +1,429 @@
+use std::{
+    sync::{Arc, PoisonError},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use tokio::{
+    sync::{broadcast::error::RecvError, oneshot, Mutex},
+    task::JoinHandle,
+    time::Instant,
+};
+
+use crate::{
+    collector::{Events, InteractionCollector, Waiter},
+    commands::Autocomplete,
+    error::Error,
+    http::Http,
+    models::{
+        application::interaction::{Interaction, InteractionError, InteractionResponse},
+        channel::message::{CreateMessage, EditMessage, Message},
+        event::Event,
+    },
+    snowflake::Snowflake,
+};
+
+#[cfg(feature = "http_interactions")]
+pub use self::server::{InteractionServer, ServerResponse, SignatureError};
+
+#[cfg(feature = "http_interactions")]
+mod server;
+
+/// What interaction handlers are given besides the interaction, the http client and the collectors waiting for interactions.
+///
+/// Interactions go to a collector waiting for them first, then to the [`Autocomplete`] callback of the option being typed,
+/// and then to the handler.
+///
+/// Cloning this gives a handle to the same collectors.
+#[derive(Clone)]
+pub struct Context {
+    http: Arc<Http>,
+    waiters: Arc<std::sync::Mutex<Vec<Waiter>>>,
+    autocomplete: Arc<Autocomplete>,
+}
+
+impl std::fmt::Debug for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Context")
+            .field("http", &self.http)
+            .field("autocomplete", &self.autocomplete)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Context {
+    /// Creates a context with no collectors.
+    pub fn new(http: Arc<Http>) -> Self {
+        Self {
+            http,
+            waiters: Arc::default(),
+            autocomplete: Arc::default(),
+        }
+    }
+    /// Sets the callbacks which answer autocomplete interactions.
+    pub fn autocomplete(mut self, autocomplete: Autocomplete) -> Self {
+        self.autocomplete = Arc::new(autocomplete);
+        self
+    }
+    /// The http client used to make requests to discord.
+    pub fn http(&self) -> &Arc<Http> {
+        &self.http
+    }
+    /// Collects the component interactions of a message, like button clicks, see [`InteractionCollector`].
+    pub fn components(&self, message: Snowflake<Message>) -> InteractionCollector {
+        InteractionCollector::components(self, message)
+    }
+    /// Collects the submissions of the modal with a custom id, see [`InteractionCollector`].
+    pub fn modal(&self, custom_id: impl Into<String>) -> InteractionCollector {
+        InteractionCollector::modal(self, custom_id)
+    }
+    pub(crate) fn register(&self, waiter: Waiter) {
+        self.waiters
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(waiter);
+    }
+    // Gives an interaction to the first collector waiting for it, or back if no collector took it.
+    pub(crate) fn route(&self, responder: Responder) -> Option<Responder> {
+        let mut waiters = self.waiters.lock().unwrap_or_else(PoisonError::into_inner);
+        // collectors which were dropped, timed out or reached their limit
+        waiters.retain(|waiter| {
+            !waiter.sender.is_closed()
+                && !waiter
+                    .criteria
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .is_expired()
+        });
+        let waiter = waiters.iter().find(|waiter| {
+            let criteria = waiter
+                .criteria
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            criteria.matches(responder.interaction())
+        });
+        match waiter {
+            Some(waiter) => waiter.sender.send(responder).err().map(|err| err.0),
+            None => Some(responder),
+        }
+    }
+    // Runs the handler for an interaction in its own task, unless a collector or an autocomplete callback takes it.
+    pub(crate) fn dispatch(&self, handler: &Arc<dyn InteractionHandler>, responder: Responder) {
+        let responder = self
+            .route(responder)
+            .and_then(|responder| self.autocomplete.dispatch(responder));
+        if let Some(responder) = responder {
+            let handler = handler.clone();
+            let context = self.clone();
+            tokio::spawn(async move { handler.interaction(context, responder).await });
+        }
+    }
+}
+
+/// Handles the interactions received from the gateway or an [`InteractionServer`].
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use discors::{
+///     interactions::{Context, InteractionHandler, Responder},
+///     models::channel::message::CreateMessage,
+/// };
+///
+/// struct Ping;
+///
+/// #[async_trait]
+/// impl InteractionHandler for Ping {
+///     async fn interaction(&self, _context: Context, responder: Responder) {
+///         let _ = responder.reply(CreateMessage::new().content("pong!")).await;
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait InteractionHandler: Send + Sync + 'static {
+    /// Called for every interaction, except pings which are answered by the library and interactions taken by a collector.
+    async fn interaction(&self, context: Context, responder: Responder);
+}
+
+/// Runs the handler for every interaction dispatched to the events hub, until it is dropped.
+///
+/// Each interaction is handled in its own task, so a slow handler does not hold up the others.
+pub fn listen(
+    events: &Events,
+    context: Context,
+    handler: Arc<dyn InteractionHandler>,
+) -> JoinHandle<()> {
+    let mut receiver = events.subscribe();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if let Event::InteractionCreate(interaction) = &*event {
+                        let responder =
+                            Responder::new(context.http.clone(), (**interaction).clone());
+                        context.dispatch(&handler, responder);
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+/// How long before the end of the response window an automatic deferral is sent, to leave time for the request.
+pub const AUTO_DEFER_MARGIN: Duration = Duration::from_millis(750);
+
+/// Responds to an [`Interaction`], keeping track of whether the initial response has been sent.
+///
+/// Cloning this gives a handle to the same interaction, so one clone can [`auto_defer`](Self::auto_defer) while another responds.
+///
+/// ```rust,no_run
+/// # async fn run(http: std::sync::Arc<discors::http::Http>, interaction: discors::models::application::interaction::Interaction) -> Result<(), discors::error::Error> {
+/// use discors::{interactions::Responder, models::channel::message::CreateMessage};
+///
+/// let responder = Responder::new(http, interaction);
+/// // defers if the reply is not sent in time
+/// responder.auto_defer(false);
+/// responder.reply(CreateMessage::new().content("done!")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Responder {
+    http: Arc<Http>,
+    interaction: Arc<Interaction>,
+    state: Arc<Mutex<Initial>>,
+    // when the window to send the initial response in ends
+    deadline: Instant,
+}
+
+// whether the initial response has been sent, and where it is sent to
+#[derive(Debug)]
+struct Initial {
+    state: ResponseState,
+    // set when the interaction was received over http, the response is the body of the reply
+    callback: Option<oneshot::Sender<Option<InteractionResponse>>>,
+}
+
+/// Whether the initial response to an interaction has been sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseState {
+    Pending,
+    /// A deferred response was sent, the message is sent later.
+    Deferred,
+    Responded,
+}
+
+impl Responder {
+    /// Creates a responder for an interaction that has not been responded to.
+    pub fn new(http: Arc<Http>, interaction: Interaction) -> Self {
+        let window = interaction.response_deadline().until().unwrap_or_default();
+        Self::with_callback(http, interaction, None, Instant::now() + window)
+    }
+    #[cfg(feature = "http_interactions")]
+    // An interaction received over http, the window is measured from when the request arrived
+    // as the local clock can be off from the time in the snowflake.
+    pub(crate) fn received(
+        http: Arc<Http>,
+        interaction: Interaction,
+        callback: oneshot::Sender<Option<InteractionResponse>>,
+        received: Instant,
+    ) -> Self {
+        let deadline =
+            received + crate::models::application::interaction::interaction_limits::RESPONSE_WINDOW;
+        Self::with_callback(http, interaction, Some(callback), deadline)
+    }
+    fn with_callback(
+        http: Arc<Http>,
+        interaction: Interaction,
+        callback: Option<oneshot::Sender<Option<InteractionResponse>>>,
+        deadline: Instant,
+    ) -> Self {
+        Self {
+            http,
+            interaction: Arc::new(interaction),
+            state: Arc::new(Mutex::new(Initial {
+                state: ResponseState::Pending,
+                callback,
+            })),
+            deadline,
+        }
+    }
+    /// The interaction being responded to.
+    pub fn interaction(&self) -> &Interaction {
+        &self.interaction
+    }
+    /// The http client used to respond.
+    pub fn http(&self) -> &Arc<Http> {
+        &self.http
+    }
+    /// Whether the initial response has been sent.
+    pub async fn state(&self) -> ResponseState {
+        self.state.lock().await.state
+    }
+    /// Sends the initial response.
+    ///
+    /// This fails with [`InteractionError::AlreadyResponded`] if a response was already sent,
+    /// and with [`InteractionError::Expired`] if the 3 seconds to respond in have passed.
+    pub async fn respond(&self, response: InteractionResponse) -> Result<(), Error> {
+        let mut initial = self.state.lock().await;
+        if initial.state != ResponseState::Pending {
+            return Err(InteractionError::AlreadyResponded.into());
+        }
+        self.send_initial(&mut initial, response).await
+    }
+    /// Replies with a message, as the initial response or as a follow-up if a response was already sent.
+    ///
+    /// After a deferral the reply replaces the loading state, the ephemeral flag of the deferral is kept.
+    pub async fn reply(&self, message: CreateMessage) -> Result<(), Error> {
+        let mut initial = self.state.lock().await;
+        if initial.state == ResponseState::Pending {
+            return self
+                .send_initial(&mut initial, InteractionResponse::message(message))
+                .await;
+        }
+        // the state is kept locked, so that a deferral stays deferred if the follow-up fails
+        self.followup(&message).await?;
+        initial.state = ResponseState::Responded;
+        Ok(())
+    }
+    async fn send_initial(
+        &self,
+        initial: &mut Initial,
+        response: InteractionResponse,
+    ) -> Result<(), Error> {
+        if Instant::now() >= self.deadline {
+            return Err(InteractionError::Expired.into());
+        }
+        let deferred = response.kind().is_deferred();
+        match initial.callback.take() {
+            // files can only be sent with a request
+            Some(callback) if response.files().is_empty() => {
+                response.validate()?;
+                callback
+                    .send(Some(response))
+                    .map_err(|_| InteractionError::Expired)?;
+            }
+            callback => {
+                self.http
+                    .create_interaction_response(
+                        self.interaction.id,
+                        &self.interaction.token,
+                        &response,
+                    )
+                    .await?;
+                // the http reply is then sent without a body
+                if let Some(callback) = callback {
+                    let _ = callback.send(None);
+                }
+            }
+        }
+        initial.state = if deferred {
+            ResponseState::Deferred
+        } else {
+            ResponseState::Responded
+        };
+        Ok(())
+    }
+    /// Defers the response, showing that the bot is thinking. `ephemeral` only shows the loading state, and the reply, to the user.
+    pub async fn defer(&self, ephemeral: bool) -> Result<(), Error> {
+        self.respond(InteractionResponse::defer(ephemeral)).await
+    }
+    /// Defers a component interaction, without showing a loading state.
+    pub async fn defer_update(&self) -> Result<(), Error> {
+        self.respond(InteractionResponse::defer_update()).await
+    }
+    /// Defers the response shortly before the 3 seconds to respond in end, if no response has been sent by then.
+    ///
+    /// This spawns a task on the tokio runtime, so it has to be called within one.
+    pub fn auto_defer(&self, ephemeral: bool) {
+        let responder = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep_until(
+                responder
+                    .deadline
+                    .checked_sub(AUTO_DEFER_MARGIN)
+                    .unwrap_or(responder.deadline),
+            )
+            .await;
+            if responder.state().await == ResponseState::Pending {
+                // responding first or the window passing are both fine
+                let _ = responder.defer(ephemeral).await;
+            }
+        });
+    }
+    /// Sends a follow-up message.
+    pub async fn followup(&self, message: &CreateMessage) -> Result<Message, Error> {
+        self.http
+            .create_followup(&self.interaction.token, message)
+            .await
+    }
+    /// Edits the initial response, or sends the message of a deferred response.
+    pub async fn edit_original(&self, edit: &EditMessage) -> Result<Message, Error> {
+        self.http
+            .edit_original_response(&self.interaction.token, edit)
+            .await
+    }
+    /// Deletes the initial response.
+    pub async fn delete_original(&self) -> Result<(), Error> {
+        self.http
+            .delete_original_response(&self.interaction.token)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, time::Duration};
+
+    use tokio::{sync::oneshot, time::Instant};
+
+    use super::{Responder, ResponseState};
+    use crate::{
+        error::Error,
+        http::Http,
+        models::{
+            application::interaction::{
+                test_interaction, Interaction, InteractionError, InteractionResponse,
+            },
+            channel::message::CreateMessage,
+        },
+    };
+
+    #[tokio::test]
+    async fn expired() {
+        // created in 2015, so it is checked before any request is made
+        let mut interaction =
+            test_interaction(2, serde_json::json!({"id": "3", "name": "ping", "type": 1}));
+        interaction["id"] = "1".into();
+        let interaction = serde_json::from_value::<Interaction>(interaction).unwrap();
+        let responder = Responder::new(Arc::new(Http::new("token").unwrap()), interaction);
+        assert!(matches!(
+            responder.respond(InteractionResponse::defer(true)).await,
+            Err(Error::Interaction(InteractionError::Expired))
+        ));
+        assert_eq!(responder.state().await, ResponseState::Pending);
+    }
+
+    #[tokio::test]
+    async fn failed_reply() {
+        let interaction =
+            test_interaction(2, serde_json::json!({"id": "3", "name": "ping", "type": 1}));
+        let interaction = serde_json::from_value::<Interaction>(interaction).unwrap();
+        let (callback, _receiver) = oneshot::channel();
+        let responder = Responder::with_callback(
+            Arc::new(Http::new("token").unwrap()),
+            interaction,
+            Some(callback),
+            Instant::now() + Duration::from_secs(3),
+        );
+        responder
+            .respond(InteractionResponse::defer(false))
+            .await
+            .unwrap();
+        assert_eq!(responder.state().await, ResponseState::Deferred);
+        // an empty message is refused before the follow-up is sent
+        assert!(responder.reply(CreateMessage::new()).await.is_err());
+        assert_eq!(responder.state().await, ResponseState::Deferred);
+    }
+}
@@ -0,0 +1,495 @@
+use std::{
+    convert::Infallible,
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+
+use ed25519_dalek::{Signature, VerifyingKey};
+use hyper::{
+    body::HttpBody,
+    header::{CONTENT_LENGTH, CONTENT_TYPE},
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use tokio::{
+    sync::oneshot,
+    time::{timeout_at, Instant},
+};
+
+use super::{Context, InteractionHandler, Responder};
+use crate::{
+    models::application::interaction::{
+        interaction_limits, Interaction, InteractionResponse, InteractionType,
+    },
+    timestamp::Timestamp,
+};
+
+// how far the timestamp of a request can be from now, so old requests can't be replayed
+const TIMESTAMP_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+// the largest body read, interactions are far smaller so anything larger is not from discord
+const MAX_BODY: usize = 1024 * 1024;
+
+/// Receives interactions over http, for applications with an interactions endpoint url instead of a gateway connection.
+///
+/// Requests are checked against the application's public key, pings are answered,
+/// and every other interaction is given to the collectors of the context, or the same [`InteractionHandler`] used with the gateway.
+/// The initial response is sent as the body of the reply, so it has to be sent within 3 seconds.
+///
+/// [`handle`](Self::handle) does not depend on a server, so it can be used with any framework.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#receiving-an-interaction)
+#[derive(Clone)]
+pub struct InteractionServer {
+    public_key: VerifyingKey,
+    context: Context,
+    handler: Arc<dyn InteractionHandler>,
+}
+
+impl Debug for InteractionServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InteractionServer")
+            .field("public_key", &hex::encode(self.public_key.as_bytes()))
+            .finish_non_exhaustive()
+    }
+}
+
+/// The reply to an interaction request, with a json body if there is one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerResponse {
+    pub status: u16,
+    pub body: Option<Vec<u8>>,
+}
+
+impl ServerResponse {
+    fn empty(status: StatusCode) -> Self {
+        Self {
+            status: status.as_u16(),
+            body: None,
+        }
+    }
+    fn json(response: &InteractionResponse) -> Self {
+        match serde_json::to_vec(response) {
+            Ok(body) => Self {
+                status: StatusCode::OK.as_u16(),
+                body: Some(body),
+            },
+            Err(_) => Self::empty(StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+}
+
+impl InteractionServer {
+    /// Creates a server from the hex encoded public key of the application, shown in the developer portal.
+    pub fn new(
+        public_key: &str,
+        context: Context,
+        handler: Arc<dyn InteractionHandler>,
+    ) -> Result<Self, SignatureError> {
+        let bytes = hex::decode(public_key.trim()).map_err(|_| SignatureError::InvalidPublicKey)?;
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| SignatureError::InvalidPublicKey)?;
+        Ok(Self {
+            public_key: VerifyingKey::from_bytes(&bytes)
+                .map_err(|_| SignatureError::InvalidPublicKey)?,
+            context,
+            handler,
+        })
+    }
+    /// Checks the `X-Signature-Ed25519` and `X-Signature-Timestamp` headers of a request were signed by discord for this body,
+    /// and the timestamp is within 5 minutes of now.
+    pub fn verify(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        body: &[u8],
+    ) -> Result<(), SignatureError> {
+        let signature = hex::decode(signature)
+            .ok()
+            .and_then(|bytes| Signature::from_slice(&bytes).ok())
+            .ok_or(SignatureError::InvalidSignature)?;
+        let mut message = timestamp.as_bytes().to_vec();
+        message.extend_from_slice(body);
+        self.public_key
+            .verify_strict(&message, &signature)
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        let sent = timestamp
+            .parse::<u64>()
+            .map_err(|_| SignatureError::InvalidTimestamp)?;
+        if sent.abs_diff(Timestamp::now().to_unix_seconds()) > TIMESTAMP_TOLERANCE.as_secs() {
+            return Err(SignatureError::InvalidTimestamp);
+        }
+        Ok(())
+    }
+    /// Handles a request, given the values of its signature headers and its body.
+    ///
+    /// Requests with a missing or wrong signature, or an old timestamp, are rejected with `401`, as discord checks they are.
+    /// If the handler does not respond within 3 seconds of this being called, or stops without responding, the reply is a `500`.
+    pub async fn handle(
+        &self,
+        signature: Option<&str>,
+        timestamp: Option<&str>,
+        body: &[u8],
+    ) -> ServerResponse {
+        let received = Instant::now();
+        let verified = match (signature, timestamp) {
+            (Some(signature), Some(timestamp)) => self.verify(signature, timestamp, body),
+            _ => Err(SignatureError::MissingHeaders),
+        };
+        if verified.is_err() {
+            return ServerResponse::empty(StatusCode::UNAUTHORIZED);
+        }
+        let interaction = match serde_json::from_slice::<Interaction>(body) {
+            Ok(interaction) => interaction,
+            Err(_) => return ServerResponse::empty(StatusCode::BAD_REQUEST),
+        };
+        if interaction.kind == InteractionType::Ping {
+            return ServerResponse::json(&InteractionResponse::pong());
+        }
+        let (sender, receiver) = oneshot::channel();
+        let responder =
+            Responder::received(self.context.http().clone(), interaction, sender, received);
+        self.context.dispatch(&self.handler, responder);
+        let deadline = received + interaction_limits::RESPONSE_WINDOW;
+        match timeout_at(deadline, receiver).await {
+            Ok(Ok(Some(response))) => ServerResponse::json(&response),
+            // the response was sent with a request, as it had files
+            Ok(Ok(None)) => ServerResponse::empty(StatusCode::ACCEPTED),
+            _ => ServerResponse::empty(StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+    /// Listens for interactions on an address, every path is treated as the interactions endpoint.
+    pub async fn serve(self, address: SocketAddr) -> Result<(), hyper::Error> {
+        let make = make_service_fn(move |_| {
+            let server = self.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let server = server.clone();
+                    async move { Ok::<_, Infallible>(server.handle_request(request).await) }
+                }))
+            }
+        });
+        Server::bind(&address).serve(make).await
+    }
+    async fn handle_request(&self, request: Request<Body>) -> Response<Body> {
+        let reply = if request.method() != Method::POST {
+            ServerResponse::empty(StatusCode::METHOD_NOT_ALLOWED)
+        } else {
+            let (parts, body) = request.into_parts();
+            let header = |name| {
+                parts
+                    .headers
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+            };
+            let length = header(CONTENT_LENGTH.as_str()).and_then(|l| l.parse::<usize>().ok());
+            match read_body(body, length).await {
+                Ok(body) => {
+                    self.handle(
+                        header("X-Signature-Ed25519"),
+                        header("X-Signature-Timestamp"),
+                        &body,
+                    )
+                    .await
+                }
+                Err(status) => ServerResponse::empty(status),
+            }
+        };
+        let mut response = Response::builder().status(reply.status);
+        if reply.body.is_some() {
+            response = response.header(CONTENT_TYPE, "application/json");
+        }
+        response
+            .body(reply.body.map_or_else(Body::empty, Body::from))
+            .unwrap_or_default()
+    }
+}
+
+// Reads a body of at most `MAX_BODY` bytes, larger bodies are rejected before they are read into memory.
+async fn read_body(mut body: Body, length: Option<usize>) -> Result<Vec<u8>, StatusCode> {
+    if length.is_some_and(|length| length > MAX_BODY) {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+    let mut bytes = Vec::with_capacity(length.unwrap_or_default());
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|_| StatusCode::BAD_REQUEST)?;
+        if bytes.len() + chunk.len() > MAX_BODY {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+/// The error given when the signature of an interaction request can not be checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SignatureError {
+    /// The public key is not 32 hex encoded bytes.
+    InvalidPublicKey,
+    /// The signature or timestamp header was not given.
+    MissingHeaders,
+    /// The signature is not valid for the body, it was not sent by discord.
+    InvalidSignature,
+    /// The timestamp is not a unix time within 5 minutes of now, the request may have been replayed.
+    InvalidTimestamp,
+}
+
+impl Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SignatureError::InvalidPublicKey => "the public key is not 32 hex encoded bytes",
+            SignatureError::MissingHeaders => "the signature or timestamp header is missing",
+            SignatureError::InvalidSignature => "the signature is not valid for the request",
+            SignatureError::InvalidTimestamp => {
+                "the timestamp of the request is too old or too new"
+            }
+        })
+    }
+}
+
+impl StdError for SignatureError {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use ed25519_dalek::{Signer, SigningKey};
+    use rand::rngs::OsRng;
+
+    use hyper::{Body, StatusCode};
+
+    use super::{read_body, InteractionServer, ServerResponse, SignatureError, MAX_BODY};
+    use crate::{
+        commands::Autocomplete,
+        http::Http,
+        interactions::{Context, InteractionHandler, Responder},
+        models::{
+            application::{
+                command::CommandOptionChoice,
+                interaction::{test_interaction, Interaction},
+            },
+            channel::message::CreateMessage,
+        },
+        snowflake::Snowflake,
+        timestamp::Timestamp,
+    };
+
+    struct Echo;
+
+    #[async_trait]
+    impl InteractionHandler for Echo {
+        async fn interaction(&self, _context: Context, responder: Responder) {
+            let name = responder.interaction().command().unwrap().name.clone();
+            responder
+                .reply(CreateMessage::new().content(name).ephemeral())
+                .await
+                .unwrap();
+        }
+    }
+
+    struct Silent;
+
+    #[async_trait]
+    impl InteractionHandler for Silent {
+        async fn interaction(&self, _context: Context, _responder: Responder) {}
+    }
+
+    fn server(key: &SigningKey, handler: Arc<dyn InteractionHandler>) -> InteractionServer {
+        InteractionServer::new(
+            &hex::encode(key.verifying_key().as_bytes()),
+            Context::new(Arc::new(Http::new("token").unwrap())),
+            handler,
+        )
+        .unwrap()
+    }
+
+    fn now() -> String {
+        Timestamp::now().to_unix_seconds().to_string()
+    }
+
+    fn sign(key: &SigningKey, timestamp: &str, body: &[u8]) -> String {
+        let mut message = timestamp.as_bytes().to_vec();
+        message.extend_from_slice(body);
+        hex::encode(key.sign(&message).to_bytes())
+    }
+
+    fn interaction(kind: u8, data: serde_json::Value) -> Vec<u8> {
+        serde_json::to_vec(&test_interaction(kind, data)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn verify() {
+        let key = SigningKey::generate(&mut OsRng);
+        let server = server(&key, Arc::new(Echo));
+        let body = interaction(1, serde_json::Value::Null);
+        let timestamp = now();
+        assert_eq!(
+            server.verify(&sign(&key, &timestamp, &body), &timestamp, &body),
+            Ok(())
+        );
+        // signed by another key
+        let other = SigningKey::generate(&mut OsRng);
+        assert_eq!(
+            server.verify(&sign(&other, &timestamp, &body), &timestamp, &body),
+            Err(SignatureError::InvalidSignature)
+        );
+        assert_eq!(
+            server.verify(&sign(&key, &timestamp, &body), "1700000001", &body),
+            Err(SignatureError::InvalidSignature)
+        );
+        assert_eq!(
+            server.handle(None, Some(&timestamp), &body).await,
+            ServerResponse {
+                status: 401,
+                body: None
+            }
+        );
+        // a validly signed request from long ago is rejected
+        assert_eq!(
+            server.verify(&sign(&key, "1700000000", &body), "1700000000", &body),
+            Err(SignatureError::InvalidTimestamp)
+        );
+        assert_eq!(
+            server
+                .handle(
+                    Some(&sign(&key, "1700000000", &body)),
+                    Some("1700000000"),
+                    &body
+                )
+                .await
+                .status,
+            401
+        );
+        let future = (Timestamp::now().to_unix_seconds() + 10 * 60).to_string();
+        assert_eq!(
+            server.verify(&sign(&key, &future, &body), &future, &body),
+            Err(SignatureError::InvalidTimestamp)
+        );
+        assert!(InteractionServer::new(
+            "abc",
+            Context::new(Arc::new(Http::new("token").unwrap())),
+            Arc::new(Echo)
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn handle() {
+        let key = SigningKey::generate(&mut OsRng);
+        let server = server(&key, Arc::new(Echo));
+        let timestamp = now();
+        let ping = interaction(1, serde_json::Value::Null);
+        let reply = server
+            .handle(
+                Some(&sign(&key, &timestamp, &ping)),
+                Some(&timestamp),
+                &ping,
+            )
+            .await;
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body.unwrap(), br#"{"type":1}"#);
+
+        let command = interaction(2, serde_json::json!({"id": "3", "name": "ping", "type": 1}));
+        let reply = server
+            .handle(
+                Some(&sign(&key, &timestamp, &command)),
+                Some(&timestamp),
+                &command,
+            )
+            .await;
+        assert_eq!(reply.status, 200);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&reply.body.unwrap()).unwrap(),
+            serde_json::json!({"type": 4, "data": {"content": "ping", "flags": 64}})
+        );
+
+        // the window is measured from when the request arrived, so a clock which is ahead doesn't fail every interaction
+        let mut skewed =
+            test_interaction(2, serde_json::json!({"id": "3", "name": "ping", "type": 1}));
+        let created = Timestamp::new(Timestamp::now().to_unix_millis() - 10_000);
+        skewed["id"] = Snowflake::<Interaction>::from_time(created)
+            .to_string()
+            .into();
+        let skewed = serde_json::to_vec(&skewed).unwrap();
+        let reply = server
+            .handle(
+                Some(&sign(&key, &timestamp, &skewed)),
+                Some(&timestamp),
+                &skewed,
+            )
+            .await;
+        assert_eq!(reply.status, 200);
+
+        // the handler stopping without responding fails the interaction
+        let server = super::InteractionServer {
+            handler: Arc::new(Silent),
+            ..server
+        };
+        let reply = server
+            .handle(
+                Some(&sign(&key, &timestamp, &command)),
+                Some(&timestamp),
+                &command,
+            )
+            .await;
+        assert_eq!(reply.status, 500);
+    }
+
+    #[tokio::test]
+    async fn body_limit() {
+        assert_eq!(
+            read_body(Body::from("{}"), Some(2)).await,
+            Ok(b"{}".to_vec())
+        );
+        // rejected by its length before anything is read
+        assert_eq!(
+            read_body(Body::empty(), Some(MAX_BODY + 1)).await,
+            Err(StatusCode::PAYLOAD_TOO_LARGE)
+        );
+        // or while reading, when the length is not given
+        assert_eq!(
+            read_body(Body::from(vec![0; MAX_BODY + 1]), None).await,
+            Err(StatusCode::PAYLOAD_TOO_LARGE)
+        );
+    }
+
+    #[tokio::test]
+    async fn autocomplete() {
+        let key = SigningKey::generate(&mut OsRng);
+        let context = Context::new(Arc::new(Http::new("token").unwrap())).autocomplete(
+            Autocomplete::new().option("tag", "name", |input| async move {
+                vec![CommandOptionChoice::new(
+                    input.value().to_uppercase(),
+                    "rust",
+                )]
+            }),
+        );
+        let server = InteractionServer {
+            context,
+            ..server(&key, Arc::new(Silent))
+        };
+        let body = interaction(
+            4,
+            serde_json::json!({"id": "3", "name": "tag", "type": 1, "options": [
+                {"name": "name", "type": 3, "value": "ru", "focused": true},
+            ]}),
+        );
+        let timestamp = now();
+        let reply = server
+            .handle(
+                Some(&sign(&key, &timestamp, &body)),
+                Some(&timestamp),
+                &body,
+            )
+            .await;
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&reply.body.unwrap()).unwrap(),
+            serde_json::json!({"type": 8, "data": {"choices": [{"name": "RU", "value": "rust"}]}})
+        );
+    }
+}
@@ -6,7 +6,10 @@
 #[cfg(feature = "v10")]
 pub const API_VERSION: u8 = 10;
 #[cfg(feature = "v10")]
-pub const BASE_URL: &str = "https://discordapp.com/api/v10";
+pub const BASE_URL: &str = "https://discord.com/api/v10";
+// without a version feature, discord picks its default api version
+#[cfg(not(feature = "v10"))]
+pub const BASE_URL: &str = "https://discord.com/api";
 
 /// The main client used to connect to discord.
 /// todo!
@@ -19,8 +22,20 @@ pub mod presence;
 /// A general representation for colors in discord, represented using their hex value (24-bit rgb colors).
 pub mod color;
 pub use self::color as colour;
+/// Collectors, which wait for gateway events like reactions.
+pub mod collector;
+/// Slash and context menu commands defined as rust types, and syncing them with discord.
+pub mod commands;
+/// The http client, which makes requests to discord's REST api.
+pub mod http;
+/// Responding to interactions, like commands and components, within discord's time limits.
+pub mod interactions;
 /// The Locale associated with a discord user, on the client-side this is the language and region
 pub mod locale;
+/// Escaping, sanitising and stripping of discord's markdown in message content.
+pub mod markdown;
+/// Parsing and formatting of mentions (users, channels, roles, emojis, commands) in message content.
+pub mod mention;
 /// The models around discord data, like users, channels, guilds
 pub mod models;
 /// Discord Permissions representations, for channel, and server wide permissions.
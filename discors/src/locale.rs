@@ -1,28 +1,29 @@
-use serde::{Deserialize, Serialize};
-
 macro_rules! locales {
     ($($val:literal = $name:ident,)*) => {
-        #[non_exhaustive]
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
-        /// A users locale (representing their selected language)
-        ///
-        /// [Read More](https://discord.com/developers/docs/reference#locales)
-        pub enum Locale {
-            $(
-                #[doc = concat!("The locale for ", stringify!($name), ", which represents ", $val, " in the discord api.")]
-                #[serde(rename = $val)]
-                $name,
-            )*
+        crate::string_enum! {
+            /// A users locale (representing their selected language)
+            ///
+            /// Locales discord adds before this library knows them are kept in [`Locale::Unknown`].
+            ///
+            /// [Read More](https://discord.com/developers/docs/reference#locales)
+            Locale {
+                $(
+                    #[doc = concat!("The locale for ", stringify!($name), ", which represents ", $val, " in the discord api.")]
+                    $name = $val,
+                )*
+            }
         }
     };
 }
 
 locales![
+    "id" = Indonesian,
     "da" = Danish,
     "de" = German,
     "en-GB" = EnglishUK,
     "en-US" = EnglishUS,
     "es-ES" = Spanish,
+    "es-419" = SpanishLatinAmerica,
     "fr" = French,
     "hr" = Croatian,
     "it" = Italian,
@@ -37,7 +38,7 @@ locales![
     "sv-SE" = Swedish,
     "vi" = Vietnamese,
     "tr" = Turkish,
-    "cz" = Czech,
+    "cs" = Czech,
     "el" = Greek,
     "bg" = Bulgarian,
     "ru" = Russian,
@@ -88,6 +89,13 @@ mod tests {
         let polish = "{
             \"locale\": \"pl\"
         }";
-        assert_eq!(serde_json::from_str::<Test>(polish).unwrap().locale, Polish)
+        assert_eq!(serde_json::from_str::<Test>(polish).unwrap().locale, Polish);
+        // locales added later don't fail the whole payload
+        assert_eq!(
+            serde_json::from_str::<Test>("{\"locale\":\"xx-XX\"}")
+                .unwrap()
+                .locale,
+            Locale::Unknown("xx-XX".to_string())
+        );
     }
 }
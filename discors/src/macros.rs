@@ -129,3 +129,149 @@ macro_rules! consume {
         $into
     };
 }
+
+// Defines the constants of a type made with `bitflags!`, an EMPTY set and a Default impl giving it.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! flags {
+    ($f:ident: $($flag:ident $($alias:ident)* = $val:expr $(; $doc:expr)?,)*) => {
+        impl $f {
+            #[doc = concat!("Gives an empty set of ", stringify!($f))]
+            pub const EMPTY: $f = $f(0);
+            $(
+                #[doc = $crate::optional_default!(concat!(
+                    "The ",
+                    stringify!($flag),
+                    " flag.",
+                )$(, $doc)?)]
+                pub const $flag: $f = $f(1 << $val);
+                $(
+                    #[doc = concat!(
+                        "An alias to [`",
+                        stringify!($f),
+                        "::",
+                        stringify!($flag),
+                        "`]."
+                    )]
+                    pub const $alias: $f = $f::$flag;
+                )*
+            )*
+        }
+        impl ::std::default::Default for $f {
+            #[doc = concat!("Gives an empty set of ", stringify!($f))]
+            fn default() -> Self {
+                $f::EMPTY
+            }
+        }
+    };
+}
+
+// An enum discord represents as an integer. Values this library does not know about yet are kept in `Unknown`,
+// so that a new value does not break deserializing.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! int_enum {
+    ($(#[$meta:meta])* $name:ident: $int:ty { $($(#[$vmeta:meta])* $variant:ident = $val:literal,)* }) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        #[derive(::std::fmt::Debug, ::std::clone::Clone, ::std::marker::Copy, ::std::cmp::PartialEq, ::std::cmp::Eq, ::std::hash::Hash)]
+        pub enum $name {
+            $(
+                $(#[$vmeta])*
+                $variant,
+            )*
+            /// A value that is not known to this library yet.
+            Unknown($int),
+        }
+        impl ::std::convert::From<$int> for $name {
+            fn from(i: $int) -> Self {
+                match i {
+                    $($val => $name::$variant,)*
+                    i => $name::Unknown(i),
+                }
+            }
+        }
+        impl ::std::convert::From<$name> for $int {
+            fn from(e: $name) -> Self {
+                match e {
+                    $($name::$variant => $val,)*
+                    $name::Unknown(i) => i,
+                }
+            }
+        }
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, s: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                <$int as ::serde::Serialize>::serialize(&<$int>::from(*self), s)
+            }
+        }
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(d: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                <$int as ::serde::Deserialize>::deserialize(d).map(Self::from)
+            }
+        }
+    };
+}
+
+// An enum discord represents as a string, like `int_enum!`, values this library does not know about yet are kept in `Unknown`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! string_enum {
+    ($(#[$meta:meta])* $name:ident { $($(#[$vmeta:meta])* $variant:ident = $val:literal,)* }) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        #[derive(::std::fmt::Debug, ::std::clone::Clone, ::std::cmp::PartialEq, ::std::cmp::Eq, ::std::hash::Hash)]
+        pub enum $name {
+            $(
+                $(#[$vmeta])*
+                $variant,
+            )*
+            /// A value that is not known to this library yet.
+            Unknown(::std::string::String),
+        }
+        impl $name {
+            /// The string discord uses for this value.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $val,)*
+                    $name::Unknown(s) => s,
+                }
+            }
+        }
+        impl ::std::convert::From<&str> for $name {
+            fn from(s: &str) -> Self {
+                match s {
+                    $($val => $name::$variant,)*
+                    s => $name::Unknown(s.to_string()),
+                }
+            }
+        }
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, s: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                s.serialize_str(self.as_str())
+            }
+        }
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(d: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                <::std::borrow::Cow<'de, str> as ::serde::Deserialize>::deserialize(d)
+                    .map(|s| Self::from(&*s))
+            }
+        }
+    };
+}
@@ -0,0 +1,371 @@
+use crate::mention::{parse_mentions, Mention};
+
+/// An alias for all the brits out there.
+pub use self::neutralize_mentions as neutralise_mentions;
+/// An alias for all the brits out there.
+pub use self::sanitize as sanitise;
+
+/// The zero width space discord uses to break up formatting and mentions, without it being visible.
+pub const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+// characters that are formatting wherever they appear
+const INLINE: [char; 8] = ['\\', '*', '_', '~', '|', '`', '[', ']'];
+// characters that are only formatting at the start of a line (block quotes, headers, lists)
+const LINE_START: [char; 3] = ['>', '#', '-'];
+// characters which end a link, so that formatting and escapes around it are still seen
+const LINK_END: [char; 7] = ['\\', '|', '*', '_', '~', '`', '>'];
+
+/// Escapes all markdown in a string, so that discord displays it exactly as it was written.
+///
+/// This covers bold, italics, underline, strikethrough, spoilers, code (blocks), block quotes, headers, lists and masked links.
+/// Links are left as they are up to the first formatting character, so that they still embed.
+///
+/// ```rust
+/// use discors::markdown::escape_markdown;
+///
+/// assert_eq!(escape_markdown("**hi** ||there||"), r"\*\*hi\*\* \|\|there\|\|");
+/// assert_eq!(escape_markdown("# title\n> quote"), "\\# title\n\\> quote");
+/// assert_eq!(escape_markdown("see https://a.com/b"), "see https://a.com/b");
+/// assert_eq!(escape_markdown("||https://a.com||"), r"\|\|https://a.com\|\|");
+/// assert_eq!(escape_markdown("1. one"), r"1\. one");
+/// ```
+pub fn escape_markdown(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    let mut line_start = true;
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if let Some(link) = link(rest) {
+            escaped.push_str(link);
+            rest = &rest[link.len()..];
+            line_start = false;
+            continue;
+        }
+        if let Some(number) = line_start.then(|| list_number(rest)).flatten() {
+            escaped.push_str(number);
+            escaped.push_str("\\.");
+            rest = &rest[number.len() + 1..];
+            line_start = false;
+            continue;
+        }
+        if INLINE.contains(&c) || (line_start && LINE_START.contains(&c)) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        line_start = c == '\n' || (line_start && c.is_whitespace());
+        rest = &rest[c.len_utf8()..];
+    }
+    escaped
+}
+
+/// Removes the escapes from a string, this is the reverse of [`escape_markdown`].
+///
+/// ```rust
+/// use discors::markdown::{escape_markdown, unescape_markdown};
+///
+/// let s = "__*wow*__ `code`";
+/// assert_eq!(unescape_markdown(&escape_markdown(s)), s);
+/// ```
+pub fn unescape_markdown(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if let Some(link) = link(rest) {
+            unescaped.push_str(link);
+            rest = &rest[link.len()..];
+            continue;
+        }
+        rest = &rest[c.len_utf8()..];
+        match rest.chars().next() {
+            Some(next) if c == '\\' && is_escapable(next) => {
+                unescaped.push(next);
+                rest = &rest[next.len_utf8()..];
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// Breaks up user, role, `@everyone` and `@here` mentions with a [`ZERO_WIDTH_SPACE`], so that they do not ping anyone.
+///
+/// The text looks the same, but discord no longer treats it as a mention.
+///
+/// ```rust
+/// use discors::markdown::neutralize_mentions;
+///
+/// assert_eq!(neutralize_mentions("hi @everyone"), "hi @\u{200b}everyone");
+/// assert_eq!(neutralize_mentions("<@&1234>"), "<@\u{200b}&1234>");
+/// ```
+pub fn neutralize_mentions(s: &str) -> String {
+    let mut neutralized = String::with_capacity(s.len());
+    let mut last = 0;
+    for span in parse_mentions(s) {
+        let at = match span.mention {
+            Mention::Everyone | Mention::Here => span.span.start,
+            Mention::User(_) | Mention::Role(_) => span.span.start + 1,
+            _ => continue,
+        };
+        // the `@` is always one byte
+        neutralized.push_str(&s[last..=at]);
+        neutralized.push(ZERO_WIDTH_SPACE);
+        last = at + 1;
+    }
+    neutralized.push_str(&s[last..]);
+    neutralized
+}
+
+/// Escapes markdown and neutralizes mentions, making user input safe to echo back into a channel.
+pub fn sanitize(s: &str) -> String {
+    neutralize_mentions(&escape_markdown(s))
+}
+
+/// Removes all formatting from a string, leaving only the text that discord would display.
+///
+/// The contents of code blocks are kept as they are, and masked links are replaced with their text.
+///
+/// ```rust
+/// use discors::markdown::strip_markdown;
+///
+/// assert_eq!(strip_markdown("**bold** and ~~gone~~ ||secret||"), "bold and gone secret");
+/// assert_eq!(strip_markdown("## [docs](https://docs.rs) \\*"), "docs *");
+/// assert_eq!(strip_markdown("```rs\nlet x_y = 1;```"), "let x_y = 1;");
+/// ```
+pub fn strip_markdown(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut line_start = true;
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if line_start {
+            if let Some(prefix) = block_prefix(rest) {
+                rest = &rest[prefix..];
+                line_start = false;
+                continue;
+            }
+        }
+        line_start = c == '\n';
+        if let Some(link) = link(rest) {
+            stripped.push_str(link);
+            rest = &rest[link.len()..];
+            continue;
+        }
+        if let Some(code) = rest.strip_prefix("```") {
+            if let Some(end) = code.find("```") {
+                // the first line is the language, if there is more than one
+                let code = &code[..end];
+                let code = match code.split_once('\n') {
+                    Some((language, body)) if !language.contains(char::is_whitespace) => body,
+                    _ => code,
+                };
+                stripped.push_str(code);
+                rest = &rest[end + 6..];
+                continue;
+            }
+        }
+        if let Some(code) = rest.strip_prefix('`') {
+            if let Some(end) = code.find('`') {
+                stripped.push_str(&code[..end]);
+                rest = &rest[end + 2..];
+                continue;
+            }
+        }
+        if let Some((text, len)) = masked_link(rest) {
+            stripped.push_str(&strip_markdown(text));
+            rest = &rest[len..];
+            continue;
+        }
+        let previous = stripped.chars().next_back();
+        rest = &rest[c.len_utf8()..];
+        let next = rest.chars().next();
+        match (c, next) {
+            ('\\', Some(next)) if is_escapable(next) => {
+                stripped.push(next);
+                rest = &rest[next.len_utf8()..];
+            }
+            ('*', _) => {}
+            // snake_case is not italics
+            ('_', _)
+                if !(previous.is_some_and(char::is_alphanumeric)
+                    && next.is_some_and(char::is_alphanumeric)) => {}
+            ('~' | '|', Some(next)) if next == c => rest = &rest[1..],
+            (c, _) => stripped.push(c),
+        }
+    }
+    stripped
+}
+
+// discord lets you escape any ascii punctuation
+fn is_escapable(c: char) -> bool {
+    c.is_ascii_punctuation()
+}
+
+// a link at the start of `s`, which extends until whitespace or something that could close formatting around it
+fn link(s: &str) -> Option<&str> {
+    if !(s.starts_with("https://") || s.starts_with("http://")) {
+        return None;
+    }
+    let end = s
+        .find(|c: char| c.is_whitespace() || LINK_END.contains(&c))
+        .unwrap_or(s.len());
+    Some(&s[..end])
+}
+
+// the number of a numbered list item (`1. `) at the start of `s`
+fn list_number(s: &str) -> Option<&str> {
+    let end = s.find(|c: char| !c.is_ascii_digit())?;
+    (end > 0 && s[end..].starts_with(". ")).then(|| &s[..end])
+}
+
+// a `[text](https://url)` at the start of `s`, giving the text and the length of the whole link
+fn masked_link(s: &str) -> Option<(&str, usize)> {
+    let text_end = s.find("](")?;
+    let text = s.strip_prefix('[')?.get(..text_end - 1)?;
+    let url = &s[text_end + 2..];
+    link(url)?;
+    let url_end = url.find(')')?;
+    if url[..url_end].contains(char::is_whitespace) {
+        return None;
+    }
+    Some((text, text_end + 2 + url_end + 1))
+}
+
+// the length of a block quote, header or subtext prefix at the start of `s`
+fn block_prefix(s: &str) -> Option<usize> {
+    [">>> ", "> ", "### ", "## ", "# ", "-# "]
+        .iter()
+        .find(|prefix| s.starts_with(*prefix))
+        .map(|prefix| prefix.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        escape_markdown, neutralize_mentions, sanitize, strip_markdown, unescape_markdown,
+        ZERO_WIDTH_SPACE,
+    };
+    use crate::mention::parse_mentions;
+
+    // pieces the fuzz corpus is built from, chosen to hit every rule
+    const PIECES: [&str; 34] = [
+        "*",
+        "**",
+        "_",
+        "__",
+        "~",
+        "~~",
+        "|",
+        "||",
+        "`",
+        "```",
+        ">",
+        ">>> ",
+        "# ",
+        "-",
+        "[",
+        "]",
+        "(",
+        ")",
+        "\\",
+        "\n",
+        " ",
+        "a",
+        "word",
+        "é",
+        "🦀",
+        "@everyone",
+        "<@123>",
+        "<@&4>",
+        "https://example.com/a_b",
+        "||https://x||",
+        "**https://x**",
+        "1. ",
+        "12",
+        ":",
+    ];
+
+    // a small xorshift, so the corpus is the same every run
+    fn corpus() -> impl Iterator<Item = String> {
+        let mut state = 0x2545f4914f6cdd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        (0..2000).map(move |_| {
+            let len = next() % 16;
+            (0..len)
+                .map(|_| PIECES[(next() % PIECES.len() as u64) as usize])
+                .collect()
+        })
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(escape_markdown("*a* _b_ __c__"), r"\*a\* \_b\_ \_\_c\_\_");
+        assert_eq!(
+            escape_markdown("~~d~~ ||e|| `f`"),
+            r"\~\~d\~\~ \|\|e\|\| \`f\`"
+        );
+        assert_eq!(
+            escape_markdown("[g](https://h.com)"),
+            r"\[g\](https://h.com)"
+        );
+        assert_eq!(escape_markdown("a > b # c - d"), "a > b # c - d");
+        assert_eq!(escape_markdown("  - item\n>>> q"), "  \\- item\n\\>>> q");
+        assert_eq!(escape_markdown("\\"), "\\\\");
+        assert_eq!(escape_markdown("**https://x**"), r"\*\*https://x\*\*");
+        assert_eq!(
+            escape_markdown("~~https://x/a_b~~"),
+            r"\~\~https://x/a\_b\~\~"
+        );
+        assert_eq!(
+            escape_markdown("1. a\n 22. b 3. c"),
+            "1\\. a\n 22\\. b 3. c"
+        );
+        assert_eq!(escape_markdown("1.5 and 2.x"), "1.5 and 2.x");
+    }
+
+    #[test]
+    fn escape_round_trip() {
+        for s in corpus() {
+            let escaped = escape_markdown(&s);
+            assert_eq!(unescape_markdown(&escaped), s, "escaped: {:?}", escaped);
+            // nothing is left to format
+            assert_eq!(strip_markdown(&escaped), s, "escaped: {:?}", escaped);
+        }
+    }
+
+    #[test]
+    fn neutralize() {
+        assert_eq!(
+            neutralize_mentions("@here <@!1> <#2> @someone"),
+            "@\u{200b}here <@\u{200b}!1> <#2> @someone"
+        );
+        for s in corpus() {
+            let neutralized = neutralize_mentions(&s);
+            assert!(parse_mentions(&neutralized).all(|m| !m.mention.to_string().contains('@')));
+            assert_eq!(neutralized.replace(ZERO_WIDTH_SPACE, ""), s);
+        }
+    }
+
+    #[test]
+    fn sanitize_everything() {
+        assert_eq!(sanitize("**@everyone**"), "\\*\\*@\u{200b}everyone\\*\\*");
+    }
+
+    #[test]
+    fn strip() {
+        assert_eq!(
+            strip_markdown("__under__ *it* snake_case"),
+            "under it snake_case"
+        );
+        assert_eq!(
+            strip_markdown("> quote\n# header\n-# small"),
+            "quote\nheader\nsmall"
+        );
+        assert_eq!(strip_markdown("`**not bold**`"), "**not bold**");
+        assert_eq!(strip_markdown("[**a**](https://b.com)"), "a");
+        assert_eq!(strip_markdown("a ~ b | c"), "a ~ b | c");
+        assert_eq!(strip_markdown("[not](a link)"), "[not](a link)");
+    }
+}
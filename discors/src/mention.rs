@@ -0,0 +1,312 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+    ops::Range,
+    str::FromStr,
+};
+
+use crate::{
+    models::{
+        application::command::{command_limits, ApplicationCommand},
+        channel::Channel,
+        emoji::{is_emoji_name, CustomEmoji},
+        guild::role::Role,
+        user::User,
+    },
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// A mention, as it appears in the content of a message.
+///
+/// This formats back into the syntax discord uses, so `Mention::User(id).to_string()` gives `<@id>`.
+///
+/// [Read more](https://discord.com/developers/docs/reference#message-formatting)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mention {
+    /// A user mention, `<@id>` or the legacy nickname form `<@!id>`.
+    User(Snowflake<User>),
+    /// A channel mention, `<#id>`.
+    Channel(Snowflake<Channel>),
+    /// A role mention, `<@&id>`.
+    Role(Snowflake<Role>),
+    /// A custom emoji, `<:name:id>` or `<a:name:id>` when animated.
+    Emoji {
+        name: String,
+        id: Snowflake<CustomEmoji>,
+        animated: bool,
+    },
+    /// A slash command mention, `</name:id>`. The name may include subcommands seperated by spaces.
+    Command {
+        name: String,
+        id: Snowflake<ApplicationCommand>,
+    },
+    /// The `@everyone` mention.
+    Everyone,
+    /// The `@here` mention.
+    Here,
+}
+
+impl Display for Mention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mention::User(id) => write!(f, "<@{}>", id),
+            Mention::Channel(id) => write!(f, "<#{}>", id),
+            Mention::Role(id) => write!(f, "<@&{}>", id),
+            Mention::Emoji { name, id, animated } => {
+                write!(f, "<{}:{}:{}>", if *animated { "a" } else { "" }, name, id)
+            }
+            Mention::Command { name, id } => write!(f, "</{}:{}>", name, id),
+            Mention::Everyone => f.write_str("@everyone"),
+            Mention::Here => f.write_str("@here"),
+        }
+    }
+}
+
+impl FromStr for Mention {
+    type Err = ParseMentionError;
+    /// Parses a string that consists of exactly one mention, like the argument of a prefix command.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_mentions(s).next() {
+            Some(MentionSpan { mention, span }) if span == (0..s.len()) => Ok(mention),
+            _ => Err(ParseMentionError),
+        }
+    }
+}
+
+/// The error given when a string could not be parsed as a [`Mention`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseMentionError;
+
+impl Display for ParseMentionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the string provided is not a valid mention")
+    }
+}
+
+impl StdError for ParseMentionError {}
+
+/// A [`Mention`] found in a string, along with the byte range it occupies in that string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MentionSpan {
+    pub mention: Mention,
+    pub span: Range<usize>,
+}
+
+/// An iterator over the mentions in a string, created by [`parse_mentions`].
+#[derive(Debug, Clone)]
+pub struct Mentions<'a> {
+    content: &'a str,
+    position: usize,
+}
+
+/// Finds all mentions in a string, in the order they appear.
+///
+/// ```rust
+/// use discors::mention::{parse_mentions, Mention};
+///
+/// let mut mentions = parse_mentions("hey <@!445556389532925952>, check <#81384788765712384>");
+///
+/// let user = mentions.next().unwrap();
+/// assert_eq!(user.mention, Mention::User(445556389532925952.into()));
+/// assert_eq!(user.span, 4..26);
+///
+/// let channel = mentions.next().unwrap();
+/// assert_eq!(channel.mention, Mention::Channel(81384788765712384.into()));
+/// assert!(mentions.next().is_none());
+/// ```
+pub fn parse_mentions(content: &str) -> Mentions<'_> {
+    Mentions {
+        content,
+        position: 0,
+    }
+}
+
+impl<'a> Iterator for Mentions<'a> {
+    type Item = MentionSpan;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(offset) = self.content[self.position..].find(['<', '@']) {
+            let start = self.position + offset;
+            let rest = &self.content[start..];
+            // both '<' and '@' are a single byte, so skipping one keeps us on a char boundary
+            self.position = start + 1;
+            if let Some((mention, len)) = parse_mention(rest) {
+                self.position = start + len;
+                return Some(MentionSpan {
+                    mention,
+                    span: start..start + len,
+                });
+            }
+        }
+        self.position = self.content.len();
+        None
+    }
+}
+
+// the longest a mention can be in bytes, a command with a subcommand group has three names of up to 4 bytes a character
+const MAX_LENGTH: usize = 3 * *command_limits::NAME.end() * 4 + "</  :18446744073709551615>".len();
+
+// parses a mention at the very start of `s`, giving it and its length in bytes
+fn parse_mention(s: &str) -> Option<(Mention, usize)> {
+    for (literal, mention) in [("@everyone", Mention::Everyone), ("@here", Mention::Here)] {
+        if s.starts_with(literal) {
+            return Some((mention, literal.len()));
+        }
+    }
+    // only looking a mention's length ahead, so that many `<` without a `>` are not quadratic
+    let end = s.bytes().take(MAX_LENGTH).position(|b| b == b'>')?;
+    let inner = s.strip_prefix('<')?.get(..end - 1)?;
+    let mention = if let Some(id) = inner.strip_prefix("@&") {
+        Mention::Role(snowflake(id)?)
+    } else if let Some(id) = inner.strip_prefix("@!") {
+        Mention::User(snowflake(id)?)
+    } else if let Some(id) = inner.strip_prefix('@') {
+        Mention::User(snowflake(id)?)
+    } else if let Some(id) = inner.strip_prefix('#') {
+        Mention::Channel(snowflake(id)?)
+    } else if let Some(command) = inner.strip_prefix('/') {
+        let (name, id) = command.rsplit_once(':')?;
+        if name.is_empty() {
+            return None;
+        }
+        Mention::Command {
+            name: name.to_string(),
+            id: snowflake(id)?,
+        }
+    } else {
+        let (animated, emoji) = match inner.strip_prefix("a:") {
+            Some(emoji) => (true, emoji),
+            None => (false, inner.strip_prefix(':')?),
+        };
+        let (name, id) = emoji.split_once(':')?;
+        if !is_emoji_name(name) {
+            return None;
+        }
+        Mention::Emoji {
+            name: name.to_string(),
+            id: snowflake(id)?,
+            animated,
+        }
+    };
+    Some((mention, end + 1))
+}
+
+fn snowflake<T>(s: &str) -> Option<Snowflake<T>>
+where
+    T: Snowflakable,
+{
+    // u64's FromStr allows a leading `+`, which discord does not
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_mentions, Mention, MentionSpan};
+    use crate::{
+        models::{traits::Mentionable, user::User},
+        Snowflake,
+    };
+
+    const USER: Snowflake<User> = Snowflake::new(445556389532925952);
+
+    fn mentions(s: &str) -> Vec<Mention> {
+        parse_mentions(s).map(|m| m.mention).collect()
+    }
+
+    #[test]
+    fn user() {
+        assert_eq!(mentions("<@445556389532925952>"), [Mention::User(USER)]);
+        assert_eq!(mentions("<@!445556389532925952>"), [Mention::User(USER)]);
+        assert_eq!(USER.mention().to_string(), "<@445556389532925952>");
+    }
+
+    #[test]
+    fn channel_and_role() {
+        assert_eq!(
+            mentions("<#123> and <@&456>"),
+            [Mention::Channel(123.into()), Mention::Role(456.into())]
+        );
+        assert_eq!(Mention::Role(456.into()).to_string(), "<@&456>");
+        assert_eq!(Mention::Channel(123.into()).to_string(), "<#123>");
+    }
+
+    #[test]
+    fn emoji() {
+        let emoji = Mention::Emoji {
+            name: "blob_wave".to_string(),
+            id: 789.into(),
+            animated: true,
+        };
+        assert_eq!(mentions("<a:blob_wave:789>"), vec![emoji.clone()]);
+        assert_eq!(emoji.to_string(), "<a:blob_wave:789>");
+        assert_eq!(
+            mentions("<:thonk:42>"),
+            [Mention::Emoji {
+                name: "thonk".to_string(),
+                id: 42.into(),
+                animated: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn command() {
+        let command = Mention::Command {
+            name: "config set".to_string(),
+            id: 1001.into(),
+        };
+        assert_eq!(mentions("try </config set:1001>!"), vec![command.clone()]);
+        assert_eq!(command.to_string(), "</config set:1001>");
+    }
+
+    #[test]
+    fn everyone() {
+        assert_eq!(
+            mentions("@everyone @here @someone"),
+            [Mention::Everyone, Mention::Here]
+        );
+    }
+
+    #[test]
+    fn spans() {
+        let s = "héllo <@1> <#2>";
+        let spans = parse_mentions(s).collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            [
+                MentionSpan {
+                    mention: Mention::User(1.into()),
+                    span: 7..11,
+                },
+                MentionSpan {
+                    mention: Mention::Channel(2.into()),
+                    span: 12..16,
+                },
+            ]
+        );
+        assert_eq!(&s[spans[0].span.clone()], "<@1>");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(mentions("<@> <@abc> <#+1> <:name:> <::1> < @1> <@1").is_empty());
+        // an invalid mention does not swallow a valid one after it
+        assert_eq!(mentions("<<@1>"), [Mention::User(1.into())]);
+        assert_eq!(mentions("<:a b:1><@&2>"), [Mention::Role(2.into())]);
+        // names have the same rules as in emoji
+        assert!(mentions("<:x:1> <:émoji:2>").is_empty());
+        let far = format!("<@1{}>", "0".repeat(super::MAX_LENGTH));
+        assert!(mentions(&far).is_empty());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("<@!1>".parse(), Ok(Mention::User(1.into())));
+        assert_eq!("@everyone".parse(), Ok(Mention::Everyone));
+        assert!("<@1> ".parse::<Mention>().is_err());
+        assert!("hello".parse::<Mention>().is_err());
+    }
+}
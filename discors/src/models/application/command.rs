@@ -0,0 +1,720 @@
+use std::{
+    collections::HashMap,
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{
+    int_enum,
+    locale::Locale,
+    mention::Mention,
+    models::{application::Application, channel::ChannelType, guild::Guild, traits::Mentionable},
+    permissions::Permissions,
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// The names or descriptions of a command in other languages.
+pub type Localizations = HashMap<Locale, String>;
+
+/// A command registered by an application, like a slash command.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/application-commands#application-command-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationCommand {
+    pub id: Snowflake<ApplicationCommand>,
+    #[serde(rename = "type", default = "chat_input")]
+    pub kind: CommandType,
+    pub application_id: Snowflake<Application>,
+    // only given for guild commands
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    pub name: String,
+    #[serde(default)]
+    pub name_localizations: Option<Localizations>,
+    // empty for user and message commands
+    pub description: String,
+    #[serde(default)]
+    pub description_localizations: Option<Localizations>,
+    #[serde(default)]
+    pub options: Vec<CommandOption>,
+    // the permissions members need to use the command by default, `None` allows everyone
+    #[serde(default, serialize_with = "serialize_permissions")]
+    pub default_member_permissions: Option<Permissions>,
+    // whether the command can be used in direct messages, only for global commands
+    #[serde(default)]
+    pub dm_permission: Option<bool>,
+    #[serde(default)]
+    pub nsfw: bool,
+    // changes every time the command is updated
+    pub version: String,
+}
+
+impl ApplicationCommand {
+    /// Gets an option of the command by its name.
+    pub fn option(&self, name: &str) -> Option<&CommandOption> {
+        self.options.iter().find(|o| o.name == name)
+    }
+}
+
+impl Snowflakable for ApplicationCommand {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+impl Mentionable for ApplicationCommand {
+    fn mention(&self) -> Mention {
+        Mention::Command {
+            name: self.name.clone(),
+            id: self.id,
+        }
+    }
+}
+
+int_enum! {
+    /// The type of an [`ApplicationCommand`], which is how it is used.
+    ///
+    /// [Read more](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types)
+    CommandType: u8 {
+        /// A slash command.
+        ChatInput = 1,
+        /// A command in the context menu of a user.
+        User = 2,
+        /// A command in the context menu of a message.
+        Message = 3,
+        /// The command which launches an activity.
+        PrimaryEntryPoint = 4,
+    }
+}
+
+// commands were only slash commands before types were added
+fn chat_input() -> CommandType {
+    CommandType::ChatInput
+}
+
+int_enum! {
+    /// The type of a [`CommandOption`], which is the kind of value it takes.
+    ///
+    /// [Read more](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type)
+    CommandOptionType: u8 {
+        SubCommand = 1,
+        SubCommandGroup = 2,
+        String = 3,
+        /// Any integer between -2^53 and 2^53.
+        Integer = 4,
+        Boolean = 5,
+        User = 6,
+        /// Any channel type and category.
+        Channel = 7,
+        Role = 8,
+        /// Users and roles.
+        Mentionable = 9,
+        /// Any double between -2^53 and 2^53.
+        Number = 10,
+        Attachment = 11,
+    }
+}
+
+impl CommandOptionType {
+    /// Checks whether this is a subcommand or a subcommand group, which have options instead of a value.
+    pub fn is_subcommand(&self) -> bool {
+        matches!(
+            self,
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+        )
+    }
+}
+
+/// An option of a command, which is either a value the user gives or a subcommand.
+///
+/// This is used both for the options of registered commands and to create commands.
+///
+/// ```rust
+/// use discors::models::application::command::{CommandOption, CommandOptionType};
+///
+/// let option = CommandOption::new(CommandOptionType::Integer, "amount", "How many messages to delete")
+///     .required(true)
+///     .min_value(1)
+///     .max_value(100);
+/// assert!(option.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandOption {
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<Localizations>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    // the values to choose from, for string, integer and number options
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<CommandOptionChoice>,
+    // the options of a subcommand, or the subcommands of a group
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandOption>,
+    // the channel types a channel option is limited to, all types when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<CommandNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<CommandNumber>,
+    // the length limits of a string option, from 0 to 6000
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    // whether the choices are given by autocomplete interactions, this can not be used with `choices`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub autocomplete: bool,
+}
+
+impl CommandOption {
+    /// Creates an option from its type, name and description.
+    pub fn new(
+        kind: CommandOptionType,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+            required: false,
+            choices: Vec::new(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: false,
+        }
+    }
+    /// Sets the name of the option in a language.
+    pub fn name_localized(mut self, locale: Locale, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(HashMap::new)
+            .insert(locale, name.into());
+        self
+    }
+    /// Sets the description of the option in a language.
+    pub fn description_localized(mut self, locale: Locale, description: impl Into<String>) -> Self {
+        self.description_localizations
+            .get_or_insert_with(HashMap::new)
+            .insert(locale, description.into());
+        self
+    }
+    /// Sets whether the option has to be given, required options must come before optional ones.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+    /// Adds a value to choose from, at most 25.
+    pub fn choice(mut self, name: impl Into<String>, value: impl Into<CommandChoiceValue>) -> Self {
+        self.choices.push(CommandOptionChoice::new(name, value));
+        self
+    }
+    /// Adds an option to a subcommand, or a subcommand to a group.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+    /// Limits a channel option to a type of channel.
+    pub fn channel_type(mut self, kind: ChannelType) -> Self {
+        self.channel_types.push(kind);
+        self
+    }
+    /// Sets the smallest value of an integer or number option.
+    pub fn min_value(mut self, min: impl Into<CommandNumber>) -> Self {
+        self.min_value = Some(min.into());
+        self
+    }
+    /// Sets the largest value of an integer or number option.
+    pub fn max_value(mut self, max: impl Into<CommandNumber>) -> Self {
+        self.max_value = Some(max.into());
+        self
+    }
+    /// Sets the shortest a string option can be.
+    pub fn min_length(mut self, min: u16) -> Self {
+        self.min_length = Some(min);
+        self
+    }
+    /// Sets the longest a string option can be.
+    pub fn max_length(mut self, max: u16) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+    /// Sets whether the choices are given by autocomplete interactions.
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = autocomplete;
+        self
+    }
+    /// Checks the option and its options are within discord's limits.
+    pub fn validate(&self) -> Result<(), CommandError> {
+        validate_name(&self.name, CommandType::ChatInput)?;
+        validate_description(&self.description)?;
+        if self.choices.len() > command_limits::CHOICES {
+            return Err(CommandError::TooManyChoices(self.choices.len()));
+        }
+        if self.autocomplete && !self.choices.is_empty() {
+            return Err(CommandError::ChoicesWithAutocomplete(self.name.clone()));
+        }
+        validate_options(&self.options)
+    }
+}
+
+/// A value a user can choose for an option.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-choice-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandOptionChoice {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    pub value: CommandChoiceValue,
+}
+
+impl CommandOptionChoice {
+    /// Creates a choice from the name shown to users and its value.
+    pub fn new(name: impl Into<String>, value: impl Into<CommandChoiceValue>) -> Self {
+        Self {
+            name: name.into(),
+            name_localizations: None,
+            value: value.into(),
+        }
+    }
+    /// Sets the name of the choice in a language.
+    pub fn name_localized(mut self, locale: Locale, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(HashMap::new)
+            .insert(locale, name.into());
+        self
+    }
+}
+
+/// The value of a [`CommandOptionChoice`], which has the type of its option.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CommandChoiceValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+}
+
+impl From<&str> for CommandChoiceValue {
+    fn from(s: &str) -> Self {
+        CommandChoiceValue::String(s.to_string())
+    }
+}
+
+impl From<String> for CommandChoiceValue {
+    fn from(s: String) -> Self {
+        CommandChoiceValue::String(s)
+    }
+}
+
+impl From<i64> for CommandChoiceValue {
+    fn from(i: i64) -> Self {
+        CommandChoiceValue::Integer(i)
+    }
+}
+
+impl From<f64> for CommandChoiceValue {
+    fn from(f: f64) -> Self {
+        CommandChoiceValue::Number(f)
+    }
+}
+
+/// The bound of an integer or number option.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CommandNumber {
+    Integer(i64),
+    Number(f64),
+}
+
+impl From<i64> for CommandNumber {
+    fn from(i: i64) -> Self {
+        CommandNumber::Integer(i)
+    }
+}
+
+impl From<f64> for CommandNumber {
+    fn from(f: f64) -> Self {
+        CommandNumber::Number(f)
+    }
+}
+
+/// The limits on commands.
+pub mod command_limits {
+    use std::ops::RangeInclusive;
+
+    /// How many characters the names of commands and options can have.
+    pub const NAME: RangeInclusive<usize> = 1..=32;
+    /// How many characters the descriptions of slash commands and options can have.
+    pub const DESCRIPTION: RangeInclusive<usize> = 1..=100;
+    /// How many options a command or subcommand can have.
+    pub const OPTIONS: usize = 25;
+    /// How many choices an option can have.
+    pub const CHOICES: usize = 25;
+    /// How many characters the name of a choice can have.
+    pub const CHOICE_NAME: RangeInclusive<usize> = 1..=100;
+    /// How many characters the value of a string choice can have.
+    pub const CHOICE_VALUE: usize = 100;
+}
+
+/// A builder for creating or overwriting a command.
+///
+/// ```rust
+/// use discors::{
+///     locale::Locale,
+///     models::application::command::{CommandOption, CommandOptionType, CreateCommand},
+///     permissions::Permissions,
+/// };
+///
+/// let command = CreateCommand::new("purge", "Deletes messages")
+///     .description_localized(Locale::German, "Löscht Nachrichten")
+///     .option(CommandOption::new(CommandOptionType::Integer, "amount", "How many").required(true))
+///     .default_member_permissions(Permissions::MANAGE_MESSAGES);
+/// assert!(command.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/interactions/application-commands#create-global-application-command)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateCommand {
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    pub(crate) kind: CommandType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name_localizations: Option<Localizations>,
+    pub(crate) description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description_localizations: Option<Localizations>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) options: Vec<CommandOption>,
+    #[serde(serialize_with = "serialize_permissions")]
+    pub(crate) default_member_permissions: Option<Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) dm_permission: Option<bool>,
+    pub(crate) nsfw: bool,
+}
+
+impl CreateCommand {
+    /// Creates a slash command from its name and description.
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: CommandType::ChatInput,
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+            options: Vec::new(),
+            default_member_permissions: None,
+            dm_permission: None,
+            nsfw: false,
+        }
+    }
+    /// Creates a command in the context menu of users, its name can have capitals and spaces.
+    pub fn user(name: impl Into<String>) -> Self {
+        Self::new(name, "").kind(CommandType::User)
+    }
+    /// Creates a command in the context menu of messages, its name can have capitals and spaces.
+    pub fn message(name: impl Into<String>) -> Self {
+        Self::new(name, "").kind(CommandType::Message)
+    }
+    /// Sets the type of the command.
+    pub fn kind(mut self, kind: CommandType) -> Self {
+        self.kind = kind;
+        self
+    }
+    /// Sets the name of the command in a language.
+    pub fn name_localized(mut self, locale: Locale, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(HashMap::new)
+            .insert(locale, name.into());
+        self
+    }
+    /// Sets the description of the command in a language.
+    pub fn description_localized(mut self, locale: Locale, description: impl Into<String>) -> Self {
+        self.description_localizations
+            .get_or_insert_with(HashMap::new)
+            .insert(locale, description.into());
+        self
+    }
+    /// Adds an option or subcommand, at most 25.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+    /// Sets the permissions members need to use the command by default, server admins can change this.
+    pub fn default_member_permissions(mut self, permissions: Permissions) -> Self {
+        self.default_member_permissions = Some(permissions);
+        self
+    }
+    /// Sets whether a global command can be used in direct messages.
+    pub fn dm_permission(mut self, dm_permission: bool) -> Self {
+        self.dm_permission = Some(dm_permission);
+        self
+    }
+    /// Sets whether the command is age restricted.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = nsfw;
+        self
+    }
+    /// The name of the command.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    /// The type of the command.
+    pub fn get_kind(&self) -> CommandType {
+        self.kind
+    }
+    /// Checks the command and its options are within discord's limits.
+    pub fn validate(&self) -> Result<(), CommandError> {
+        validate_name(&self.name, self.kind)?;
+        if self.kind == CommandType::ChatInput {
+            validate_description(&self.description)?;
+            validate_options(&self.options)
+        } else if !self.description.is_empty() || !self.options.is_empty() {
+            Err(CommandError::ContextMenuWithDescription(self.name.clone()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn validate_name(name: &str, kind: CommandType) -> Result<(), CommandError> {
+    let len = name.chars().count();
+    let valid = command_limits::NAME.contains(&len)
+        && match kind {
+            // context menu commands can have spaces and capitals
+            CommandType::User | CommandType::Message => true,
+            _ => name
+                .chars()
+                .all(|c| (c.is_alphanumeric() || c == '-' || c == '_') && !c.is_uppercase()),
+        };
+    if valid {
+        Ok(())
+    } else {
+        Err(CommandError::InvalidName(name.to_string()))
+    }
+}
+
+fn validate_description(description: &str) -> Result<(), CommandError> {
+    let len = description.chars().count();
+    if command_limits::DESCRIPTION.contains(&len) {
+        Ok(())
+    } else {
+        Err(CommandError::Description(len))
+    }
+}
+
+fn validate_options(options: &[CommandOption]) -> Result<(), CommandError> {
+    if options.len() > command_limits::OPTIONS {
+        return Err(CommandError::TooManyOptions(options.len()));
+    }
+    let mut optional = false;
+    for option in options {
+        if option.required && optional {
+            return Err(CommandError::RequiredAfterOptional(option.name.clone()));
+        }
+        optional |= !option.required;
+        option.validate()?;
+    }
+    Ok(())
+}
+
+// discord takes the permissions as a string here
+fn serialize_permissions<S>(permissions: &Option<Permissions>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match permissions {
+        Some(permissions) => s.collect_str(&permissions.0),
+        None => s.serialize_none(),
+    }
+}
+
+/// The error given when a command is outside of discord's limits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CommandError {
+    /// The name is not 1 to 32 characters, or a slash command name has capitals or characters other than letters, numbers, `-` and `_`.
+    InvalidName(String),
+    /// The description is not 1 to 100 characters long, with the length that was given.
+    Description(usize),
+    /// There are more than 25 options, with the amount that was given.
+    TooManyOptions(usize),
+    /// There are more than 25 choices, with the amount that was given.
+    TooManyChoices(usize),
+    /// A required option, with this name, comes after an optional one.
+    RequiredAfterOptional(String),
+    /// An option, with this name, has choices and autocomplete.
+    ChoicesWithAutocomplete(String),
+    /// A user or message command, with this name, has a description or options.
+    ContextMenuWithDescription(String),
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::InvalidName(name) => write!(f, "{:?} is not a valid command name", name),
+            CommandError::Description(len) => write!(
+                f,
+                "command description is {} characters long, it must be 1 to 100",
+                len
+            ),
+            CommandError::TooManyOptions(amount) => write!(
+                f,
+                "{} options were given, the limit is {}",
+                amount,
+                command_limits::OPTIONS
+            ),
+            CommandError::TooManyChoices(amount) => write!(
+                f,
+                "{} choices were given, the limit is {}",
+                amount,
+                command_limits::CHOICES
+            ),
+            CommandError::RequiredAfterOptional(name) => write!(
+                f,
+                "the required option {:?} comes after an optional option",
+                name
+            ),
+            CommandError::ChoicesWithAutocomplete(name) => write!(
+                f,
+                "the option {:?} has choices and autocomplete, only one can be used",
+                name
+            ),
+            CommandError::ContextMenuWithDescription(name) => write!(
+                f,
+                "the context menu command {:?} can not have a description or options",
+                name
+            ),
+        }
+    }
+}
+
+impl StdError for CommandError {}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        ApplicationCommand, CommandChoiceValue, CommandError, CommandNumber, CommandOption,
+        CommandOptionType, CommandType, CreateCommand,
+    };
+    use crate::{locale::Locale, models::channel::ChannelType, permissions::Permissions};
+
+    #[test]
+    fn deserialize() {
+        let command = serde_json::from_str::<ApplicationCommand>(
+            r#"{
+                "id": "1",
+                "type": 1,
+                "application_id": "2",
+                "name": "blep",
+                "name_localizations": {"de": "blepp", "xx-XX": "unknown"},
+                "description": "Send a random adorable animal photo",
+                "options": [{
+                    "type": 3,
+                    "name": "animal",
+                    "description": "The type of animal",
+                    "required": true,
+                    "choices": [{"name": "Dog", "value": "animal_dog"}, {"name": "Cat", "value": "animal_cat"}]
+                }, {
+                    "type": 10,
+                    "name": "size",
+                    "description": "How big",
+                    "min_value": 0.5,
+                    "max_value": 10
+                }, {
+                    "type": 7,
+                    "name": "channel",
+                    "description": "Where to send it",
+                    "channel_types": [0, 5]
+                }],
+                "default_member_permissions": "8",
+                "version": "3"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(command.kind, CommandType::ChatInput);
+        assert_eq!(
+            command.name_localizations.as_ref().unwrap()[&Locale::German],
+            "blepp"
+        );
+        // locales this library does not know are kept
+        assert_eq!(
+            command.name_localizations.as_ref().unwrap()[&Locale::Unknown("xx-XX".to_string())],
+            "unknown"
+        );
+        let animal = &command.options[0];
+        assert_eq!(
+            animal.choices[1].value,
+            CommandChoiceValue::String("animal_cat".to_string())
+        );
+        let size = command.option("size").unwrap();
+        assert_eq!(size.min_value, Some(CommandNumber::Number(0.5)));
+        assert_eq!(size.max_value, Some(CommandNumber::Integer(10)));
+        assert_eq!(
+            command.options[2].channel_types,
+            [ChannelType::GuildText, ChannelType::GuildAnnouncement]
+        );
+        assert_eq!(
+            command.default_member_permissions,
+            Some(Permissions::ADMINISTRATOR)
+        );
+    }
+
+    #[test]
+    fn create() {
+        let command = CreateCommand::new("purge", "Deletes messages")
+            .option(
+                CommandOption::new(CommandOptionType::Integer, "amount", "How many")
+                    .required(true)
+                    .choice("ten", 10),
+            )
+            .default_member_permissions(Permissions::MANAGE_MESSAGES);
+        assert_eq!(
+            serde_json::to_string(&command).unwrap(),
+            r#"{"name":"purge","type":1,"description":"Deletes messages","options":[{"type":4,"name":"amount","description":"How many","required":true,"choices":[{"name":"ten","value":10}]}],"default_member_permissions":"8192","nsfw":false}"#
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            CreateCommand::new("Purge", "a").validate(),
+            Err(CommandError::InvalidName("Purge".to_string()))
+        );
+        assert!(CreateCommand::new("Purge me", "")
+            .kind(CommandType::Message)
+            .validate()
+            .is_ok());
+        assert_eq!(
+            CreateCommand::new("purge", "a")
+                .option(CommandOption::new(CommandOptionType::User, "a", "a"))
+                .option(CommandOption::new(CommandOptionType::User, "b", "b").required(true))
+                .validate(),
+            Err(CommandError::RequiredAfterOptional("b".to_string()))
+        );
+        assert_eq!(
+            CommandOption::new(CommandOptionType::String, "a", "a")
+                .choice("a", "a")
+                .autocomplete(true)
+                .validate(),
+            Err(CommandError::ChoicesWithAutocomplete("a".to_string()))
+        );
+    }
+}
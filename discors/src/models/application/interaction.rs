@@ -0,0 +1,783 @@
+use std::{
+    collections::HashMap,
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    error::Error,
+    int_enum,
+    locale::Locale,
+    models::{
+        application::{
+            command::{ApplicationCommand, CommandOptionChoice, CommandOptionType, CommandType},
+            Application,
+        },
+        channel::{
+            attachment::{Attachment, AttachmentFile},
+            component::{validate_custom_id, ActionRow, Component, ComponentType, TextInput},
+            message::{CreateMessage, EditMessage, Message, MessageFlags},
+            Channel,
+        },
+        guild::{member::Member, role::Role, Guild},
+        user::User,
+    },
+    permissions::Permissions,
+    snowflake::{Generic, Snowflakable, Snowflake},
+    timestamp::Timestamp,
+};
+
+/// An interaction, sent when a user uses a command or component, or submits a modal.
+///
+/// It has to be responded to within 3 seconds of being created, after which the token can be used for follow-ups for 15 minutes.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct Interaction {
+    pub id: Snowflake<Interaction>,
+    pub application_id: Snowflake<Application>,
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    // not given for pings, deserialized by the type of the interaction
+    #[serde(default, skip_deserializing)]
+    pub data: Option<InteractionData>,
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    // a partial channel
+    #[serde(default)]
+    pub channel: Option<Channel>,
+    #[serde(default)]
+    pub channel_id: Option<Snowflake<Channel>>,
+    // given in guilds, with the member's user
+    #[serde(default)]
+    pub member: Option<Member>,
+    // given outside of guilds
+    #[serde(default)]
+    pub user: Option<User>,
+    // used to respond and to send follow-ups
+    pub token: String,
+    pub version: u8,
+    // the message a component is attached to
+    #[serde(default)]
+    pub message: Option<Message>,
+    // the permissions the bot has in the channel
+    #[serde(default)]
+    pub app_permissions: Option<Permissions>,
+    // the language of the user, not given for pings
+    #[serde(default)]
+    pub locale: Option<Locale>,
+    #[serde(default)]
+    pub guild_locale: Option<Locale>,
+}
+
+impl Interaction {
+    /// The user who used the interaction, in a guild or a direct message.
+    pub fn user(&self) -> Option<&User> {
+        self.member
+            .as_ref()
+            .and_then(|member| member.user.as_ref())
+            .or(self.user.as_ref())
+    }
+    /// The data of a command or autocomplete interaction.
+    pub fn command(&self) -> Option<&CommandData> {
+        match &self.data {
+            Some(InteractionData::Command(data)) => Some(data),
+            _ => None,
+        }
+    }
+    /// The data of a component interaction.
+    pub fn component(&self) -> Option<&ComponentData> {
+        match &self.data {
+            Some(InteractionData::Component(data)) => Some(data),
+            _ => None,
+        }
+    }
+    /// The data of a modal submit interaction.
+    pub fn modal_submit(&self) -> Option<&ModalSubmitData> {
+        match &self.data {
+            Some(InteractionData::ModalSubmit(data)) => Some(data),
+            _ => None,
+        }
+    }
+    /// When the initial response has to be sent by.
+    pub fn response_deadline(&self) -> Timestamp {
+        self.id.to_time() + interaction_limits::RESPONSE_WINDOW
+    }
+    /// Whether the window to send the initial response in has passed.
+    pub fn is_expired(&self) -> bool {
+        self.response_deadline().until().is_none()
+    }
+    /// Whether the token can no longer be used for follow-ups.
+    pub fn is_token_expired(&self) -> bool {
+        (self.id.to_time() + interaction_limits::TOKEN_LIFETIME)
+            .until()
+            .is_none()
+    }
+}
+
+impl Serialize for Interaction {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Interaction::serialize(self, s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut interaction = serde_json::Value::deserialize(d)?;
+        let data = interaction
+            .as_object_mut()
+            .and_then(|interaction| interaction.remove("data"))
+            .filter(|data| !data.is_null());
+        let mut interaction = Interaction::deserialize(interaction).map_err(de::Error::custom)?;
+        interaction.data = match data {
+            Some(data) => {
+                InteractionData::from_kind(interaction.kind, data).map_err(de::Error::custom)?
+            }
+            None => None,
+        };
+        Ok(interaction)
+    }
+}
+
+impl Snowflakable for Interaction {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+int_enum! {
+    /// The type of an [`Interaction`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type)
+    InteractionType: u8 {
+        /// Sent by discord to check an interactions endpoint is working.
+        Ping = 1,
+        ApplicationCommand = 2,
+        MessageComponent = 3,
+        /// A user is typing an option which has autocomplete.
+        ApplicationCommandAutocomplete = 4,
+        ModalSubmit = 5,
+    }
+}
+
+/// The data of an [`Interaction`], which depends on its type.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum InteractionData {
+    /// The data of a command or autocomplete interaction.
+    Command(Box<CommandData>),
+    Component(Box<ComponentData>),
+    ModalSubmit(Box<ModalSubmitData>),
+}
+
+impl InteractionData {
+    // the data of interaction types this library does not know is left out
+    fn from_kind(
+        kind: InteractionType,
+        data: serde_json::Value,
+    ) -> Result<Option<Self>, serde_json::Error> {
+        Ok(Some(match kind {
+            InteractionType::ApplicationCommand
+            | InteractionType::ApplicationCommandAutocomplete => {
+                InteractionData::Command(serde_json::from_value(data)?)
+            }
+            InteractionType::MessageComponent => {
+                InteractionData::Component(serde_json::from_value(data)?)
+            }
+            InteractionType::ModalSubmit => {
+                InteractionData::ModalSubmit(serde_json::from_value(data)?)
+            }
+            _ => return Ok(None),
+        }))
+    }
+}
+
+/// The component that was used in an interaction.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-message-component-data-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentData {
+    pub custom_id: String,
+    pub component_type: ComponentType,
+    // the values chosen in a select menu
+    #[serde(default)]
+    pub values: Vec<String>,
+    // the users, roles and channels chosen in a select menu
+    #[serde(default)]
+    pub resolved: ResolvedData,
+}
+
+impl ComponentData {
+    /// The users chosen in a user or mentionable select menu.
+    pub fn users(&self) -> Vec<Snowflake<User>> {
+        self.ids(|id| {
+            self.component_type == ComponentType::UserSelect || self.resolved.users.contains_key(id)
+        })
+    }
+    /// The roles chosen in a role or mentionable select menu.
+    pub fn roles(&self) -> Vec<Snowflake<Role>> {
+        self.ids(|id| {
+            self.component_type == ComponentType::RoleSelect || self.resolved.roles.contains_key(id)
+        })
+    }
+    /// The channels chosen in a channel select menu.
+    pub fn channels(&self) -> Vec<Snowflake<Channel>> {
+        self.ids(|_| self.component_type == ComponentType::ChannelSelect)
+    }
+    // the values of id select menus, mentionable selects mix users and roles
+    fn ids<T>(&self, keep: impl Fn(&Snowflake<T>) -> bool) -> Vec<Snowflake<T>>
+    where
+        T: Snowflakable,
+    {
+        self.values
+            .iter()
+            .filter_map(|value| value.parse().ok())
+            .filter(keep)
+            .collect()
+    }
+}
+
+/// The modal that was submitted in an interaction, with the values the user gave.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-modal-submit-data-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModalSubmitData {
+    pub custom_id: String,
+    pub components: Vec<ActionRow>,
+}
+
+impl ModalSubmitData {
+    /// The value the user entered in the text input with this custom id.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find_map(|component| match component {
+                Component::TextInput(input) if input.custom_id == custom_id => {
+                    input.value.as_deref()
+                }
+                _ => None,
+            })
+    }
+}
+
+/// The command that was used in an interaction, with the options the user gave.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-data-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandData {
+    pub id: Snowflake<ApplicationCommand>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: CommandType,
+    // the users, roles, channels and messages the options refer to
+    #[serde(default)]
+    pub resolved: ResolvedData,
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    // the guild of a guild command
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    // the id of the user or message a context menu command was used on
+    #[serde(default)]
+    pub target_id: Option<Snowflake<Generic>>,
+}
+
+impl CommandData {
+    /// The user a user command was used on, from the resolved data.
+    pub fn target_user(&self) -> Option<&User> {
+        self.resolved.users.get(&self.target(CommandType::User)?)
+    }
+    /// The member a user command was used on, without its user, only given in guilds.
+    pub fn target_member(&self) -> Option<&Member> {
+        self.resolved.members.get(&self.target(CommandType::User)?)
+    }
+    /// The message a message command was used on, from the resolved data.
+    pub fn target_message(&self) -> Option<&Message> {
+        self.resolved
+            .messages
+            .get(&self.target(CommandType::Message)?)
+    }
+    // the id of the target of a context menu command of a type
+    fn target<T>(&self, kind: CommandType) -> Option<Snowflake<T>>
+    where
+        T: Snowflakable,
+    {
+        self.target_id
+            .filter(|_| self.kind == kind)
+            .map(|id| id.convert())
+    }
+}
+
+/// An option the user gave to a command, or the subcommand they used.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-interaction-data-option-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandDataOption {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    // not given for subcommands and groups
+    #[serde(default)]
+    pub value: Option<OptionValue>,
+    // the options of a subcommand, or the subcommand of a group
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    // whether the user is typing this option, in autocomplete interactions
+    #[serde(default)]
+    pub focused: bool,
+}
+
+/// The value of a [`CommandDataOption`], users, channels, roles and attachments are given as their id.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OptionValue {
+    Boolean(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+/// The full objects of the users, roles, channels, messages and attachments given as options.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-resolved-data-structure)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResolvedData {
+    pub users: HashMap<Snowflake<User>, User>,
+    // partial members, without their user
+    pub members: HashMap<Snowflake<User>, Member>,
+    pub roles: HashMap<Snowflake<Role>, Role>,
+    // partial channels, with their id, name, type and permissions
+    pub channels: HashMap<Snowflake<Channel>, Channel>,
+    pub messages: HashMap<Snowflake<Message>, Message>,
+    pub attachments: HashMap<Snowflake<Attachment>, Attachment>,
+}
+
+int_enum! {
+    /// The type of an [`InteractionResponse`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type)
+    InteractionCallbackType: u8 {
+        /// Acknowledges a ping.
+        Pong = 1,
+        /// Responds with a message.
+        ChannelMessageWithSource = 4,
+        /// Shows a loading state, the message is sent later.
+        DeferredChannelMessageWithSource = 5,
+        /// Acknowledges a component, the message it is on can be edited later.
+        DeferredUpdateMessage = 6,
+        /// Edits the message a component is on.
+        UpdateMessage = 7,
+        ApplicationCommandAutocompleteResult = 8,
+        Modal = 9,
+        PremiumRequired = 10,
+        LaunchActivity = 12,
+    }
+}
+
+impl InteractionCallbackType {
+    /// Whether the response only acknowledges the interaction, with the message sent or edited later.
+    pub fn is_deferred(&self) -> bool {
+        matches!(
+            self,
+            InteractionCallbackType::DeferredChannelMessageWithSource
+                | InteractionCallbackType::DeferredUpdateMessage
+        )
+    }
+}
+
+/// The initial response to an [`Interaction`].
+///
+/// ```rust
+/// use discors::models::{
+///     application::interaction::{InteractionCallbackType, InteractionResponse},
+///     channel::message::CreateMessage,
+/// };
+///
+/// let response = InteractionResponse::message(CreateMessage::new().content("pong!").ephemeral());
+/// assert_eq!(response.kind(), InteractionCallbackType::ChannelMessageWithSource);
+/// assert!(response.validate().is_ok());
+/// assert!(InteractionResponse::defer(true).kind().is_deferred());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object)
+#[derive(Debug, Clone, Serialize)]
+pub struct InteractionResponse {
+    #[serde(rename = "type")]
+    kind: InteractionCallbackType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<CallbackData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+enum CallbackData {
+    Message(CreateMessage),
+    Update(EditMessage),
+    Autocomplete { choices: Vec<CommandOptionChoice> },
+    Modal(CreateModal),
+    // the flags of a deferred message, to make it ephemeral
+    Flags { flags: MessageFlags },
+}
+
+impl InteractionResponse {
+    fn new(kind: InteractionCallbackType, data: Option<CallbackData>) -> Self {
+        Self { kind, data }
+    }
+    /// Acknowledges a ping, which is only sent to interactions endpoints.
+    pub fn pong() -> Self {
+        Self::new(InteractionCallbackType::Pong, None)
+    }
+    /// Responds with a message, use [`CreateMessage::ephemeral`] to only show it to the user.
+    pub fn message(message: CreateMessage) -> Self {
+        Self::new(
+            InteractionCallbackType::ChannelMessageWithSource,
+            Some(CallbackData::Message(message)),
+        )
+    }
+    /// Shows that the bot is thinking, the message is sent later as a follow-up or by editing the original response.
+    ///
+    /// `ephemeral` decides whether the loading state, and the message replacing it, are only shown to the user.
+    pub fn defer(ephemeral: bool) -> Self {
+        Self::new(
+            InteractionCallbackType::DeferredChannelMessageWithSource,
+            ephemeral.then_some(CallbackData::Flags {
+                flags: MessageFlags::EPHEMERAL,
+            }),
+        )
+    }
+    /// Acknowledges a component interaction, the message it is on can be edited later with the original response.
+    pub fn defer_update() -> Self {
+        Self::new(InteractionCallbackType::DeferredUpdateMessage, None)
+    }
+    /// Edits the message the component is on.
+    pub fn update(edit: EditMessage) -> Self {
+        Self::new(
+            InteractionCallbackType::UpdateMessage,
+            Some(CallbackData::Update(edit)),
+        )
+    }
+    /// Suggests choices for the option being typed, at most 25.
+    pub fn autocomplete(choices: Vec<CommandOptionChoice>) -> Self {
+        Self::new(
+            InteractionCallbackType::ApplicationCommandAutocompleteResult,
+            Some(CallbackData::Autocomplete { choices }),
+        )
+    }
+    /// Shows a modal to the user, this can't be the response to a modal submit.
+    pub fn modal(modal: CreateModal) -> Self {
+        Self::new(
+            InteractionCallbackType::Modal,
+            Some(CallbackData::Modal(modal)),
+        )
+    }
+    /// The type of the response.
+    pub fn kind(&self) -> InteractionCallbackType {
+        self.kind
+    }
+    // the files of a message or an update
+    pub(crate) fn files(&self) -> &[AttachmentFile] {
+        match &self.data {
+            Some(CallbackData::Message(message)) => &message.files,
+            Some(CallbackData::Update(edit)) => &edit.files,
+            _ => &[],
+        }
+    }
+    /// Checks the response is within discord's limits.
+    pub fn validate(&self) -> Result<(), Error> {
+        match &self.data {
+            Some(CallbackData::Message(message)) => message.validate()?,
+            Some(CallbackData::Update(edit)) => edit.validate()?,
+            Some(CallbackData::Autocomplete { choices })
+                if choices.len() > interaction_limits::CHOICES =>
+            {
+                return Err(InteractionError::TooManyChoices(choices.len()).into())
+            }
+            Some(CallbackData::Modal(modal)) => modal.validate()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// A builder for a modal, a popup form shown to the user.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal)
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateModal {
+    custom_id: String,
+    title: String,
+    components: Vec<ActionRow>,
+}
+
+impl CreateModal {
+    /// Creates a modal, the custom id is given back when it is submitted.
+    pub fn new(custom_id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            title: title.into(),
+            components: Vec::new(),
+        }
+    }
+    /// Adds a row of components, there can be 1 to 5.
+    pub fn component(mut self, row: ActionRow) -> Self {
+        self.components.push(row);
+        self
+    }
+    /// Adds a text input in its own row.
+    pub fn text_input(self, input: TextInput) -> Self {
+        self.component(ActionRow::new().text_input(input))
+    }
+    /// Checks the modal and its components are within discord's limits.
+    pub fn validate(&self) -> Result<(), Error> {
+        validate_custom_id(&self.custom_id)?;
+        let len = self.title.chars().count();
+        if !interaction_limits::MODAL_TITLE.contains(&len) {
+            return Err(InteractionError::ModalTitle(len).into());
+        }
+        if !interaction_limits::MODAL_COMPONENTS.contains(&self.components.len()) {
+            return Err(InteractionError::ModalComponents(self.components.len()).into());
+        }
+        for row in &self.components {
+            row.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// The limits on interactions and their responses.
+pub mod interaction_limits {
+    use std::{ops::RangeInclusive, time::Duration};
+
+    /// How long after an interaction is created the initial response has to be sent.
+    pub const RESPONSE_WINDOW: Duration = Duration::from_secs(3);
+    /// How long the token of an interaction can be used for follow-ups.
+    pub const TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+    /// How many choices an autocomplete response can have.
+    pub const CHOICES: usize = 25;
+    /// How many characters the title of a modal can have.
+    pub const MODAL_TITLE: RangeInclusive<usize> = 1..=45;
+    /// How many rows of components a modal can have.
+    pub const MODAL_COMPONENTS: RangeInclusive<usize> = 1..=5;
+}
+
+/// The error given when responding to an interaction fails, or a response is outside of discord's limits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InteractionError {
+    /// The 3 seconds to send the initial response in have passed.
+    Expired,
+    /// The interaction has already been responded to.
+    AlreadyResponded,
+    /// There are more than 25 autocomplete choices, with the amount that was given.
+    TooManyChoices(usize),
+    /// The title of a modal is not 1 to 45 characters long, with the length that was given.
+    ModalTitle(usize),
+    /// A modal does not have 1 to 5 rows of components, with the amount that was given.
+    ModalComponents(usize),
+}
+
+impl Display for InteractionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InteractionError::Expired => {
+                f.write_str("the interaction was not responded to within 3 seconds")
+            }
+            InteractionError::AlreadyResponded => {
+                f.write_str("the interaction has already been responded to")
+            }
+            InteractionError::TooManyChoices(amount) => write!(
+                f,
+                "{} choices were given, the limit is {}",
+                amount,
+                interaction_limits::CHOICES
+            ),
+            InteractionError::ModalTitle(len) => write!(
+                f,
+                "modal title is {} characters long, it must be 1 to 45",
+                len
+            ),
+            InteractionError::ModalComponents(amount) => write!(
+                f,
+                "modal has {} rows of components, it must have 1 to 5",
+                amount
+            ),
+        }
+    }
+}
+
+impl StdError for InteractionError {}
+
+// An interaction as discord sends it, made now by user 4 in channel 3, for the tests of every module using interactions.
+#[cfg(test)]
+pub(crate) fn test_interaction(kind: u8, data: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "id": Snowflake::<Interaction>::from_time(Timestamp::now()).to_string(),
+        "application_id": "2", "type": kind, "token": "token", "version": 1, "data": data,
+        "channel_id": "3", "guild_locale": "de",
+        "user": {"id": "4", "username": "user", "discriminator": "0", "avatar": null},
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{
+        test_interaction, CreateModal, Interaction, InteractionError, InteractionResponse,
+        InteractionType,
+    };
+    use crate::{
+        locale::Locale,
+        models::{
+            application::command::CommandOptionChoice,
+            channel::{
+                component::{ComponentType, TextInput, TextInputStyle},
+                message::CreateMessage,
+            },
+        },
+        snowflake::Snowflake,
+        timestamp::Timestamp,
+    };
+
+    // made in 2015, with a locale this library does not know
+    fn interaction(kind: u8, data: serde_json::Value) -> serde_json::Value {
+        let mut interaction = test_interaction(kind, data);
+        interaction["id"] = json!("1");
+        interaction["locale"] = json!("xx-XX");
+        interaction
+    }
+
+    #[test]
+    fn deserialize() {
+        let component = serde_json::from_value::<Interaction>(interaction(
+            3,
+            json!({"custom_id": "delete", "component_type": 2}),
+        ))
+        .unwrap();
+        assert_eq!(component.kind, InteractionType::MessageComponent);
+        assert_eq!(component.component().unwrap().custom_id, "delete");
+        assert_eq!(
+            component.component().unwrap().component_type,
+            ComponentType::Button
+        );
+        assert_eq!(component.user().unwrap().id, 4.into());
+        // unknown locales are kept instead of failing
+        assert_eq!(component.locale, Some(Locale::Unknown("xx-XX".to_string())));
+        assert_eq!(component.guild_locale, Some(Locale::German));
+        // made in 2015, long expired
+        assert!(component.is_expired());
+        assert!(component.is_token_expired());
+
+        let command = serde_json::from_value::<Interaction>(interaction(
+            2,
+            json!({"id": "5", "name": "ping", "type": 1}),
+        ))
+        .unwrap();
+        assert_eq!(command.command().unwrap().name, "ping");
+        assert!(command.component().is_none());
+        // the data is read as the type of the interaction says, not guessed from its fields
+        assert!(serde_json::from_value::<Interaction>(interaction(
+            3,
+            json!({"id": "5", "name": "ping", "type": 1}),
+        ))
+        .is_err());
+        let modal = serde_json::from_value::<Interaction>(interaction(5, json!({
+            "custom_id": "form",
+            "components": [{"type": 1, "components": [{"type": 4, "custom_id": "text", "value": "hi"}]}],
+        })))
+        .unwrap();
+        assert_eq!(modal.modal_submit().unwrap().custom_id, "form");
+        assert_eq!(modal.modal_submit().unwrap().value("text"), Some("hi"));
+        assert_eq!(modal.modal_submit().unwrap().value("other"), None);
+
+        let select = serde_json::from_value::<Interaction>(interaction(
+            3,
+            json!({
+                "custom_id": "who", "component_type": 7, "values": ["10", "20"],
+                "resolved": {"roles": {"20": {
+                    "id": "20", "name": "role", "color": 0, "hoist": false, "position": 1,
+                    "permissions": "0", "managed": false, "mentionable": false,
+                }}},
+            }),
+        ))
+        .unwrap();
+        let data = select.component().unwrap();
+        assert_eq!(data.roles(), vec![Snowflake::new(20)]);
+        assert!(data.channels().is_empty());
+
+        // serializing gives back the data
+        let json = serde_json::to_value(&select).unwrap();
+        assert_eq!(serde_json::from_value::<Interaction>(json).unwrap(), select);
+    }
+
+    #[test]
+    fn expiry() {
+        let mut interaction =
+            serde_json::from_value::<Interaction>(interaction(1, json!(null))).unwrap();
+        interaction.id = Snowflake::from_time(Timestamp::now());
+        assert!(!interaction.is_expired());
+        assert!(interaction.response_deadline() > Timestamp::now());
+    }
+
+    #[test]
+    fn responses() {
+        assert_eq!(
+            serde_json::to_value(InteractionResponse::defer(true)).unwrap(),
+            json!({"type": 5, "data": {"flags": 64}})
+        );
+        assert_eq!(
+            serde_json::to_value(InteractionResponse::defer(false)).unwrap(),
+            json!({"type": 5})
+        );
+        assert_eq!(
+            serde_json::to_value(InteractionResponse::message(
+                CreateMessage::new().content("hi").ephemeral()
+            ))
+            .unwrap(),
+            json!({"type": 4, "data": {"content": "hi", "flags": 64}})
+        );
+        assert_eq!(
+            serde_json::to_value(InteractionResponse::autocomplete(vec![
+                CommandOptionChoice::new("one", 1)
+            ]))
+            .unwrap(),
+            json!({"type": 8, "data": {"choices": [{"name": "one", "value": 1}]}})
+        );
+        let choices = vec![CommandOptionChoice::new("one", 1); 26];
+        assert!(matches!(
+            InteractionResponse::autocomplete(choices).validate(),
+            Err(crate::error::Error::Interaction(
+                InteractionError::TooManyChoices(26)
+            ))
+        ));
+        assert!(matches!(
+            CreateModal::new("form", "").validate(),
+            Err(crate::error::Error::Interaction(
+                InteractionError::ModalTitle(0)
+            ))
+        ));
+        assert!(matches!(
+            CreateModal::new("form", "Feedback").validate(),
+            Err(crate::error::Error::Interaction(
+                InteractionError::ModalComponents(0)
+            ))
+        ));
+        let modal = CreateModal::new("form", "Feedback").text_input(TextInput::new(
+            "text",
+            "Text",
+            TextInputStyle::Paragraph,
+        ));
+        assert!(modal.validate().is_ok());
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::user::User,
+    snowflake::{Snowflakable, Snowflake},
+};
+
+pub mod command;
+pub mod interaction;
+
+/// An application, which a bot belongs to.
+///
+/// [Read more](https://discord.com/developers/docs/resources/application#application-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Application {
+    pub id: Snowflake<Application>,
+    pub name: String,
+    pub icon: Option<String>, // icon hash
+    pub description: String,
+    // whether anyone can add the bot to guilds, not only the owner
+    #[serde(default)]
+    pub bot_public: bool,
+    #[serde(default)]
+    pub owner: Option<User>,
+}
+
+impl Snowflakable for Application {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
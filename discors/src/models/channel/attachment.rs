@@ -0,0 +1,222 @@
+use std::{
+    fmt::{self, Debug},
+    io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use reqwest::multipart::Part;
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+use crate::{
+    error::Error,
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// A file attached to a message.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#attachment-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: Snowflake<Attachment>,
+    pub filename: String,
+    // alt text
+    #[serde(default)]
+    pub description: Option<String>,
+    // the media type, like `image/png`
+    #[serde(default)]
+    pub content_type: Option<String>,
+    // in bytes
+    pub size: u64,
+    pub url: String,
+    pub proxy_url: String,
+    // only set for images and videos
+    #[serde(default)]
+    pub height: Option<u32>,
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde(default)]
+    pub ephemeral: bool,
+}
+
+impl Snowflakable for Attachment {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+/// A file to upload, as part of a message, a forum post or a sticker.
+///
+/// Files are streamed when the request is sent, so files read from a path or a reader are never fully loaded into memory.
+///
+/// ```rust
+/// use discors::models::channel::attachment::AttachmentFile;
+///
+/// let file = AttachmentFile::from_bytes("cat.png", vec![0; 16])
+///     .description("a cat")
+///     .spoiler(true);
+/// assert_eq!(file.upload_filename(), "SPOILER_cat.png");
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/reference#uploading-files)
+#[derive(Clone)]
+pub struct AttachmentFile {
+    filename: String,
+    description: Option<String>,
+    spoiler: bool,
+    source: FileSource,
+}
+
+// the reader is shared between clones, it can only be read once.
+type SharedReader = Arc<Mutex<Option<Box<dyn AsyncRead + Send + Sync + Unpin>>>>;
+
+#[derive(Clone)]
+enum FileSource {
+    Path(PathBuf),
+    Bytes(Bytes),
+    Reader(SharedReader),
+}
+
+impl AttachmentFile {
+    /// A file read from a path when it is sent, named after the last part of the path.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let filename = path
+            .file_name()
+            .map_or_else(|| "file".to_string(), |n| n.to_string_lossy().into_owned());
+        Self::with_source(filename, FileSource::Path(path))
+    }
+    /// A file from data already in memory.
+    pub fn from_bytes(filename: impl Into<String>, data: impl Into<Bytes>) -> Self {
+        Self::with_source(filename.into(), FileSource::Bytes(data.into()))
+    }
+    /// A file streamed from a reader.
+    ///
+    /// A reader can only be read once, so a request with this file can not be retried when it fails, and the file can only be sent once.
+    pub fn from_reader<R>(filename: impl Into<String>, reader: R) -> Self
+    where
+        R: AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        Self::with_source(
+            filename.into(),
+            FileSource::Reader(Arc::new(Mutex::new(Some(Box::new(reader))))),
+        )
+    }
+    fn with_source(filename: String, source: FileSource) -> Self {
+        Self {
+            filename,
+            description: None,
+            spoiler: false,
+            source,
+        }
+    }
+    /// Sets the name of the file, including the extension.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = filename.into();
+        self
+    }
+    /// Sets the description (alt text) of the file.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+    /// Sets whether the file is hidden behind a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+    /// The name the file is uploaded with, discord marks files as spoilers by their name.
+    pub fn upload_filename(&self) -> String {
+        if self.spoiler && !self.filename.starts_with("SPOILER_") {
+            format!("SPOILER_{}", self.filename)
+        } else {
+            self.filename.clone()
+        }
+    }
+    pub(crate) fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    // Opens the file as a streamed part of a multipart form.
+    pub(crate) async fn part(&self) -> Result<Part, Error> {
+        let part = match &self.source {
+            FileSource::Path(path) => {
+                let file = tokio::fs::File::open(path).await?;
+                let len = file.metadata().await?.len();
+                Part::stream_with_length(reqwest::Body::wrap_stream(ReaderStream::new(file)), len)
+            }
+            FileSource::Bytes(bytes) => {
+                Part::stream_with_length(reqwest::Body::from(bytes.clone()), bytes.len() as u64)
+            }
+            FileSource::Reader(reader) => {
+                let reader = reader
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .take()
+                    .ok_or_else(|| {
+                        io::Error::other(format!(
+                            "the reader of {} was already read",
+                            self.filename
+                        ))
+                    })?;
+                Part::stream(reqwest::Body::wrap_stream(ReaderStream::new(reader)))
+            }
+        };
+        Ok(part.file_name(self.upload_filename()))
+    }
+}
+
+impl Debug for AttachmentFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match &self.source {
+            FileSource::Path(path) => format!("Path({:?})", path),
+            FileSource::Bytes(bytes) => format!("Bytes({})", bytes.len()),
+            FileSource::Reader(_) => "Reader".to_string(),
+        };
+        f.debug_struct("AttachmentFile")
+            .field("filename", &self.filename)
+            .field("description", &self.description)
+            .field("spoiler", &self.spoiler)
+            .field("source", &format_args!("{}", source))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AttachmentFile;
+
+    #[test]
+    fn filename() {
+        assert_eq!(
+            AttachmentFile::from_path("images/cat.png").upload_filename(),
+            "cat.png"
+        );
+        let file = AttachmentFile::from_bytes("a.txt", "a").spoiler(true);
+        assert_eq!(file.upload_filename(), "SPOILER_a.txt");
+        // spoilers are not marked twice
+        assert_eq!(
+            file.filename("SPOILER_b.txt").upload_filename(),
+            "SPOILER_b.txt"
+        );
+    }
+
+    #[tokio::test]
+    async fn parts() {
+        assert!(AttachmentFile::from_bytes("a.txt", "a")
+            .part()
+            .await
+            .is_ok());
+        assert!(AttachmentFile::from_path("does/not/exist.txt")
+            .part()
+            .await
+            .is_err());
+        let file = AttachmentFile::from_reader("a.txt", &b"a"[..]);
+        assert!(file.clone().part().await.is_ok());
+        // the reader was used up by the clone
+        assert!(file.part().await.is_err());
+    }
+}
@@ -0,0 +1,909 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{int_enum, models::emoji::Emoji};
+
+use super::ChannelType;
+
+int_enum! {
+    /// The type of a message component.
+    ///
+    /// [Read more](https://discord.com/developers/docs/interactions/message-components#component-object-component-types)
+    ComponentType: u8 {
+        /// A row of other components.
+        ActionRow = 1,
+        Button = 2,
+        StringSelect = 3,
+        /// A text input in a modal.
+        TextInput = 4,
+        UserSelect = 5,
+        RoleSelect = 6,
+        /// A select menu for users and roles.
+        MentionableSelect = 7,
+        ChannelSelect = 8,
+    }
+}
+
+impl ComponentType {
+    /// Whether this is one of the select menus.
+    pub fn is_select(&self) -> bool {
+        matches!(
+            self,
+            ComponentType::StringSelect
+                | ComponentType::UserSelect
+                | ComponentType::RoleSelect
+                | ComponentType::MentionableSelect
+                | ComponentType::ChannelSelect
+        )
+    }
+}
+
+/// A row of components, messages and modals have their components in up to 5 rows.
+///
+/// A row has up to 5 buttons, or a single select menu or text input.
+///
+/// ```rust
+/// use discors::models::channel::component::{ActionRow, Button, ButtonStyle};
+///
+/// let row = ActionRow::new()
+///     .button(Button::new(ButtonStyle::Success, "confirm").label("Confirm"))
+///     .button(Button::new(ButtonStyle::Danger, "cancel").label("Cancel"))
+///     .button(Button::link("https://discord.com").emoji("🔗"));
+/// assert!(row.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/interactions/message-components#action-rows)
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct ActionRow {
+    pub components: Vec<Component>,
+}
+
+#[derive(Serialize)]
+struct RawRow<'a> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    components: &'a [Component],
+}
+
+impl Serialize for ActionRow {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RawRow {
+            kind: ComponentType::ActionRow,
+            components: &self.components,
+        }
+        .serialize(s)
+    }
+}
+
+impl ActionRow {
+    /// Creates an empty row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds a button, there can be at most 5.
+    pub fn button(mut self, button: Button) -> Self {
+        self.components.push(Component::Button(button));
+        self
+    }
+    /// Adds a select menu, which has to be the only component in the row.
+    pub fn select_menu(mut self, menu: SelectMenu) -> Self {
+        self.components.push(Component::SelectMenu(menu));
+        self
+    }
+    /// Adds a text input, which has to be the only component in the row. These can only be used in modals.
+    pub fn text_input(mut self, input: TextInput) -> Self {
+        self.components.push(Component::TextInput(input));
+        self
+    }
+    /// Checks the row and its components are within discord's limits.
+    pub fn validate(&self) -> Result<(), ComponentError> {
+        let buttons = self
+            .components
+            .iter()
+            .filter(|component| matches!(component, Component::Button(_)))
+            .count();
+        match self.components.len() {
+            0 => return Err(ComponentError::EmptyRow),
+            len if buttons == len && len > component_limits::BUTTONS => {
+                return Err(ComponentError::TooManyButtons(len))
+            }
+            len if buttons != len && len > 1 => return Err(ComponentError::MixedRow),
+            _ => {}
+        }
+        for component in &self.components {
+            match component {
+                Component::Button(button) => button.validate()?,
+                Component::SelectMenu(menu) => menu.validate()?,
+                Component::TextInput(input) => input.validate()?,
+                Component::Unknown(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+// Deserializes the rows of a message, skipping top level components which are not rows so one new
+// kind of component doesn't fail the whole message.
+pub(crate) fn deserialize_rows<'de, D>(d: D) -> Result<Vec<ActionRow>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let rows = Option::<Vec<serde_json::Value>>::deserialize(d)?.unwrap_or_default();
+    Ok(rows
+        .into_iter()
+        .filter(|row| row.get("type").and_then(serde_json::Value::as_u64) == Some(1))
+        .filter_map(|row| serde_json::from_value(row).ok())
+        .collect())
+}
+
+/// Checks there are at most 5 rows, and each row is within discord's limits.
+pub fn validate_rows(rows: &[ActionRow]) -> Result<(), ComponentError> {
+    if rows.len() > component_limits::ROWS {
+        return Err(ComponentError::TooManyRows(rows.len()));
+    }
+    rows.iter().try_for_each(ActionRow::validate)
+}
+
+/// A component in an [`ActionRow`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Component {
+    Button(Button),
+    SelectMenu(SelectMenu),
+    TextInput(TextInput),
+    // components this library does not have a model for yet, as raw json
+    Unknown(serde_json::Value),
+}
+
+impl Component {
+    /// The type of the component.
+    pub fn kind(&self) -> ComponentType {
+        match self {
+            Component::Button(_) => ComponentType::Button,
+            Component::SelectMenu(menu) => menu.kind,
+            Component::TextInput(_) => ComponentType::TextInput,
+            Component::Unknown(component) => component
+                .get("type")
+                .and_then(serde_json::Value::as_u64)
+                .map_or(ComponentType::Unknown(0), |kind| {
+                    ComponentType::from(kind as u8)
+                }),
+        }
+    }
+    /// The custom id of the component, link buttons don't have one.
+    pub fn custom_id(&self) -> Option<&str> {
+        match self {
+            Component::Button(button) => button.custom_id.as_deref(),
+            Component::SelectMenu(menu) => Some(&menu.custom_id),
+            Component::TextInput(input) => Some(&input.custom_id),
+            Component::Unknown(component) => component
+                .get("custom_id")
+                .and_then(serde_json::Value::as_str),
+        }
+    }
+}
+
+// buttons and text inputs don't keep their type, so it is added when serializing
+#[derive(Serialize)]
+struct Typed<'a, T> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    #[serde(flatten)]
+    component: &'a T,
+}
+
+impl Serialize for Component {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Component::Button(button) => Typed {
+                kind: ComponentType::Button,
+                component: button,
+            }
+            .serialize(s),
+            Component::SelectMenu(menu) => menu.serialize(s),
+            Component::TextInput(input) => Typed {
+                kind: ComponentType::TextInput,
+                component: input,
+            }
+            .serialize(s),
+            Component::Unknown(component) => component.serialize(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let component = serde_json::Value::deserialize(d)?;
+        let kind = component
+            .get("type")
+            .and_then(serde_json::Value::as_u64)
+            .map(|kind| ComponentType::from(kind as u8));
+        let parsed = match kind {
+            Some(ComponentType::Button) => serde_json::from_value(component).map(Component::Button),
+            Some(ComponentType::TextInput) => {
+                serde_json::from_value(component).map(Component::TextInput)
+            }
+            Some(kind) if kind.is_select() => {
+                serde_json::from_value(component).map(Component::SelectMenu)
+            }
+            _ => Ok(Component::Unknown(component)),
+        };
+        parsed.map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Button> for Component {
+    fn from(button: Button) -> Self {
+        Component::Button(button)
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(menu: SelectMenu) -> Self {
+        Component::SelectMenu(menu)
+    }
+}
+
+impl From<TextInput> for Component {
+    fn from(input: TextInput) -> Self {
+        Component::TextInput(input)
+    }
+}
+
+int_enum! {
+    /// The style of a [`Button`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/interactions/message-components#button-object-button-styles)
+    ButtonStyle: u8 {
+        /// Blurple.
+        Primary = 1,
+        /// Grey.
+        Secondary = 2,
+        /// Green.
+        Success = 3,
+        /// Red.
+        Danger = 4,
+        /// Grey, opening a url instead of sending an interaction.
+        Link = 5,
+    }
+}
+
+/// A button, which sends an interaction when clicked, or opens a url for link buttons.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/message-components#buttons)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Button {
+    pub style: ButtonStyle,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Emoji>,
+    // every button except link buttons has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+    // only link buttons have one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+impl Button {
+    /// Creates a button with a style other than [`ButtonStyle::Link`], the custom id is given back when it is clicked.
+    pub fn new(style: ButtonStyle, custom_id: impl Into<String>) -> Self {
+        Self {
+            style,
+            label: None,
+            emoji: None,
+            custom_id: Some(custom_id.into()),
+            url: None,
+            disabled: false,
+        }
+    }
+    /// Creates a button which opens a url.
+    pub fn link(url: impl Into<String>) -> Self {
+        Self {
+            style: ButtonStyle::Link,
+            label: None,
+            emoji: None,
+            custom_id: None,
+            url: Some(url.into()),
+            disabled: false,
+        }
+    }
+    /// Sets the text of the button, at most 80 characters.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+    /// Sets the emoji shown before the label.
+    pub fn emoji(mut self, emoji: impl Into<Emoji>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+    /// Sets whether the button is greyed out and can't be clicked.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+    /// Checks the button is within discord's limits.
+    pub fn validate(&self) -> Result<(), ComponentError> {
+        match (self.style, &self.custom_id, &self.url) {
+            (ButtonStyle::Link, None, Some(_)) => {}
+            (ButtonStyle::Link, _, _) | (_, _, Some(_)) => return Err(ComponentError::InvalidLink),
+            (_, custom_id, None) => validate_custom_id(custom_id.as_deref().unwrap_or_default())?,
+        }
+        let label = self.label.as_deref().map(|l| l.chars().count());
+        if label == Some(0) {
+            return Err(ComponentError::EmptyLabel);
+        }
+        let label = label.unwrap_or(0);
+        if label > component_limits::BUTTON_LABEL {
+            return Err(ComponentError::LabelTooLong(label));
+        }
+        if label == 0 && self.emoji.is_none() {
+            return Err(ComponentError::EmptyButton);
+        }
+        Ok(())
+    }
+}
+
+/// A select menu, which sends an interaction with the values the user chose.
+///
+/// ```rust
+/// use discors::models::channel::component::{SelectMenu, SelectOption};
+///
+/// let menu = SelectMenu::string("colour")
+///     .option(SelectOption::new("Red", "red").emoji("🟥"))
+///     .option(SelectOption::new("Blue", "blue").default(true))
+///     .placeholder("Pick a colour");
+/// assert!(menu.validate().is_ok());
+/// assert!(SelectMenu::string("empty").validate().is_err());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/interactions/message-components#select-menus)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectMenu {
+    // which kind of values can be chosen
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    pub custom_id: String,
+    // only for string selects
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOption>,
+    // only for channel selects, all types are allowed if it is empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    // the amount of values that have to be chosen, from 0 to 25, defaults to 1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+impl SelectMenu {
+    fn new(kind: ComponentType, custom_id: impl Into<String>) -> Self {
+        Self {
+            kind,
+            custom_id: custom_id.into(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            placeholder: None,
+            min_values: None,
+            max_values: None,
+            disabled: false,
+        }
+    }
+    /// Creates a select menu of options, at least one option has to be added.
+    pub fn string(custom_id: impl Into<String>) -> Self {
+        Self::new(ComponentType::StringSelect, custom_id)
+    }
+    /// Creates a select menu of users.
+    pub fn user(custom_id: impl Into<String>) -> Self {
+        Self::new(ComponentType::UserSelect, custom_id)
+    }
+    /// Creates a select menu of roles.
+    pub fn role(custom_id: impl Into<String>) -> Self {
+        Self::new(ComponentType::RoleSelect, custom_id)
+    }
+    /// Creates a select menu of users and roles.
+    pub fn mentionable(custom_id: impl Into<String>) -> Self {
+        Self::new(ComponentType::MentionableSelect, custom_id)
+    }
+    /// Creates a select menu of channels.
+    pub fn channel(custom_id: impl Into<String>) -> Self {
+        Self::new(ComponentType::ChannelSelect, custom_id)
+    }
+    /// Adds an option to a string select, there can be at most 25.
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+    /// Sets the types of channels a channel select shows.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.channel_types = channel_types;
+        self
+    }
+    /// Sets the text shown when nothing is chosen, at most 150 characters.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+    /// Sets the least amount of values that have to be chosen, at most 25.
+    pub fn min_values(mut self, min_values: u8) -> Self {
+        self.min_values = Some(min_values);
+        self
+    }
+    /// Sets the most values that can be chosen, at most 25.
+    pub fn max_values(mut self, max_values: u8) -> Self {
+        self.max_values = Some(max_values);
+        self
+    }
+    /// Sets whether the select menu is greyed out and can't be used.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+    /// Checks the select menu and its options are within discord's limits.
+    pub fn validate(&self) -> Result<(), ComponentError> {
+        validate_custom_id(&self.custom_id)?;
+        if self.options.len() > component_limits::SELECT_OPTIONS {
+            return Err(ComponentError::TooManyOptions(self.options.len()));
+        }
+        if self.kind == ComponentType::StringSelect && self.options.is_empty() {
+            return Err(ComponentError::NoOptions);
+        }
+        for option in &self.options {
+            option.validate()?;
+        }
+        let placeholder = self.placeholder.as_deref().map_or(0, |p| p.chars().count());
+        if placeholder > component_limits::SELECT_PLACEHOLDER {
+            return Err(ComponentError::PlaceholderTooLong(placeholder));
+        }
+        let min = self.min_values.unwrap_or(1);
+        let max = self.max_values.unwrap_or(1);
+        if max == 0 || min > max || max as usize > component_limits::SELECT_OPTIONS {
+            return Err(ComponentError::InvalidValues { min, max });
+        }
+        Ok(())
+    }
+}
+
+/// An option of a string [`SelectMenu`].
+///
+/// [Read more](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectOption {
+    // shown to the user
+    pub label: String,
+    // given back in the interaction
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Emoji>,
+    // whether it is chosen by default
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub default: bool,
+}
+
+impl SelectOption {
+    /// Creates an option from the label shown to users and the value given back, both at most 100 characters.
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            description: None,
+            emoji: None,
+            default: false,
+        }
+    }
+    /// Sets the text shown below the label, at most 100 characters.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+    /// Sets the emoji shown before the label.
+    pub fn emoji(mut self, emoji: impl Into<Emoji>) -> Self {
+        self.emoji = Some(emoji.into());
+        self
+    }
+    /// Sets whether the option is chosen by default.
+    pub fn default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+    /// Checks the option is within discord's limits.
+    pub fn validate(&self) -> Result<(), ComponentError> {
+        if self.label.is_empty() || self.value.is_empty() {
+            return Err(ComponentError::EmptyLabel);
+        }
+        for text in [
+            Some(&self.label),
+            Some(&self.value),
+            self.description.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let len = text.chars().count();
+            if len > component_limits::SELECT_OPTION_TEXT {
+                return Err(ComponentError::LabelTooLong(len));
+            }
+        }
+        Ok(())
+    }
+}
+
+int_enum! {
+    /// The style of a [`TextInput`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/interactions/message-components#text-input-object-text-input-styles)
+    TextInputStyle: u8 {
+        /// A single line.
+        Short = 1,
+        /// Multiple lines.
+        Paragraph = 2,
+    }
+}
+
+fn short() -> TextInputStyle {
+    TextInputStyle::Short
+}
+
+/// A text input in a modal.
+///
+/// When a modal is submitted, only the custom id and value of its text inputs are given back.
+///
+/// [Read more](https://discord.com/developers/docs/interactions/message-components#text-inputs)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextInput {
+    pub custom_id: String,
+    #[serde(default = "short")]
+    pub style: TextInputStyle,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    // defaults to true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    // the text filled in, or that the user submitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+}
+
+impl TextInput {
+    /// Creates a text input, the label is shown above it and is at most 45 characters.
+    pub fn new(
+        custom_id: impl Into<String>,
+        label: impl Into<String>,
+        style: TextInputStyle,
+    ) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            style,
+            label: label.into(),
+            min_length: None,
+            max_length: None,
+            required: None,
+            value: None,
+            placeholder: None,
+        }
+    }
+    /// Sets the least amount of characters that have to be entered, at most 4000.
+    pub fn min_length(mut self, min_length: u16) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+    /// Sets the most characters that can be entered, from 1 to 4000.
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+    /// Sets whether the text input has to be filled in.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+    /// Sets the text that is already filled in.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+    /// Sets the text shown when nothing is entered, at most 100 characters.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+    /// Checks the text input is within discord's limits.
+    pub fn validate(&self) -> Result<(), ComponentError> {
+        validate_custom_id(&self.custom_id)?;
+        let label = self.label.chars().count();
+        if label == 0 {
+            return Err(ComponentError::EmptyLabel);
+        }
+        if !component_limits::TEXT_INPUT_LABEL.contains(&label) {
+            return Err(ComponentError::LabelTooLong(label));
+        }
+        let min = self.min_length.unwrap_or(0);
+        let max = self
+            .max_length
+            .unwrap_or(component_limits::TEXT_INPUT_LENGTH);
+        if max == 0 || min > max || max > component_limits::TEXT_INPUT_LENGTH {
+            return Err(ComponentError::InvalidLength { min, max });
+        }
+        let placeholder = self.placeholder.as_deref().map_or(0, |p| p.chars().count());
+        if placeholder > component_limits::TEXT_INPUT_PLACEHOLDER {
+            return Err(ComponentError::PlaceholderTooLong(placeholder));
+        }
+        Ok(())
+    }
+}
+
+/// Checks a custom id is 1 to 100 characters long.
+pub fn validate_custom_id(custom_id: &str) -> Result<(), ComponentError> {
+    let len = custom_id.chars().count();
+    if !component_limits::CUSTOM_ID.contains(&len) {
+        return Err(ComponentError::CustomId(len));
+    }
+    Ok(())
+}
+
+/// The limits on components.
+pub mod component_limits {
+    use std::ops::RangeInclusive;
+
+    /// How many rows a message or modal can have.
+    pub const ROWS: usize = 5;
+    /// How many buttons a row can have.
+    pub const BUTTONS: usize = 5;
+    /// How many options a select menu can have, and how many values can be chosen.
+    pub const SELECT_OPTIONS: usize = 25;
+    /// How many characters a custom id can have.
+    pub const CUSTOM_ID: RangeInclusive<usize> = 1..=100;
+    /// How many characters the label of a button can have.
+    pub const BUTTON_LABEL: usize = 80;
+    /// How many characters the label, value and description of a select option can have.
+    pub const SELECT_OPTION_TEXT: usize = 100;
+    /// How many characters the placeholder of a select menu can have.
+    pub const SELECT_PLACEHOLDER: usize = 150;
+    /// How many characters the label of a text input can have.
+    pub const TEXT_INPUT_LABEL: RangeInclusive<usize> = 1..=45;
+    /// How many characters the placeholder of a text input can have.
+    pub const TEXT_INPUT_PLACEHOLDER: usize = 100;
+    /// How many characters can be entered in a text input.
+    pub const TEXT_INPUT_LENGTH: u16 = 4000;
+}
+
+/// The error given when a component is outside of discord's limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ComponentError {
+    /// There are more than 5 rows, with the amount that was given.
+    TooManyRows(usize),
+    /// A row has no components.
+    EmptyRow,
+    /// A row has more than 5 buttons, with the amount that was given.
+    TooManyButtons(usize),
+    /// A row has a select menu or text input together with other components.
+    MixedRow,
+    /// A custom id is not 1 to 100 characters long, with the length that was given.
+    CustomId(usize),
+    /// A link button has no url or has a custom id, or another button has a url.
+    InvalidLink,
+    /// A button has no label or emoji.
+    EmptyButton,
+    /// A label, or the value or description of a select option, is too long, with the length that was given.
+    LabelTooLong(usize),
+    /// A label, or the value of a select option, is empty.
+    EmptyLabel,
+    /// A select menu has more than 25 options, with the amount that was given.
+    TooManyOptions(usize),
+    /// A string select menu has no options.
+    NoOptions,
+    /// A placeholder is too long, with the length that was given.
+    PlaceholderTooLong(usize),
+    /// The amount of values of a select menu is not within 0 to 25, or the minimum is above the maximum.
+    InvalidValues { min: u8, max: u8 },
+    /// The length of a text input is not within 0 to 4000, or the minimum is above the maximum.
+    InvalidLength { min: u16, max: u16 },
+}
+
+impl Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentError::TooManyRows(amount) => write!(
+                f,
+                "{} rows of components were given, the limit is {}",
+                amount,
+                component_limits::ROWS
+            ),
+            ComponentError::EmptyRow => f.write_str("a row of components is empty"),
+            ComponentError::TooManyButtons(amount) => write!(
+                f,
+                "a row has {} buttons, the limit is {}",
+                amount,
+                component_limits::BUTTONS
+            ),
+            ComponentError::MixedRow => {
+                f.write_str("a select menu or text input has to be the only component in its row")
+            }
+            ComponentError::CustomId(len) => write!(
+                f,
+                "custom id is {} characters long, it must be 1 to 100",
+                len
+            ),
+            ComponentError::InvalidLink => {
+                f.write_str("only link buttons have a url, and only other buttons have a custom id")
+            }
+            ComponentError::EmptyButton => f.write_str("a button needs a label or an emoji"),
+            ComponentError::LabelTooLong(len) => {
+                write!(f, "a label is {} characters long, which is too long", len)
+            }
+            ComponentError::EmptyLabel => f.write_str("a label can't be empty"),
+            ComponentError::TooManyOptions(amount) => write!(
+                f,
+                "a select menu has {} options, the limit is {}",
+                amount,
+                component_limits::SELECT_OPTIONS
+            ),
+            ComponentError::NoOptions => f.write_str("a string select menu needs an option"),
+            ComponentError::PlaceholderTooLong(len) => {
+                write!(
+                    f,
+                    "a placeholder is {} characters long, which is too long",
+                    len
+                )
+            }
+            ComponentError::InvalidValues { min, max } => {
+                write!(f, "a select menu can't take {} to {} values", min, max)
+            }
+            ComponentError::InvalidLength { min, max } => {
+                write!(f, "a text input can't take {} to {} characters", min, max)
+            }
+        }
+    }
+}
+
+impl StdError for ComponentError {}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{
+        validate_rows, ActionRow, Button, ButtonStyle, Component, ComponentError, ComponentType,
+        SelectMenu, SelectOption, TextInput, TextInputStyle,
+    };
+
+    #[test]
+    fn serialize() {
+        let row = ActionRow::new()
+            .button(
+                Button::new(ButtonStyle::Primary, "next")
+                    .label("Next")
+                    .disabled(true),
+            )
+            .button(Button::link("https://discord.com").emoji("🔗"));
+        assert_eq!(
+            serde_json::to_value(&row).unwrap(),
+            json!({"type": 1, "components": [
+                {"type": 2, "style": 1, "label": "Next", "custom_id": "next", "disabled": true},
+                {"type": 2, "style": 5, "emoji": {"id": null, "name": "🔗"}, "url": "https://discord.com"},
+            ]})
+        );
+        let row = ActionRow::new().select_menu(SelectMenu::user("who").max_values(3));
+        assert_eq!(
+            serde_json::to_value(&row).unwrap(),
+            json!({"type": 1, "components": [{"type": 5, "custom_id": "who", "max_values": 3}]})
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let row = serde_json::from_value::<ActionRow>(json!({"type": 1, "components": [
+            {"type": 3, "custom_id": "colour", "options": [{"label": "Red", "value": "red"}]},
+        ]}))
+        .unwrap();
+        assert_eq!(row.components[0].kind(), ComponentType::StringSelect);
+        assert_eq!(row.components[0].custom_id(), Some("colour"));
+        assert_eq!(
+            row.components[0],
+            Component::SelectMenu(
+                SelectMenu::string("colour").option(SelectOption::new("Red", "red"))
+            )
+        );
+        // the text input of a modal submit only has its custom id and value
+        let row = serde_json::from_value::<ActionRow>(json!({"type": 1, "components": [
+            {"type": 4, "custom_id": "name", "value": "ferris"},
+        ]}))
+        .unwrap();
+        match &row.components[0] {
+            Component::TextInput(input) => assert_eq!(input.value.as_deref(), Some("ferris")),
+            component => panic!("expected a text input, got {:?}", component),
+        }
+        let row =
+            serde_json::from_value::<ActionRow>(json!({"type": 1, "components": [{"type": 99}]}))
+                .unwrap();
+        assert_eq!(row.components[0].kind(), ComponentType::Unknown(99));
+    }
+
+    #[test]
+    fn validate() {
+        let button = || Button::new(ButtonStyle::Secondary, "b").label("b");
+        let row = (0..6).fold(ActionRow::new(), |row, _| row.button(button()));
+        assert_eq!(row.validate(), Err(ComponentError::TooManyButtons(6)));
+        assert_eq!(ActionRow::new().validate(), Err(ComponentError::EmptyRow));
+        assert_eq!(
+            ActionRow::new()
+                .button(button())
+                .select_menu(SelectMenu::role("r"))
+                .validate(),
+            Err(ComponentError::MixedRow)
+        );
+        let rows = vec![ActionRow::new().button(button()); 6];
+        assert_eq!(validate_rows(&rows), Err(ComponentError::TooManyRows(6)));
+        assert_eq!(
+            Button::new(ButtonStyle::Primary, "a".repeat(101))
+                .label("b")
+                .validate(),
+            Err(ComponentError::CustomId(101))
+        );
+        assert_eq!(
+            Button::new(ButtonStyle::Link, "b").label("b").validate(),
+            Err(ComponentError::InvalidLink)
+        );
+        assert_eq!(
+            Button::new(ButtonStyle::Primary, "b").validate(),
+            Err(ComponentError::EmptyButton)
+        );
+        let menu = (0..26).fold(SelectMenu::string("s"), |menu, i| {
+            menu.option(SelectOption::new(i.to_string(), i.to_string()))
+        });
+        assert_eq!(menu.validate(), Err(ComponentError::TooManyOptions(26)));
+        assert_eq!(
+            SelectMenu::channel("c")
+                .min_values(3)
+                .max_values(2)
+                .validate(),
+            Err(ComponentError::InvalidValues { min: 3, max: 2 })
+        );
+        assert!(TextInput::new("t", "Name", TextInputStyle::Short)
+            .validate()
+            .is_ok());
+        assert_eq!(
+            TextInput::new("t", "", TextInputStyle::Short).validate(),
+            Err(ComponentError::EmptyLabel)
+        );
+        assert_eq!(
+            TextInput::new("t", "a".repeat(46), TextInputStyle::Short).validate(),
+            Err(ComponentError::LabelTooLong(46))
+        );
+        assert_eq!(
+            SelectOption::new("Red", "").validate(),
+            Err(ComponentError::EmptyLabel)
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{color::Color, timestamp::Timestamp};
+
+/// A rich embed, which is displayed under the content of a message.
+///
+/// Use [`EmbedBuilder`] to construct one, which makes sure it stays within discord's limits.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#embed-object)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Embed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<EmbedFooter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<EmbedMedia>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<EmbedMedia>,
+    // only set by discord, bots cannot send videos in embeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<EmbedMedia>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<EmbedProvider>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<EmbedAuthor>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+}
+
+/// The footer of an [`Embed`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedFooter {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    // proxied urls are only ever set by discord
+    #[serde(skip_serializing)]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// An image, thumbnail or video of an [`Embed`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedMedia {
+    // this is optional for videos
+    #[serde(default)]
+    pub url: String,
+    #[serde(skip_serializing)]
+    pub proxy_url: Option<String>,
+    #[serde(skip_serializing)]
+    pub height: Option<u32>,
+    #[serde(skip_serializing)]
+    pub width: Option<u32>,
+}
+
+/// The provider of an [`Embed`], like the website of a link.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedProvider {
+    pub name: Option<String>,
+    pub url: Option<String>,
+}
+
+/// The author of an [`Embed`], displayed above the title.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedAuthor {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    #[serde(skip_serializing)]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// A field of an [`Embed`], which is a name and value pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub inline: bool,
+}
+
+impl EmbedMedia {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            proxy_url: None,
+            height: None,
+            width: None,
+        }
+    }
+}
+
+/// The limits discord puts on embeds.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#embed-object-embed-limits)
+pub mod limits {
+    pub const TITLE: usize = 256;
+    pub const DESCRIPTION: usize = 4096;
+    pub const FIELDS: usize = 25;
+    pub const FIELD_NAME: usize = 256;
+    pub const FIELD_VALUE: usize = 1024;
+    pub const FOOTER_TEXT: usize = 2048;
+    pub const AUTHOR_NAME: usize = 256;
+    /// The limit of all the text in an embed combined, this also applies to all embeds in one message.
+    pub const TOTAL: usize = 6000;
+}
+
+impl Embed {
+    /// Creates an [`EmbedBuilder`].
+    pub fn builder() -> EmbedBuilder {
+        EmbedBuilder::new()
+    }
+    /// Gives the amount of characters this embed counts towards [`limits::TOTAL`].
+    pub fn len(&self) -> usize {
+        let len = |s: &Option<String>| s.as_deref().map_or(0, |s| s.chars().count());
+        len(&self.title)
+            + len(&self.description)
+            + self.footer.as_ref().map_or(0, |f| f.text.chars().count())
+            + self.author.as_ref().map_or(0, |a| a.name.chars().count())
+            + self
+                .fields
+                .iter()
+                .map(|f| f.name.chars().count() + f.value.chars().count())
+                .sum::<usize>()
+    }
+    /// Whether this embed has no text in it at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Checks this embed is within discord's [`limits`].
+    pub fn validate(&self) -> Result<(), EmbedError> {
+        let check = |s: Option<&str>, limit, error: fn(usize) -> EmbedError| {
+            let len = s.map_or(0, |s| s.chars().count());
+            if len > limit {
+                Err(error(len))
+            } else {
+                Ok(())
+            }
+        };
+        check(
+            self.title.as_deref(),
+            limits::TITLE,
+            EmbedError::TitleTooLong,
+        )?;
+        check(
+            self.description.as_deref(),
+            limits::DESCRIPTION,
+            EmbedError::DescriptionTooLong,
+        )?;
+        check(
+            self.footer.as_ref().map(|f| f.text.as_str()),
+            limits::FOOTER_TEXT,
+            EmbedError::FooterTooLong,
+        )?;
+        check(
+            self.author.as_ref().map(|a| a.name.as_str()),
+            limits::AUTHOR_NAME,
+            EmbedError::AuthorNameTooLong,
+        )?;
+        if self.fields.len() > limits::FIELDS {
+            return Err(EmbedError::TooManyFields(self.fields.len()));
+        }
+        for field in &self.fields {
+            check(
+                Some(&field.name),
+                limits::FIELD_NAME,
+                EmbedError::FieldNameTooLong,
+            )?;
+            check(
+                Some(&field.value),
+                limits::FIELD_VALUE,
+                EmbedError::FieldValueTooLong,
+            )?;
+        }
+        match self.len() {
+            len if len > limits::TOTAL => Err(EmbedError::TooLong(len)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A builder for an [`Embed`], which checks discord's limits when it is built.
+///
+/// ```rust
+/// use discors::{models::channel::embed::EmbedBuilder, Color};
+///
+/// let embed = EmbedBuilder::new()
+///     .title("Server rules")
+///     .description("Be nice.")
+///     .color(Color::BLURPLE)
+///     .field("Spam", "Don't.", true)
+///     .footer("Last updated")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(embed.fields.len(), 1);
+/// assert!(EmbedBuilder::new().title("a".repeat(257)).build().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EmbedBuilder {
+    embed: Embed,
+}
+
+impl EmbedBuilder {
+    /// Creates a builder for an empty embed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the title of the embed, at most 256 characters.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.embed.title = Some(title.into());
+        self
+    }
+    /// Sets the description of the embed, at most 4096 characters.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.embed.description = Some(description.into());
+        self
+    }
+    /// Sets the url the title of the embed links to.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.embed.url = Some(url.into());
+        self
+    }
+    /// Sets the timestamp shown in the footer of the embed.
+    pub fn timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.embed.timestamp = Some(timestamp);
+        self
+    }
+    /// Sets the color of the side of the embed.
+    pub fn color(mut self, color: Color) -> Self {
+        self.embed.color = Some(color);
+        self
+    }
+    /// An alias to [`EmbedBuilder::color`].
+    pub fn colour(self, colour: Color) -> Self {
+        self.color(colour)
+    }
+    /// Sets the footer text, at most 2048 characters.
+    pub fn footer(mut self, text: impl Into<String>) -> Self {
+        self.embed.footer = Some(EmbedFooter {
+            text: text.into(),
+            icon_url: None,
+            proxy_icon_url: None,
+        });
+        self
+    }
+    /// Sets the footer text and the icon next to it.
+    pub fn footer_with_icon(
+        mut self,
+        text: impl Into<String>,
+        icon_url: impl Into<String>,
+    ) -> Self {
+        self = self.footer(text);
+        if let Some(footer) = &mut self.embed.footer {
+            footer.icon_url = Some(icon_url.into());
+        }
+        self
+    }
+    /// Sets the large image of the embed.
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.embed.image = Some(EmbedMedia::new(url.into()));
+        self
+    }
+    /// Sets the small image in the top right of the embed.
+    pub fn thumbnail(mut self, url: impl Into<String>) -> Self {
+        self.embed.thumbnail = Some(EmbedMedia::new(url.into()));
+        self
+    }
+    /// Sets the author of the embed, at most 256 characters.
+    pub fn author(mut self, name: impl Into<String>) -> Self {
+        self.embed.author = Some(EmbedAuthor {
+            name: name.into(),
+            url: None,
+            icon_url: None,
+            proxy_icon_url: None,
+        });
+        self
+    }
+    /// Sets the author of the embed, with the url their name links to and their icon.
+    pub fn author_with(
+        mut self,
+        name: impl Into<String>,
+        url: Option<String>,
+        icon_url: Option<String>,
+    ) -> Self {
+        self = self.author(name);
+        if let Some(author) = &mut self.embed.author {
+            author.url = url;
+            author.icon_url = icon_url;
+        }
+        self
+    }
+    /// Adds a field, the name can be at most 256 and the value 1024 characters. There can be at most 25 fields.
+    pub fn field(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: bool,
+    ) -> Self {
+        self.embed.fields.push(EmbedField {
+            name: name.into(),
+            value: value.into(),
+            inline,
+        });
+        self
+    }
+    /// Builds the embed, checking it is within discord's [`limits`].
+    pub fn build(self) -> Result<Embed, EmbedError> {
+        self.embed.validate()?;
+        Ok(self.embed)
+    }
+}
+
+/// The error given when an embed is outside of discord's [`limits`]. Each variant holds the length that was given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EmbedError {
+    TitleTooLong(usize),
+    DescriptionTooLong(usize),
+    TooManyFields(usize),
+    FieldNameTooLong(usize),
+    FieldValueTooLong(usize),
+    FooterTooLong(usize),
+    AuthorNameTooLong(usize),
+    /// All the text of the embed (or embeds) is longer than 6000 characters.
+    TooLong(usize),
+}
+
+impl Display for EmbedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (what, len, limit) = match self {
+            EmbedError::TitleTooLong(len) => ("title", len, limits::TITLE),
+            EmbedError::DescriptionTooLong(len) => ("description", len, limits::DESCRIPTION),
+            EmbedError::TooManyFields(len) => {
+                return write!(
+                    f,
+                    "embed has {} fields, the limit is {}",
+                    len,
+                    limits::FIELDS
+                )
+            }
+            EmbedError::FieldNameTooLong(len) => ("field name", len, limits::FIELD_NAME),
+            EmbedError::FieldValueTooLong(len) => ("field value", len, limits::FIELD_VALUE),
+            EmbedError::FooterTooLong(len) => ("footer text", len, limits::FOOTER_TEXT),
+            EmbedError::AuthorNameTooLong(len) => ("author name", len, limits::AUTHOR_NAME),
+            EmbedError::TooLong(len) => ("total text", len, limits::TOTAL),
+        };
+        write!(
+            f,
+            "embed {} is {} characters long, the limit is {}",
+            what, len, limit
+        )
+    }
+}
+
+impl StdError for EmbedError {}
+
+#[cfg(test)]
+mod test {
+    use super::{limits, Embed, EmbedBuilder, EmbedError};
+    use crate::{color::Color, timestamp::Timestamp};
+
+    #[test]
+    fn limits() {
+        assert!(EmbedBuilder::new().title("a".repeat(256)).build().is_ok());
+        assert_eq!(
+            EmbedBuilder::new().title("a".repeat(257)).build(),
+            Err(EmbedError::TitleTooLong(257))
+        );
+        // characters, not bytes
+        assert!(EmbedBuilder::new().title("é".repeat(256)).build().is_ok());
+        assert_eq!(
+            EmbedBuilder::new().description("a".repeat(4097)).build(),
+            Err(EmbedError::DescriptionTooLong(4097))
+        );
+        assert_eq!(
+            EmbedBuilder::new()
+                .field("a", "b".repeat(1025), false)
+                .build(),
+            Err(EmbedError::FieldValueTooLong(1025))
+        );
+        let mut builder = EmbedBuilder::new();
+        for i in 0..=limits::FIELDS {
+            builder = builder.field(i.to_string(), "value", true);
+        }
+        assert_eq!(builder.build(), Err(EmbedError::TooManyFields(26)));
+    }
+
+    #[test]
+    fn total() {
+        let mut builder = EmbedBuilder::new().description("a".repeat(4096));
+        for _ in 0..2 {
+            builder = builder.field("b".repeat(256), "c".repeat(1024), false);
+        }
+        assert_eq!(builder.build(), Err(EmbedError::TooLong(4096 + 2 * 1280)));
+    }
+
+    #[test]
+    fn serialize() {
+        let embed = EmbedBuilder::new()
+            .title("hi")
+            .colour(Color::RED)
+            .timestamp(Timestamp::new(1420070400000))
+            .thumbnail("https://example.com/a.png")
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&embed).unwrap(),
+            r#"{"title":"hi","timestamp":"2015-01-01T00:00:00.000+00:00","color":15158332,"thumbnail":{"url":"https://example.com/a.png"}}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let embed = serde_json::from_str::<Embed>(
+            r#"{
+                "type": "rich",
+                "title": "hello",
+                "color": 3447003,
+                "timestamp": "2015-01-01T00:00:00+00:00",
+                "footer": {"text": "foot", "proxy_icon_url": "https://media.discordapp.net/a.png"},
+                "video": {"height": 720, "width": 1280},
+                "fields": [{"name": "a", "value": "b"}]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(embed.title.as_deref(), Some("hello"));
+        assert_eq!(embed.color, Some(Color::BLUE));
+        assert_eq!(embed.timestamp, Some(Timestamp::new(1420070400000)));
+        assert_eq!(embed.video.as_ref().and_then(|v| v.width), Some(1280));
+        assert!(!embed.fields[0].inline);
+        assert_eq!(embed.len(), 5 + 4 + 2);
+    }
+}
@@ -0,0 +1,349 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    int_enum,
+    models::{
+        channel::{
+            message::{CreateMessage, CreateMessageError, Message},
+            thread::{AutoArchiveDuration, StartThread, ThreadError},
+            Channel, ChannelFlags, ChannelType,
+        },
+        emoji::{CustomEmoji, Emoji},
+        traits::BitFlag,
+    },
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// A tag which can be applied to the posts of a forum channel.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#forum-tag-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForumTag {
+    pub id: Snowflake<ForumTag>,
+    pub name: String,
+    // only members with `MANAGE_THREADS` can apply a moderated tag
+    #[serde(default)]
+    pub moderated: bool,
+    // the custom emoji of the tag, or a unicode emoji in `emoji_name`
+    #[serde(default)]
+    pub emoji_id: Option<Snowflake<CustomEmoji>>,
+    #[serde(default)]
+    pub emoji_name: Option<String>,
+}
+
+impl ForumTag {
+    /// The emoji shown with the tag.
+    pub fn emoji(&self) -> Option<Emoji> {
+        emoji(self.emoji_id, self.emoji_name.as_deref())
+    }
+}
+
+impl Snowflakable for ForumTag {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+/// The emoji shown on the button for reacting to posts in a forum channel.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#default-reaction-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DefaultReaction {
+    // a custom emoji, or a unicode emoji in `emoji_name`
+    #[serde(default)]
+    pub emoji_id: Option<Snowflake<CustomEmoji>>,
+    #[serde(default)]
+    pub emoji_name: Option<String>,
+}
+
+impl DefaultReaction {
+    /// The emoji of the reaction.
+    pub fn emoji(&self) -> Option<Emoji> {
+        emoji(self.emoji_id, self.emoji_name.as_deref())
+    }
+}
+
+fn emoji(id: Option<Snowflake<CustomEmoji>>, name: Option<&str>) -> Option<Emoji> {
+    match (id, name) {
+        (Some(id), name) => Some(Emoji::Custom(CustomEmoji::new(
+            id,
+            name.unwrap_or_default(),
+        ))),
+        (None, Some(name)) => Some(Emoji::Unicode(name.to_string())),
+        (None, None) => None,
+    }
+}
+
+int_enum! {
+    /// How the posts of a forum channel are sorted by default.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/channel#channel-object-sort-order-types)
+    SortOrderType: u8 {
+        /// By the last activity.
+        LatestActivity = 0,
+        /// By when they were created.
+        CreationDate = 1,
+    }
+}
+
+int_enum! {
+    /// How the posts of a forum channel are shown by default.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/channel#channel-object-forum-layout-types)
+    ForumLayoutType: u8 {
+        /// No layout has been set by the moderators.
+        NotSet = 0,
+        ListView = 1,
+        GalleryView = 2,
+    }
+}
+
+/// The limits on forum posts.
+pub mod forum_limits {
+    /// How many tags a post can have.
+    pub const APPLIED_TAGS: usize = 5;
+}
+
+/// A builder for creating a post in a forum channel, which is a thread started together with its first message.
+///
+/// The message is built the same way as any other message, with a [`CreateMessage`].
+/// Forum channels can not be sent to with [`Messagable`](crate::models::traits::Messagable), as a post needs a title and creates a thread
+/// alongside its message, so posts are created with [`Channel::create_post`] instead.
+///
+/// ```rust
+/// use discors::models::channel::{forum::CreateForumPost, message::CreateMessage};
+///
+/// let post = CreateForumPost::new("it does not compile", CreateMessage::new().content("help"))
+///     .tag(1.into());
+/// assert!(post.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#start-thread-in-forum-or-media-channel)
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateForumPost {
+    #[serde(flatten)]
+    pub(crate) thread: StartThread,
+    pub(crate) message: CreateMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    applied_tags: Vec<Snowflake<ForumTag>>,
+}
+
+impl CreateForumPost {
+    /// Creates the post from its title and first message.
+    pub fn new(name: impl Into<String>, message: CreateMessage) -> Self {
+        Self {
+            thread: StartThread::new(name),
+            message,
+            applied_tags: Vec::new(),
+        }
+    }
+    /// Applies a tag of the forum channel to the post.
+    pub fn tag(mut self, tag: Snowflake<ForumTag>) -> Self {
+        self.applied_tags.push(tag);
+        self
+    }
+    /// Sets how long until the post is archived after its last activity.
+    pub fn auto_archive_duration(mut self, duration: AutoArchiveDuration) -> Self {
+        self.thread = self.thread.auto_archive_duration(duration);
+        self
+    }
+    /// Sets the slowmode of the post in seconds, at most 6 hours.
+    pub fn rate_limit_per_user(mut self, seconds: u16) -> Self {
+        self.thread = self.thread.rate_limit_per_user(seconds);
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.thread = self.thread.reason(reason);
+        self
+    }
+    /// Checks the post and its message are within discord's limits.
+    pub fn validate(&self) -> Result<(), ForumError> {
+        self.thread.validate()?;
+        self.message.validate()?;
+        if self.applied_tags.len() > forum_limits::APPLIED_TAGS {
+            return Err(ForumError::TooManyTags(self.applied_tags.len()));
+        }
+        Ok(())
+    }
+    /// Checks the post can be created in a channel, with the tags it has, see [`Channel::create_post`].
+    pub fn validate_for(&self, channel: &Channel) -> Result<(), ForumError> {
+        self.validate()?;
+        if !channel.kind.is_forum() {
+            return Err(ForumError::NotAForum(channel.kind));
+        }
+        if self.applied_tags.is_empty() && channel.flags.includes(ChannelFlags::REQUIRE_TAG) {
+            return Err(ForumError::TagRequired);
+        }
+        match self
+            .applied_tags
+            .iter()
+            .find(|tag| !channel.available_tags.iter().any(|t| t.id == **tag))
+        {
+            Some(tag) => Err(ForumError::UnknownTag(*tag)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A post created in a forum channel, the thread with the message it was started with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForumPost {
+    #[serde(flatten)]
+    pub thread: Channel,
+    pub message: Message,
+}
+
+/// The error given when a forum post can not be created or tagged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ForumError {
+    /// More than 5 tags were applied, with the amount that was given.
+    TooManyTags(usize),
+    /// The forum requires posts to have a tag.
+    TagRequired,
+    /// The tag is not one of the forum's tags.
+    UnknownTag(Snowflake<ForumTag>),
+    /// The channel is not a forum channel, with the type it is.
+    NotAForum(ChannelType),
+    /// The channel is not a forum post.
+    NotAPost,
+    /// The thread of the post is outside of discord's limits.
+    Thread(ThreadError),
+    /// The message of the post is outside of discord's limits.
+    Message(CreateMessageError),
+}
+
+impl From<ThreadError> for ForumError {
+    fn from(err: ThreadError) -> Self {
+        ForumError::Thread(err)
+    }
+}
+
+impl From<CreateMessageError> for ForumError {
+    fn from(err: CreateMessageError) -> Self {
+        ForumError::Message(err)
+    }
+}
+
+impl Display for ForumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForumError::TooManyTags(amount) => write!(
+                f,
+                "{} tags were applied, the limit is {}",
+                amount,
+                forum_limits::APPLIED_TAGS
+            ),
+            ForumError::TagRequired => f.write_str("the forum requires posts to have a tag"),
+            ForumError::UnknownTag(tag) => write!(f, "{} is not a tag of the forum", tag),
+            ForumError::NotAForum(kind) => write!(f, "{:?} is not a forum channel", kind),
+            ForumError::NotAPost => f.write_str("the channel is not a forum post"),
+            ForumError::Thread(err) => err.fmt(f),
+            ForumError::Message(err) => err.fmt(f),
+        }
+    }
+}
+
+impl StdError for ForumError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            ForumError::Thread(err) => Some(err),
+            ForumError::Message(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateForumPost, ForumError, ForumLayoutType, ForumPost, SortOrderType};
+    use crate::models::{
+        channel::{
+            message::{CreateMessage, CreateMessageError},
+            thread::ThreadError,
+            Channel, ChannelFlags,
+        },
+        emoji::Emoji,
+        traits::BitFlag,
+    };
+
+    const FORUM: &str = r#"{
+        "id": "1",
+        "type": 15,
+        "guild_id": "2",
+        "name": "support",
+        "flags": 16,
+        "available_tags": [
+            {"id": "10", "name": "solved", "moderated": true, "emoji_id": null, "emoji_name": "✅"},
+            {"id": "11", "name": "bug", "moderated": false, "emoji_id": "12", "emoji_name": null}
+        ],
+        "default_reaction_emoji": {"emoji_id": null, "emoji_name": "👍"},
+        "default_sort_order": 1,
+        "default_forum_layout": 2
+    }"#;
+
+    #[test]
+    fn deserialize() {
+        let forum = serde_json::from_str::<Channel>(FORUM).unwrap();
+        assert!(forum.kind.is_forum());
+        assert!(forum.flags.includes(ChannelFlags::REQUIRE_TAG));
+        assert_eq!(forum.available_tags[0].emoji(), Some(Emoji::from("✅")));
+        assert_eq!(
+            forum.available_tags[1].emoji().unwrap().id(),
+            Some(12.into())
+        );
+        assert_eq!(
+            forum.default_reaction_emoji.unwrap().emoji(),
+            Some(Emoji::from("👍"))
+        );
+        assert_eq!(forum.default_sort_order, Some(SortOrderType::CreationDate));
+        assert_eq!(
+            forum.default_forum_layout,
+            Some(ForumLayoutType::GalleryView)
+        );
+        let post = serde_json::from_str::<ForumPost>(
+            r#"{
+                "id": "3", "type": 11, "parent_id": "1", "applied_tags": ["11"],
+                "message": {
+                    "id": "3", "channel_id": "3", "content": "help", "timestamp": "2023-01-01T00:00:00.000000+00:00",
+                    "author": {"id": "4", "username": "a", "discriminator": "0", "avatar": null},
+                    "tts": false, "mention_everyone": false, "mentions": [], "mention_roles": [],
+                    "attachments": [], "embeds": [], "pinned": false, "type": 0
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(post.thread.applied_tags, [11.into()]);
+        assert_eq!(post.message.content, "help");
+    }
+
+    #[test]
+    fn validate() {
+        let forum = serde_json::from_str::<Channel>(FORUM).unwrap();
+        let post = || CreateForumPost::new("help", CreateMessage::new().content("a"));
+        assert_eq!(post().validate_for(&forum), Err(ForumError::TagRequired));
+        assert_eq!(
+            post().tag(13.into()).validate_for(&forum),
+            Err(ForumError::UnknownTag(13.into()))
+        );
+        assert_eq!(
+            CreateForumPost::new("", CreateMessage::new().content("a")).validate(),
+            Err(ForumError::Thread(ThreadError::Name(0)))
+        );
+        assert_eq!(
+            CreateForumPost::new("help", CreateMessage::new()).validate(),
+            Err(ForumError::Message(CreateMessageError::Empty))
+        );
+        assert!(post().tag(11.into()).validate_for(&forum).is_ok());
+        assert_eq!(
+            serde_json::to_string(&post().tag(11.into())).unwrap(),
+            r#"{"name":"help","message":{"content":"a"},"applied_tags":["11"]}"#
+        );
+    }
+}
@@ -0,0 +1,886 @@
+use std::{
+    collections::HashSet,
+    error::Error as StdError,
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bitflags,
+    error::Error,
+    flags, int_enum,
+    models::{
+        channel::{
+            attachment::{Attachment, AttachmentFile},
+            component::{deserialize_rows, validate_rows, ActionRow, ComponentError},
+            embed::{limits, Embed, EmbedError},
+            reaction::Reaction,
+            Channel,
+        },
+        guild::{member::Member, role::Role, Guild},
+        sticker::Sticker,
+        user::User,
+    },
+    snowflake::{Snowflakable, Snowflake},
+    timestamp::Timestamp,
+};
+
+/// A message sent in a channel.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#message-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    pub id: Snowflake<Message>,
+    pub channel_id: Snowflake<Channel>,
+    // only given in message events sent in guilds
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    pub author: User,
+    // the author's member, without a user, only given in message events sent in guilds
+    #[serde(default)]
+    pub member: Option<Member>,
+    // this is empty without the MESSAGE_CONTENT intent, unless the bot is mentioned or it is a direct message.
+    pub content: String,
+    pub timestamp: Timestamp,
+    pub edited_timestamp: Option<Timestamp>,
+    pub tts: bool,
+    pub mention_everyone: bool,
+    pub mentions: Vec<User>,
+    pub mention_roles: Vec<Snowflake<Role>>,
+    pub attachments: Vec<Attachment>,
+    pub embeds: Vec<Embed>,
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+    pub pinned: bool,
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    #[serde(default)]
+    pub message_reference: Option<MessageReference>,
+    #[serde(default)]
+    pub flags: MessageFlags,
+    // the message this is a reply to, this is null if it was deleted.
+    #[serde(default)]
+    pub referenced_message: Option<Box<Message>>,
+    // rows with components this library can't read are left out
+    #[serde(default, deserialize_with = "deserialize_rows")]
+    pub components: Vec<ActionRow>,
+}
+
+int_enum! {
+    /// The type of a [`Message`], most messages are [`MessageType::Default`] or [`MessageType::Reply`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/channel#message-object-message-types)
+    MessageType: u8 {
+        Default = 0,
+        RecipientAdd = 1,
+        RecipientRemove = 2,
+        Call = 3,
+        ChannelNameChange = 4,
+        ChannelIconChange = 5,
+        ChannelPinnedMessage = 6,
+        UserJoin = 7,
+        GuildBoost = 8,
+        GuildBoostTier1 = 9,
+        GuildBoostTier2 = 10,
+        GuildBoostTier3 = 11,
+        ChannelFollowAdd = 12,
+        GuildDiscoveryDisqualified = 14,
+        GuildDiscoveryRequalified = 15,
+        GuildDiscoveryGracePeriodInitialWarning = 16,
+        GuildDiscoveryGracePeriodFinalWarning = 17,
+        ThreadCreated = 18,
+        Reply = 19,
+        ChatInputCommand = 20,
+        ThreadStarterMessage = 21,
+        GuildInviteReminder = 22,
+        ContextMenuCommand = 23,
+        AutoModerationAction = 24,
+        RoleSubscriptionPurchase = 25,
+    }
+}
+
+bitflags!("The flags of a [`Message`].", "", "[Read more](https://discord.com/developers/docs/resources/channel#message-object-message-flags)"; MessageFlags: u32: "crate::utilities::serde::deserialize_u32", "crate::utilities::serde::serialize_u32");
+
+flags! {
+    MessageFlags:
+    CROSSPOSTED = 0; "This message has been published to following channels.",
+    IS_CROSSPOST = 1; "This message came from a message in another channel that it follows.",
+    SUPPRESS_EMBEDS = 2; "Do not include any embeds when serializing this message.",
+    SOURCE_MESSAGE_DELETED = 3; "The message this crosspost came from has been deleted.",
+    URGENT = 4; "This message came from the urgent message system.",
+    HAS_THREAD = 5; "This message has a thread associated with it, with the same id as the message.",
+    EPHEMERAL = 6; "This message is only visible to the user who invoked the interaction.",
+    LOADING = 7; "This message is an interaction response, and the bot is \"thinking\".",
+    FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 8,
+    SUPPRESS_NOTIFICATIONS SILENT = 12; "This message will not trigger push and desktop notifications.",
+    IS_VOICE_MESSAGE = 13,
+}
+
+/// A reference to another message, like the message a reply is replying to.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#message-reference-object-message-reference-structure)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageReference {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Snowflake<Message>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake<Channel>>,
+    // when sending, whether to error if the message does not exist or to send a normal message instead.
+    #[serde(default = "default_true")]
+    pub fail_if_not_exists: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl From<&Message> for MessageReference {
+    fn from(message: &Message) -> Self {
+        Self {
+            message_id: Some(message.id),
+            channel_id: Some(message.channel_id),
+            fail_if_not_exists: true,
+        }
+    }
+}
+
+/// Which mentions in a message are allowed to ping.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#allowed-mentions-object)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AllowedMentions {
+    // the types of mentions to parse from the content
+    pub parse: Vec<ParseMentions>,
+    // specific roles and users that may be pinged, these can not be used along with parsing their type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Snowflake<Role>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<Snowflake<User>>,
+    // whether to ping the author of the message being replied to
+    #[serde(default)]
+    pub replied_user: bool,
+}
+
+/// A type of mention which [`AllowedMentions`] can allow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParseMentions {
+    Roles,
+    Users,
+    Everyone,
+}
+
+impl AllowedMentions {
+    /// Allows no mentions at all to ping.
+    pub fn none() -> Self {
+        Self::default()
+    }
+    /// Allows every mention to ping, including `@everyone` and replies.
+    pub fn all() -> Self {
+        Self {
+            parse: vec![
+                ParseMentions::Roles,
+                ParseMentions::Users,
+                ParseMentions::Everyone,
+            ],
+            replied_user: true,
+            ..Self::default()
+        }
+    }
+    /// Allows user mentions to ping.
+    pub fn users(mut self) -> Self {
+        self.parse.push(ParseMentions::Users);
+        self
+    }
+    /// Allows role mentions to ping.
+    pub fn roles(mut self) -> Self {
+        self.parse.push(ParseMentions::Roles);
+        self
+    }
+    /// Allows `@everyone` and `@here` to ping.
+    pub fn everyone(mut self) -> Self {
+        self.parse.push(ParseMentions::Everyone);
+        self
+    }
+    /// Allows a specific user to be pinged.
+    pub fn user(mut self, user: Snowflake<User>) -> Self {
+        self.users.push(user);
+        self
+    }
+    /// Allows a specific role to be pinged.
+    pub fn role(mut self, role: Snowflake<Role>) -> Self {
+        self.roles.push(role);
+        self
+    }
+    /// Sets whether the author of the message being replied to is pinged.
+    pub fn replied_user(mut self, ping: bool) -> Self {
+        self.replied_user = ping;
+        self
+    }
+}
+
+/// The limits discord puts on sending messages.
+pub mod message_limits {
+    pub const CONTENT: usize = 2000;
+    pub const EMBEDS: usize = 10;
+    pub const STICKERS: usize = 3;
+    pub const FILES: usize = 10;
+}
+
+/// A message to send, used with [`Messagable::send`](crate::models::traits::messagable::Messagable::send).
+///
+/// ```rust
+/// use discors::models::channel::{embed::EmbedBuilder, message::{AllowedMentions, CreateMessage}};
+///
+/// let message = CreateMessage::new()
+///     .content("Hello <@445556389532925952>!")
+///     .embed(EmbedBuilder::new().title("Welcome").build().unwrap())
+///     .allowed_mentions(AllowedMentions::none());
+///
+/// assert!(message.validate().is_ok());
+/// assert!(CreateMessage::new().validate().is_err());
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    embeds: Vec<Embed>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    tts: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_reference: Option<MessageReference>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    components: Vec<ActionRow>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sticker_ids: Vec<Snowflake<Sticker>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<MessageFlags>,
+    #[serde(skip)]
+    pub(crate) files: Vec<AttachmentFile>,
+}
+
+impl CreateMessage {
+    /// Creates an empty message, at least one of content, an embed, a sticker or a file has to be added to send it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the text of the message, at most 2000 characters.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+    /// Adds an embed, there can be at most 10.
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+    /// Sets all the embeds of the message.
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = embeds;
+        self
+    }
+    /// Sets whether the message is read out with text to speech.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = tts;
+        self
+    }
+    /// Sets which mentions in the message ping.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+    /// Makes this message a reply to another message.
+    pub fn reference(mut self, reference: impl Into<MessageReference>) -> Self {
+        self.message_reference = Some(reference.into());
+        self
+    }
+    /// Adds a row of components, there can be at most 5.
+    pub fn component(mut self, row: ActionRow) -> Self {
+        self.components.push(row);
+        self
+    }
+    /// Adds a sticker, there can be at most 3.
+    pub fn sticker(mut self, sticker: Snowflake<Sticker>) -> Self {
+        self.sticker_ids.push(sticker);
+        self
+    }
+    /// Attaches a file, there can be at most 10.
+    pub fn file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+        self
+    }
+    /// Sets the flags of the message, only [`MessageFlags::SUPPRESS_EMBEDS`] and [`MessageFlags::SUPPRESS_NOTIFICATIONS`] can be set.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+    /// Makes an interaction response only visible to the user who used the interaction.
+    pub fn ephemeral(mut self) -> Self {
+        self.flags = Some(self.flags.unwrap_or_default() | MessageFlags::EPHEMERAL);
+        self
+    }
+    /// Checks the message is within discord's limits, and is not empty.
+    pub fn validate(&self) -> Result<(), CreateMessageError> {
+        let content = self.content.as_deref().map_or(0, |c| c.chars().count());
+        if content > message_limits::CONTENT {
+            return Err(CreateMessageError::ContentTooLong(content));
+        }
+        if self.embeds.len() > message_limits::EMBEDS {
+            return Err(CreateMessageError::TooManyEmbeds(self.embeds.len()));
+        }
+        for embed in &self.embeds {
+            embed.validate()?;
+        }
+        // the total applies to all embeds together
+        let total = self.embeds.iter().map(Embed::len).sum();
+        if total > limits::TOTAL {
+            return Err(EmbedError::TooLong(total).into());
+        }
+        if self.sticker_ids.len() > message_limits::STICKERS {
+            return Err(CreateMessageError::TooManyStickers(self.sticker_ids.len()));
+        }
+        if self.files.len() > message_limits::FILES {
+            return Err(CreateMessageError::TooManyFiles(self.files.len()));
+        }
+        validate_rows(&self.components)?;
+        if content == 0
+            && self.embeds.is_empty()
+            && self.sticker_ids.is_empty()
+            && self.files.is_empty()
+            && self.components.is_empty()
+        {
+            return Err(CreateMessageError::Empty);
+        }
+        Ok(())
+    }
+}
+
+/// A builder for editing a message. Only the fields that are set are changed.
+///
+/// ```rust
+/// use discors::models::channel::message::EditMessage;
+///
+/// let edit = EditMessage::new().content("edited").keep_attachments(Vec::new());
+/// assert!(edit.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#edit-message)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<MessageFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<ActionRow>>,
+    // the existing attachments which are kept
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_kept_attachments"
+    )]
+    attachments: Option<Vec<Snowflake<Attachment>>>,
+    #[serde(skip)]
+    pub(crate) files: Vec<AttachmentFile>,
+}
+
+impl EditMessage {
+    /// Creates an edit which doesn't change anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the text of the message, at most 2000 characters. An empty string removes the content.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+    /// Replaces the embeds of the message, there can be at most 10.
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Some(embeds);
+        self
+    }
+    /// Sets the flags of the message, only [`MessageFlags::SUPPRESS_EMBEDS`] can be changed.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+    /// Sets which mentions in the new content ping.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+    /// Replaces the rows of components of the message, an empty list removes them.
+    pub fn components(mut self, components: Vec<ActionRow>) -> Self {
+        self.components = Some(components);
+        self
+    }
+    /// Sets which of the existing attachments are kept, the others are removed.
+    ///
+    /// When files are added without this, only the new files are kept.
+    pub fn keep_attachments(mut self, attachments: Vec<Snowflake<Attachment>>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+    /// Attaches a new file, there can be at most 10.
+    pub fn file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+        self
+    }
+    /// Checks the edit is within discord's limits.
+    pub fn validate(&self) -> Result<(), CreateMessageError> {
+        let content = self.content.as_deref().map_or(0, |c| c.chars().count());
+        if content > message_limits::CONTENT {
+            return Err(CreateMessageError::ContentTooLong(content));
+        }
+        let embeds = self.embeds.as_deref().unwrap_or_default();
+        if embeds.len() > message_limits::EMBEDS {
+            return Err(CreateMessageError::TooManyEmbeds(embeds.len()));
+        }
+        for embed in embeds {
+            embed.validate()?;
+        }
+        let total = embeds.iter().map(Embed::len).sum();
+        if total > limits::TOTAL {
+            return Err(EmbedError::TooLong(total).into());
+        }
+        let files = self.files.len() + self.attachments.as_ref().map_or(0, Vec::len);
+        if files > message_limits::FILES {
+            return Err(CreateMessageError::TooManyFiles(files));
+        }
+        validate_rows(self.components.as_deref().unwrap_or_default())?;
+        Ok(())
+    }
+}
+
+fn serialize_kept_attachments<S>(
+    attachments: &Option<Vec<Snowflake<Attachment>>>,
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    #[derive(Serialize)]
+    struct Kept {
+        id: Snowflake<Attachment>,
+    }
+    s.collect_seq(attachments.iter().flatten().map(|&id| Kept { id }))
+}
+
+/// The limits on deleting messages in bulk.
+pub mod bulk_delete_limits {
+    use std::{ops::RangeInclusive, time::Duration};
+
+    /// How many messages one bulk delete request can delete.
+    pub const MESSAGES: RangeInclusive<usize> = 2..=100;
+    /// How old messages can be to be deleted in bulk, 14 days.
+    pub const MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+}
+
+/// What was done by [`Http::bulk_delete`](crate::http::Http::bulk_delete).
+#[derive(Debug, Default)]
+pub struct BulkDeleteReport {
+    /// The messages deleted in bulk.
+    pub bulk_deleted: Vec<Snowflake<Message>>,
+    /// The messages deleted one by one, as they were too old or left over after chunking.
+    pub single_deleted: Vec<Snowflake<Message>>,
+    /// The messages that were not deleted, because they did not exist (anymore) or were given twice.
+    pub skipped: Vec<Snowflake<Message>>,
+    /// The messages that were not deleted because a request failed, including the ones which were not tried after it.
+    pub failed: Vec<Snowflake<Message>>,
+    /// The error of the request that failed, deleting stops at the first error.
+    pub error: Option<Error>,
+}
+
+impl BulkDeleteReport {
+    /// How many messages were deleted.
+    pub fn deleted(&self) -> usize {
+        self.bulk_deleted.len() + self.single_deleted.len()
+    }
+    /// Whether every message was deleted or skipped.
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// How [`Http::bulk_delete`](crate::http::Http::bulk_delete) deletes a list of messages.
+pub(crate) struct BulkDeletePlan {
+    // the chunks of messages which can be deleted in bulk
+    pub(crate) chunks: Vec<Vec<Snowflake<Message>>>,
+    // the messages which have to be deleted one by one
+    pub(crate) single: Vec<Snowflake<Message>>,
+    // the messages given more than once
+    pub(crate) duplicates: Vec<Snowflake<Message>>,
+}
+
+impl BulkDeletePlan {
+    pub(crate) fn new(ids: &[Snowflake<Message>], now: Timestamp) -> Self {
+        // a minute of leeway, so messages do not age past the limit while the requests are made
+        let margin = Duration::from_secs(60);
+        let oldest = Snowflake::from_time(Timestamp::new(
+            now.to_unix_millis()
+                .saturating_sub((bulk_delete_limits::MAX_AGE - margin).as_millis() as u64),
+        ));
+        let mut seen = HashSet::new();
+        let mut plan = Self {
+            chunks: Vec::new(),
+            single: Vec::new(),
+            duplicates: Vec::new(),
+        };
+        let mut recent = Vec::new();
+        for &id in ids {
+            if !seen.insert(id) {
+                plan.duplicates.push(id);
+            } else if id > oldest {
+                recent.push(id);
+            } else {
+                plan.single.push(id);
+            }
+        }
+        for chunk in recent.chunks(*bulk_delete_limits::MESSAGES.end()) {
+            if chunk.len() < *bulk_delete_limits::MESSAGES.start() {
+                plan.single.extend_from_slice(chunk);
+            } else {
+                plan.chunks.push(chunk.to_vec());
+            }
+        }
+        plan
+    }
+}
+
+/// Where a page of message history starts.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#get-channel-messages)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageCursor {
+    /// The messages before (older than) a message, newest first.
+    Before(Snowflake<Message>),
+    /// The messages after (newer than) a message, oldest first.
+    After(Snowflake<Message>),
+    /// The messages around a message, including it.
+    Around(Snowflake<Message>),
+}
+
+/// Which messages to go through when reading the history of a channel.
+///
+/// By default every message is read, from the newest to the oldest.
+///
+/// ```rust
+/// use discors::{models::channel::message::MessageHistory, timestamp::Timestamp};
+///
+/// // the messages of 2022, oldest first
+/// let history = MessageHistory::after(Timestamp::new(1640995200000))
+///     .until(Timestamp::new(1672531200000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MessageHistory {
+    pub(crate) cursor: Option<MessageCursor>,
+    pub(crate) until: Option<Timestamp>,
+    pub(crate) limit: Option<usize>,
+}
+
+impl MessageHistory {
+    /// Reads from the newest message to the oldest.
+    pub fn latest() -> Self {
+        Self::default()
+    }
+    /// Reads the messages older than a message or a time, newest first.
+    pub fn before(before: impl Into<HistoryPoint>) -> Self {
+        Self {
+            cursor: Some(MessageCursor::Before(before.into().0)),
+            ..Self::default()
+        }
+    }
+    /// Reads the messages newer than a message or a time, oldest first.
+    pub fn after(after: impl Into<HistoryPoint>) -> Self {
+        Self {
+            cursor: Some(MessageCursor::After(after.into().0)),
+            ..Self::default()
+        }
+    }
+    /// Reads the (at most 100) messages around a message, oldest first.
+    pub fn around(around: Snowflake<Message>) -> Self {
+        Self {
+            cursor: Some(MessageCursor::Around(around)),
+            ..Self::default()
+        }
+    }
+    /// Stops at a time, messages sent before it when reading backwards or after it when reading forwards are not read.
+    pub fn until(mut self, until: Timestamp) -> Self {
+        self.until = Some(until);
+        self
+    }
+    /// Stops after an amount of messages.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    /// Whether the history is read from older to newer messages.
+    pub(crate) fn forwards(&self) -> bool {
+        !matches!(self.cursor, None | Some(MessageCursor::Before(_)))
+    }
+    /// Checks whether a message is past the time the history stops at.
+    pub(crate) fn is_past_bound(&self, message: &Message) -> bool {
+        match self.until {
+            Some(until) if self.forwards() => message.id.to_time() > until,
+            Some(until) => message.id.to_time() < until,
+            None => false,
+        }
+    }
+}
+
+/// A point in message history, a message or a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryPoint(Snowflake<Message>);
+
+impl From<Snowflake<Message>> for HistoryPoint {
+    fn from(id: Snowflake<Message>) -> Self {
+        Self(id)
+    }
+}
+
+impl From<Timestamp> for HistoryPoint {
+    /// The earliest possible id of a message sent at a time.
+    fn from(time: Timestamp) -> Self {
+        Self(Snowflake::from_time(time))
+    }
+}
+
+/// The error given when a [`CreateMessage`] or an [`EditMessage`] is outside of discord's limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CreateMessageError {
+    ContentTooLong(usize),
+    TooManyEmbeds(usize),
+    Embed(EmbedError),
+    TooManyStickers(usize),
+    TooManyFiles(usize),
+    Component(ComponentError),
+    /// The message has no content, embeds, stickers, files or components.
+    Empty,
+}
+
+impl From<EmbedError> for CreateMessageError {
+    fn from(err: EmbedError) -> Self {
+        CreateMessageError::Embed(err)
+    }
+}
+
+impl From<ComponentError> for CreateMessageError {
+    fn from(err: ComponentError) -> Self {
+        CreateMessageError::Component(err)
+    }
+}
+
+impl Display for CreateMessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CreateMessageError::ContentTooLong(len) => write!(
+                f,
+                "message content is {} characters long, the limit is {}",
+                len,
+                message_limits::CONTENT
+            ),
+            CreateMessageError::TooManyEmbeds(len) => write!(
+                f,
+                "message has {} embeds, the limit is {}",
+                len,
+                message_limits::EMBEDS
+            ),
+            CreateMessageError::Embed(err) => err.fmt(f),
+            CreateMessageError::TooManyStickers(len) => write!(
+                f,
+                "message has {} stickers, the limit is {}",
+                len,
+                message_limits::STICKERS
+            ),
+            CreateMessageError::TooManyFiles(len) => write!(
+                f,
+                "message has {} files, the limit is {}",
+                len,
+                message_limits::FILES
+            ),
+            CreateMessageError::Component(err) => err.fmt(f),
+            CreateMessageError::Empty => f.write_str(
+                "message needs content, an embed, a sticker, a file or a component to be sent",
+            ),
+        }
+    }
+}
+
+impl StdError for CreateMessageError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            CreateMessageError::Embed(err) => Some(err),
+            CreateMessageError::Component(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Snowflakable for Message {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        AllowedMentions, BulkDeletePlan, CreateMessage, CreateMessageError, EditMessage, Message,
+        MessageCursor, MessageFlags, MessageHistory, MessageType,
+    };
+    use crate::{
+        models::{
+            channel::{attachment::AttachmentFile, embed::EmbedBuilder},
+            traits::BitFlag,
+        },
+        snowflake::Snowflake,
+        timestamp::Timestamp,
+    };
+
+    const MESSAGE: &str = r#"{
+        "id": "334385199974967042",
+        "channel_id": "290926798999357250",
+        "author": {
+            "id": "53908099506183680",
+            "username": "Mason",
+            "discriminator": "9999",
+            "avatar": "a_bab14f271d565501444b2ca3be944b25",
+            "public_flags": 131141
+        },
+        "content": "Supa Hot",
+        "timestamp": "2017-07-11T17:27:07.299000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "pinned": false,
+        "type": 19,
+        "flags": 4,
+        "referenced_message": null
+    }"#;
+
+    #[test]
+    fn deserialize() {
+        let message = serde_json::from_str::<Message>(MESSAGE).unwrap();
+        assert_eq!(message.id, 334385199974967042.into());
+        assert_eq!(message.author.name, "Mason");
+        assert_eq!(message.author.discriminator, 9999);
+        assert_eq!(message.kind, MessageType::Reply);
+        assert!(message.flags.includes(MessageFlags::SUPPRESS_EMBEDS));
+        assert_eq!(message.timestamp.to_unix_millis(), 1499794027299);
+        assert!(message.components.is_empty());
+    }
+
+    #[test]
+    fn deserialize_components() {
+        let mut message = serde_json::from_str::<serde_json::Value>(MESSAGE).unwrap();
+        // a component which is not a row is skipped instead of failing the message
+        message["components"] = serde_json::json!([
+            {"type": 10, "content": "new"},
+            {"type": 1, "components": [{"type": 2, "style": 1, "custom_id": "a", "label": "A"}]},
+        ]);
+        let message = serde_json::from_value::<Message>(message).unwrap();
+        assert_eq!(message.components.len(), 1);
+        assert_eq!(message.components[0].components[0].custom_id(), Some("a"));
+    }
+
+    #[test]
+    fn serialize() {
+        let message = CreateMessage::new()
+            .content("hi")
+            .allowed_mentions(AllowedMentions::none().users());
+        assert_eq!(
+            serde_json::to_string(&message).unwrap(),
+            r#"{"content":"hi","allowed_mentions":{"parse":["users"],"replied_user":false}}"#
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            CreateMessage::new().validate(),
+            Err(CreateMessageError::Empty)
+        );
+        assert_eq!(
+            CreateMessage::new().content("a".repeat(2001)).validate(),
+            Err(CreateMessageError::ContentTooLong(2001))
+        );
+        assert!(CreateMessage::new()
+            .file(AttachmentFile::from_bytes("a.txt", "a"))
+            .validate()
+            .is_ok());
+        let embed = EmbedBuilder::new()
+            .description("a".repeat(4000))
+            .build()
+            .unwrap();
+        assert!(CreateMessage::new()
+            .embeds(vec![embed.clone(), embed])
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn edit() {
+        let edit = EditMessage::new()
+            .content("")
+            .keep_attachments(vec![1.into()]);
+        assert_eq!(
+            serde_json::to_string(&edit).unwrap(),
+            r#"{"content":"","attachments":[{"id":"1"}]}"#
+        );
+        assert_eq!(EditMessage::new().validate(), Ok(()));
+        assert_eq!(
+            EditMessage::new()
+                .keep_attachments((0..10).map(Into::into).collect())
+                .file(AttachmentFile::from_bytes("a.txt", "a"))
+                .validate(),
+            Err(CreateMessageError::TooManyFiles(11))
+        );
+    }
+
+    #[test]
+    fn history() {
+        let message = serde_json::from_str::<Message>(MESSAGE).unwrap();
+        let sent = message.id.to_time();
+        let before = MessageHistory::before(sent).until(Snowflake::<Message>::new(1).to_time());
+        assert!(!before.forwards());
+        assert!(!before.is_past_bound(&message));
+        assert_eq!(
+            before.cursor,
+            Some(MessageCursor::Before(Snowflake::from_time(sent)))
+        );
+        let after = MessageHistory::after(Snowflake::new(1)).until(sent);
+        assert!(after.forwards());
+        assert!(!after.is_past_bound(&message));
+        assert!(MessageHistory::latest()
+            .until(sent + std::time::Duration::from_secs(1))
+            .is_past_bound(&message));
+    }
+
+    #[test]
+    fn bulk_delete() {
+        let now = Timestamp::now();
+        let first = u64::from(Snowflake::<Message>::from_time(now));
+        let old = Snowflake::from_time(Timestamp::new(now.to_unix_millis() - 15 * 86400000));
+        let mut ids = (0..101)
+            .map(|i| Snowflake::new(first + i))
+            .collect::<Vec<_>>();
+        ids.extend([old, Snowflake::new(first)]);
+        let plan = BulkDeletePlan::new(&ids, now);
+        assert_eq!(plan.chunks, vec![ids[..100].to_vec()]);
+        assert_eq!(plan.single, vec![old, ids[100]]);
+        assert_eq!(plan.duplicates, vec![Snowflake::new(first)]);
+    }
+}
@@ -1 +1,265 @@
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bitflags,
+    error::Error,
+    flags,
+    http::Http,
+    int_enum,
+    mention::Mention,
+    models::{guild::Guild, traits::Mentionable, user::User},
+    snowflake::{Snowflakable, Snowflake},
+    timestamp::Timestamp,
+};
+
+use self::{
+    forum::{
+        CreateForumPost, DefaultReaction, ForumError, ForumLayoutType, ForumPost, ForumTag,
+        SortOrderType,
+    },
+    message::{Message, MessageHistory},
+    overwrites::PermissionOverwrites,
+    thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+};
+
+pub mod attachment;
+pub mod component;
+pub mod embed;
+pub mod forum;
+pub mod message;
 pub mod overwrites;
+pub mod reaction;
+pub mod thread;
+
+/// A channel in a guild or a direct message.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#channel-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Channel {
+    pub id: Snowflake<Channel>,
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    // not given for direct messages, or for the channels in `GUILD_CREATE`
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub topic: Option<String>,
+    #[serde(default)]
+    pub position: Option<i32>,
+    #[serde(default)]
+    pub nsfw: bool,
+    #[serde(default)]
+    pub permission_overwrites: PermissionOverwrites,
+    // the category this channel is in, or the channel a thread was created in.
+    #[serde(default)]
+    pub parent_id: Option<Snowflake<Channel>>,
+    // the recipients of a direct message
+    #[serde(default)]
+    pub recipients: Vec<User>,
+    #[serde(default)]
+    pub last_message_id: Option<Snowflake<Message>>,
+    // the slowmode in seconds
+    #[serde(default)]
+    pub rate_limit_per_user: Option<u16>,
+    // when the last message was pinned
+    #[serde(default)]
+    pub last_pin_timestamp: Option<Timestamp>,
+    // the creator of a thread, or of a group direct message
+    #[serde(default)]
+    pub owner_id: Option<Snowflake<User>>,
+    // only given for threads
+    #[serde(default)]
+    pub thread_metadata: Option<ThreadMetadata>,
+    // the bot's thread member, if it has joined the thread
+    #[serde(default)]
+    pub member: Option<ThreadMember>,
+    // the approximate amount of messages and members in a thread, it stops counting members at 50
+    #[serde(default)]
+    pub message_count: Option<u32>,
+    #[serde(default)]
+    pub member_count: Option<u8>,
+    // the thread archive duration clients use for new threads
+    #[serde(default)]
+    pub default_auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(default)]
+    pub flags: ChannelFlags,
+    // the tags that can be applied to posts in a forum channel
+    #[serde(default)]
+    pub available_tags: Vec<ForumTag>,
+    // the tags applied to a forum post
+    #[serde(default)]
+    pub applied_tags: Vec<Snowflake<ForumTag>>,
+    // the emoji shown on the reaction button of forum posts
+    #[serde(default)]
+    pub default_reaction_emoji: Option<DefaultReaction>,
+    // the slowmode of new forum posts in seconds
+    #[serde(default)]
+    pub default_thread_rate_limit_per_user: Option<u16>,
+    #[serde(default)]
+    pub default_sort_order: Option<SortOrderType>,
+    #[serde(default)]
+    pub default_forum_layout: Option<ForumLayoutType>,
+}
+
+int_enum! {
+    /// The type of a [`Channel`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/channel#channel-object-channel-types)
+    ChannelType: u8 {
+        GuildText = 0,
+        DirectMessage = 1,
+        GuildVoice = 2,
+        GroupDirectMessage = 3,
+        GuildCategory = 4,
+        GuildAnnouncement = 5,
+        AnnouncementThread = 10,
+        PublicThread = 11,
+        PrivateThread = 12,
+        GuildStageVoice = 13,
+        GuildDirectory = 14,
+        GuildForum = 15,
+        GuildMedia = 16,
+    }
+}
+
+bitflags!("The flags of a [`Channel`].", "", "[Read more](https://discord.com/developers/docs/resources/channel#channel-object-channel-flags)"; ChannelFlags: u32: "crate::utilities::serde::deserialize_u32", "crate::utilities::serde::serialize_u32");
+
+flags! {
+    ChannelFlags:
+    PINNED = 1; "This thread is pinned to the top of its forum channel.",
+    REQUIRE_TAG = 4; "Posts in this forum channel must have a tag.",
+    HIDE_MEDIA_DOWNLOAD_OPTIONS = 15; "The download options are hidden in this media channel.",
+}
+
+impl ChannelType {
+    /// Checks whether this is a type of thread.
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self,
+            ChannelType::AnnouncementThread
+                | ChannelType::PublicThread
+                | ChannelType::PrivateThread
+        )
+    }
+    /// Checks whether this is a channel made of posts, a forum or media channel.
+    pub fn is_forum(&self) -> bool {
+        matches!(self, ChannelType::GuildForum | ChannelType::GuildMedia)
+    }
+}
+
+impl Channel {
+    /// Checks whether the channel is a thread.
+    pub fn is_thread(&self) -> bool {
+        self.kind.is_thread()
+    }
+    /// Streams the message history of the channel, see [`Http::messages`].
+    ///
+    /// ```rust,no_run
+    /// # async fn run(http: discors::http::Http, channel: discors::models::channel::Channel) -> Result<(), discors::error::Error> {
+    /// use discors::models::channel::message::MessageHistory;
+    /// use futures::TryStreamExt;
+    ///
+    /// let latest = channel
+    ///     .messages(&http, MessageHistory::latest().limit(250))
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn messages<'a>(
+        &self,
+        http: &'a Http,
+        history: MessageHistory,
+    ) -> impl Stream<Item = Result<Message, Error>> + 'a {
+        self.id.messages(http, history)
+    }
+    /// Creates a post in the forum channel, after checking it with [`CreateForumPost::validate_for`].
+    ///
+    /// ```rust,no_run
+    /// # async fn run(http: discors::http::Http, forum: discors::models::channel::Channel) -> Result<(), discors::error::Error> {
+    /// use discors::models::channel::{forum::CreateForumPost, message::CreateMessage};
+    ///
+    /// let tag = forum.available_tags[0].id;
+    /// let post = forum
+    ///     .create_post(
+    ///         &http,
+    ///         CreateForumPost::new("it does not compile", CreateMessage::new().content("help")).tag(tag),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_post(
+        &self,
+        http: &Http,
+        post: CreateForumPost,
+    ) -> Result<ForumPost, Error> {
+        post.validate_for(self)?;
+        http.create_forum_post(self.id, &post).await
+    }
+    /// Applies a tag of its forum channel to the post, this requires `MANAGE_THREADS` unless the bot created the post.
+    ///
+    /// The post is updated with the new tags.
+    pub async fn apply_tag(&mut self, http: &Http, tag: Snowflake<ForumTag>) -> Result<(), Error> {
+        if self.applied_tags.contains(&tag) {
+            return Ok(());
+        }
+        let mut tags = self.applied_tags.clone();
+        tags.push(tag);
+        self.set_tags(http, tags).await
+    }
+    /// Removes a tag from the post, this requires `MANAGE_THREADS` unless the bot created the post.
+    ///
+    /// The post is updated with the new tags.
+    pub async fn remove_tag(&mut self, http: &Http, tag: Snowflake<ForumTag>) -> Result<(), Error> {
+        if !self.applied_tags.contains(&tag) {
+            return Ok(());
+        }
+        let tags = self
+            .applied_tags
+            .iter()
+            .copied()
+            .filter(|t| *t != tag)
+            .collect();
+        self.set_tags(http, tags).await
+    }
+    async fn set_tags(&mut self, http: &Http, tags: Vec<Snowflake<ForumTag>>) -> Result<(), Error> {
+        if !self.is_thread() {
+            return Err(ForumError::NotAPost.into());
+        }
+        *self = http.set_applied_tags(self.id, &tags, None).await?;
+        Ok(())
+    }
+}
+
+impl Snowflake<Channel> {
+    /// Streams the message history of the channel, see [`Http::messages`].
+    pub fn messages<'a>(
+        &self,
+        http: &'a Http,
+        history: MessageHistory,
+    ) -> impl Stream<Item = Result<Message, Error>> + 'a {
+        http.messages(*self, history)
+    }
+}
+
+impl Snowflakable for Channel {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+impl Mentionable for Channel {
+    fn mention(&self) -> Mention {
+        self.id.mention()
+    }
+}
+
+impl Mentionable for Snowflake<Channel> {
+    fn mention(&self) -> Mention {
+        Mention::Channel(*self)
+    }
+}
@@ -1,6 +1,12 @@
-use crate::{models::user::User, permissions::Permissions, Snowflake};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, Clone, PartialEq)]
+use crate::{
+    models::{guild::role::Role, user::User},
+    permissions::Permissions,
+    Snowflake,
+};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct PermissionOverwrites(pub Vec<PermissionOverwrite>);
 
 #[derive(Debug, Clone, PartialEq)]
@@ -10,10 +16,67 @@ pub struct PermissionOverwrite {
     pub id: PermissionOverwriteType,
 }
 
+// how discord represents an overwrite, the type of the id is given as 0 for roles and 1 for members
+#[derive(Serialize, Deserialize)]
+struct RawPermissionOverwrite {
+    #[serde(
+        deserialize_with = "crate::utilities::serde::deserialize_u64",
+        serialize_with = "crate::utilities::serde::serialize_u64_str"
+    )]
+    id: u64,
+    #[serde(rename = "type")]
+    kind: u8,
+    allow: Permissions,
+    deny: Permissions,
+}
+
+impl Serialize for PermissionOverwrite {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (id, kind) = match self.id {
+            PermissionOverwriteType::Role(id) => (id.into(), 0),
+            PermissionOverwriteType::User(id) => (id.into(), 1),
+        };
+        RawPermissionOverwrite {
+            id,
+            kind,
+            allow: self.allow,
+            deny: self.deny,
+        }
+        .serialize(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionOverwrite {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawPermissionOverwrite::deserialize(d)?;
+        let id = match raw.kind {
+            0 => PermissionOverwriteType::Role(raw.id.into()),
+            1 => PermissionOverwriteType::User(raw.id.into()),
+            kind => {
+                return Err(serde::de::Error::custom(format!(
+                    "invalid permission overwrite type: {}",
+                    kind
+                )))
+            }
+        };
+        Ok(Self {
+            allow: raw.allow,
+            deny: raw.deny,
+            id,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PermissionOverwriteType {
     User(Snowflake<User>),
-    // Role(Snowflake<Role>),
+    Role(Snowflake<Role>),
 }
 
 // so the idea
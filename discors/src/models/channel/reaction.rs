@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::{
+        channel::{message::Message, Channel},
+        emoji::Emoji,
+        guild::{member::Member, Guild},
+        user::User,
+    },
+    snowflake::Snowflake,
+};
+
+/// The reactions with one emoji on a message.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#reaction-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reaction {
+    pub count: u32,
+    // whether the bot reacted with this emoji
+    pub me: bool,
+    pub emoji: Emoji,
+}
+
+/// A reaction being added to a message, given by the `MESSAGE_REACTION_ADD` event.
+///
+/// [Read more](https://discord.com/developers/docs/topics/gateway-events#message-reaction-add)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionAdd {
+    pub user_id: Snowflake<User>,
+    pub channel_id: Snowflake<Channel>,
+    pub message_id: Snowflake<Message>,
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    // the member who reacted, only given in guilds
+    #[serde(default)]
+    pub member: Option<Member>,
+    pub emoji: Emoji,
+    #[serde(default)]
+    pub message_author_id: Option<Snowflake<User>>,
+    // whether this is a super reaction
+    #[serde(default)]
+    pub burst: bool,
+}
+
+/// A reaction being removed from a message, given by the `MESSAGE_REACTION_REMOVE` event.
+///
+/// [Read more](https://discord.com/developers/docs/topics/gateway-events#message-reaction-remove)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionRemove {
+    pub user_id: Snowflake<User>,
+    pub channel_id: Snowflake<Channel>,
+    pub message_id: Snowflake<Message>,
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    pub emoji: Emoji,
+    #[serde(default)]
+    pub burst: bool,
+}
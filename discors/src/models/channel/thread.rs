@@ -0,0 +1,321 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    int_enum,
+    models::{
+        channel::{Channel, ChannelType},
+        guild::{member::Member, Guild},
+        user::User,
+    },
+    snowflake::Snowflake,
+    timestamp::Timestamp,
+};
+
+/// The information only threads have.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#thread-metadata-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadMetadata {
+    pub archived: bool,
+    // how long until the thread is archived after its last activity
+    pub auto_archive_duration: AutoArchiveDuration,
+    // when the thread was last archived or unarchived
+    pub archive_timestamp: Timestamp,
+    // only members with `MANAGE_THREADS` can unarchive a locked thread
+    #[serde(default)]
+    pub locked: bool,
+    // whether members who are not moderators can add others to a private thread
+    #[serde(default)]
+    pub invitable: Option<bool>,
+    // only given for threads created after 2022-01-09
+    #[serde(default)]
+    pub create_timestamp: Option<Timestamp>,
+}
+
+int_enum! {
+    /// How many minutes of inactivity it takes for a thread to be archived.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/channel#thread-metadata-object-thread-metadata-structure)
+    AutoArchiveDuration: u16 {
+        Hour = 60,
+        Day = 1440,
+        ThreeDays = 4320,
+        Week = 10080,
+    }
+}
+
+/// A user who has joined a thread.
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#thread-member-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadMember {
+    // the id of the thread, not given in `GUILD_CREATE`
+    #[serde(default)]
+    pub id: Option<Snowflake<Channel>>,
+    // not given in `GUILD_CREATE`
+    #[serde(default)]
+    pub user_id: Option<Snowflake<User>>,
+    pub join_timestamp: Timestamp,
+    // notification settings
+    #[serde(default)]
+    pub flags: u32,
+    // the guild member, only given when asked for with `with_member`
+    #[serde(default)]
+    pub member: Option<Member>,
+}
+
+/// A list of threads, with the bot's thread members of the threads it has joined.
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#list-active-guild-threads)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadList {
+    pub threads: Vec<Channel>,
+    #[serde(default)]
+    pub members: Vec<ThreadMember>,
+    // whether there are more archived threads, not given for active threads
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// The limits on threads.
+pub mod thread_limits {
+    use std::ops::RangeInclusive;
+
+    /// How many characters a thread name can have.
+    pub const NAME: RangeInclusive<usize> = 1..=100;
+    /// The longest slowmode, in seconds, 6 hours.
+    pub const RATE_LIMIT_PER_USER: u16 = 21600;
+}
+
+/// A builder for starting a thread, either from a message or on its own.
+///
+/// Starting a public thread requires `CREATE_PUBLIC_THREADS`, and a private one `CREATE_PRIVATE_THREADS`.
+///
+/// ```rust
+/// use discors::models::channel::{
+///     thread::{AutoArchiveDuration, StartThread},
+///     ChannelType,
+/// };
+///
+/// let thread = StartThread::new("planning")
+///     .kind(ChannelType::PrivateThread)
+///     .auto_archive_duration(AutoArchiveDuration::Week)
+///     .invitable(false);
+/// assert!(thread.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/resources/channel#start-thread-without-message)
+#[derive(Debug, Clone, Serialize)]
+pub struct StartThread {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u16>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<ChannelType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl StartThread {
+    /// Creates the thread from its name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            auto_archive_duration: None,
+            rate_limit_per_user: None,
+            kind: None,
+            invitable: None,
+            reason: None,
+        }
+    }
+    /// Sets how long until the thread is archived after its last activity.
+    pub fn auto_archive_duration(mut self, duration: AutoArchiveDuration) -> Self {
+        self.auto_archive_duration = Some(duration);
+        self
+    }
+    /// Sets the slowmode of the thread in seconds, at most 6 hours.
+    pub fn rate_limit_per_user(mut self, seconds: u16) -> Self {
+        self.rate_limit_per_user = Some(seconds);
+        self
+    }
+    /// Sets the type of a thread started without a message, this defaults to a private thread.
+    ///
+    /// Threads started from a message are always public, or announcement threads in announcement channels.
+    pub fn kind(mut self, kind: ChannelType) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+    /// Sets whether members who are not moderators can add others to a private thread.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.invitable = Some(invitable);
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+    /// Checks the thread is within discord's limits.
+    pub fn validate(&self) -> Result<(), ThreadError> {
+        let len = self.name.chars().count();
+        if !thread_limits::NAME.contains(&len) {
+            return Err(ThreadError::Name(len));
+        }
+        if let Some(kind) = self.kind {
+            if !kind.is_thread() {
+                return Err(ThreadError::NotAThread(kind));
+            }
+        }
+        match self.rate_limit_per_user {
+            Some(seconds) if seconds > thread_limits::RATE_LIMIT_PER_USER => {
+                Err(ThreadError::RateLimitPerUser(seconds))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The thread given in a `THREAD_DELETE` event.
+///
+/// [Read more](https://discord.com/developers/docs/topics/gateway-events#thread-delete)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadDelete {
+    pub id: Snowflake<Channel>,
+    pub guild_id: Snowflake<Guild>,
+    pub parent_id: Snowflake<Channel>,
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+}
+
+/// The event sent when the bot gains access to channels, with the active threads in them.
+///
+/// [Read more](https://discord.com/developers/docs/topics/gateway-events#thread-list-sync)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadListSync {
+    pub guild_id: Snowflake<Guild>,
+    // the parent channels whose threads are synced, all channels of the guild when this is empty
+    #[serde(default)]
+    pub channel_ids: Vec<Snowflake<Channel>>,
+    pub threads: Vec<Channel>,
+    // the bot's thread members of the threads it has joined
+    pub members: Vec<ThreadMember>,
+}
+
+/// The event sent when members are added to or removed from a thread.
+///
+/// [Read more](https://discord.com/developers/docs/topics/gateway-events#thread-members-update)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadMembersUpdate {
+    // the id of the thread
+    pub id: Snowflake<Channel>,
+    pub guild_id: Snowflake<Guild>,
+    // the approximate amount of members, it stops counting at 50
+    pub member_count: u8,
+    #[serde(default)]
+    pub added_members: Vec<ThreadMember>,
+    #[serde(default)]
+    pub removed_member_ids: Vec<Snowflake<User>>,
+}
+
+/// The error given when a [`StartThread`] is outside of discord's limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThreadError {
+    /// The name is not 1 to 100 characters long, with the length that was given.
+    Name(usize),
+    /// The slowmode is longer than 6 hours, with the seconds that were given.
+    RateLimitPerUser(u16),
+    /// The type given is not a thread type.
+    NotAThread(ChannelType),
+}
+
+impl Display for ThreadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThreadError::Name(len) => write!(
+                f,
+                "thread name is {} characters long, it must be 1 to 100",
+                len
+            ),
+            ThreadError::RateLimitPerUser(seconds) => write!(
+                f,
+                "a slowmode of {} seconds is longer than the limit of {}",
+                seconds,
+                thread_limits::RATE_LIMIT_PER_USER
+            ),
+            ThreadError::NotAThread(kind) => write!(f, "{:?} is not a thread type", kind),
+        }
+    }
+}
+
+impl StdError for ThreadError {}
+
+#[cfg(test)]
+mod test {
+    use super::{AutoArchiveDuration, StartThread, ThreadError, ThreadListSync};
+    use crate::models::channel::ChannelType;
+
+    #[test]
+    fn deserialize() {
+        let sync = serde_json::from_str::<ThreadListSync>(
+            r#"{
+                "guild_id": "1",
+                "threads": [{
+                    "id": "2",
+                    "type": 11,
+                    "guild_id": "1",
+                    "parent_id": "3",
+                    "owner_id": "4",
+                    "name": "a thread",
+                    "member_count": 2,
+                    "message_count": 5,
+                    "thread_metadata": {
+                        "archived": false,
+                        "auto_archive_duration": 1440,
+                        "archive_timestamp": "2023-01-01T00:00:00.000000+00:00",
+                        "locked": false
+                    }
+                }],
+                "members": [{"id": "2", "user_id": "4", "join_timestamp": "2023-01-01T00:00:00.000000+00:00", "flags": 1}]
+            }"#,
+        )
+        .unwrap();
+        let thread = &sync.threads[0];
+        assert!(thread.kind.is_thread());
+        assert_eq!(
+            thread
+                .thread_metadata
+                .as_ref()
+                .unwrap()
+                .auto_archive_duration,
+            AutoArchiveDuration::Day
+        );
+        assert_eq!(thread.member_count, Some(2));
+        assert_eq!(sync.members[0].id, Some(thread.id));
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(StartThread::new("").validate(), Err(ThreadError::Name(0)));
+        assert_eq!(
+            StartThread::new("a")
+                .kind(ChannelType::GuildText)
+                .validate(),
+            Err(ThreadError::NotAThread(ChannelType::GuildText))
+        );
+        let thread = StartThread::new("a").auto_archive_duration(AutoArchiveDuration::Hour);
+        assert_eq!(
+            serde_json::to_string(&thread).unwrap(),
+            r#"{"name":"a","auto_archive_duration":60}"#
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use std::fmt::{self, Display};
+
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    mention::Mention,
+    models::{guild::role::Role, image::ImageData, traits::Mentionable, user::User},
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// An emoji, which is either a unicode emoji or a custom emoji uploaded to a guild.
+///
+/// This formats into the syntax used in messages, and [`Emoji::to_reaction`] gives the form used for reactions.
+///
+/// ```rust
+/// use discors::models::emoji::Emoji;
+///
+/// let emoji = Emoji::from("<:rust:123>");
+/// assert_eq!(emoji.to_string(), "<:rust:123>");
+/// assert_eq!(emoji.to_reaction(), "rust%3A123");
+/// assert_eq!(Emoji::from("🦀").to_reaction(), "%F0%9F%A6%80");
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/resources/emoji#emoji-object)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Emoji {
+    /// A unicode emoji, like `🦀`.
+    Unicode(String),
+    /// A custom emoji.
+    Custom(CustomEmoji),
+}
+
+impl Emoji {
+    /// The name of the emoji, which is the emoji itself for unicode emojis.
+    pub fn name(&self) -> &str {
+        match self {
+            Emoji::Unicode(emoji) => emoji,
+            Emoji::Custom(emoji) => &emoji.name,
+        }
+    }
+    /// The id of the emoji, if it is a custom emoji.
+    pub fn id(&self) -> Option<Snowflake<CustomEmoji>> {
+        match self {
+            Emoji::Unicode(_) => None,
+            Emoji::Custom(emoji) => Some(emoji.id),
+        }
+    }
+    /// The URL encoded form used for reactions, `name:id` for custom emojis.
+    pub fn to_reaction(&self) -> String {
+        let emoji = match self {
+            Emoji::Unicode(emoji) => emoji.clone(),
+            Emoji::Custom(emoji) => format!("{}:{}", emoji.name, emoji.id),
+        };
+        utf8_percent_encode(&emoji, NON_ALPHANUMERIC).to_string()
+    }
+    /// Checks whether two emojis are the same, custom emojis are compared by id as their names can change.
+    pub fn matches(&self, other: &Emoji) -> bool {
+        match (self, other) {
+            (Emoji::Custom(a), Emoji::Custom(b)) => a.id == b.id,
+            (Emoji::Unicode(a), Emoji::Unicode(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Emoji {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Emoji::Unicode(emoji) => f.write_str(emoji),
+            Emoji::Custom(emoji) => emoji.mention().fmt(f),
+        }
+    }
+}
+
+impl From<&str> for Emoji {
+    /// Parses a custom emoji like `<:name:id>`, or `name:id` as used for reactions, anything else is taken as a unicode emoji.
+    fn from(s: &str) -> Self {
+        let custom = match s.parse() {
+            Ok(Mention::Emoji { name, id, animated }) => Some((name, id, animated)),
+            _ => s
+                .split_once(':')
+                .filter(|(name, _)| is_emoji_name(name))
+                .and_then(|(name, id)| Some((name.to_string(), id.parse().ok()?, false))),
+        };
+        match custom {
+            Some((name, id, animated)) => Emoji::Custom(CustomEmoji {
+                animated,
+                ..CustomEmoji::new(id, name)
+            }),
+            None => Emoji::Unicode(s.to_string()),
+        }
+    }
+}
+
+// custom emoji names are at least 2 letters, digits and underscores
+pub(crate) fn is_emoji_name(name: &str) -> bool {
+    name.len() >= 2 && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl From<char> for Emoji {
+    fn from(c: char) -> Self {
+        Emoji::Unicode(c.to_string())
+    }
+}
+
+impl From<CustomEmoji> for Emoji {
+    fn from(emoji: CustomEmoji) -> Self {
+        Emoji::Custom(emoji)
+    }
+}
+
+// the partial emoji discord sends for reactions, unicode emojis have no id
+#[derive(Deserialize)]
+struct RawEmoji {
+    id: Option<Snowflake<CustomEmoji>>,
+    name: Option<String>,
+    #[serde(flatten)]
+    rest: serde_json::Map<String, serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for Emoji {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let RawEmoji { id, name, mut rest } = RawEmoji::deserialize(d)?;
+        match id {
+            None => Ok(Emoji::Unicode(name.unwrap_or_default())),
+            Some(id) => {
+                rest.insert("id".to_string(), id.to_string().into());
+                // deleted custom emojis have no name
+                rest.insert("name".to_string(), name.unwrap_or_default().into());
+                serde_json::from_value(rest.into())
+                    .map(Emoji::Custom)
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    }
+}
+
+impl Serialize for Emoji {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Emoji::Unicode(emoji) => RawUnicode {
+                id: None,
+                name: emoji,
+            }
+            .serialize(s),
+            Emoji::Custom(emoji) => emoji.serialize(s),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RawUnicode<'a> {
+    id: Option<()>,
+    name: &'a str,
+}
+
+/// A custom emoji, uploaded to a guild.
+///
+/// [Read more](https://discord.com/developers/docs/resources/emoji#emoji-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomEmoji {
+    pub id: Snowflake<CustomEmoji>,
+    pub name: String,
+    #[serde(default)]
+    pub animated: bool,
+    // the roles allowed to use the emoji, everyone can use it when this is empty
+    #[serde(default)]
+    pub roles: Vec<Snowflake<Role>>,
+    // who uploaded the emoji, only given with `MANAGE_GUILD_EXPRESSIONS`
+    #[serde(default)]
+    pub user: Option<Box<User>>,
+    #[serde(default = "default_true")]
+    pub require_colons: bool,
+    // whether the emoji is managed by an integration
+    #[serde(default)]
+    pub managed: bool,
+    // emojis can be unavailable when the guild loses boosts
+    #[serde(default = "default_true")]
+    pub available: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl CustomEmoji {
+    /// Creates a partial emoji from its id and name, like the ones in reactions and components.
+    pub fn new(id: Snowflake<CustomEmoji>, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            animated: false,
+            roles: Vec::new(),
+            user: None,
+            require_colons: true,
+            managed: false,
+            available: true,
+        }
+    }
+    /// The URL of the emoji's image, a gif for animated emojis and a png otherwise.
+    pub fn url(&self) -> String {
+        format!(
+            "https://cdn.discordapp.com/emojis/{}.{}",
+            self.id,
+            if self.animated { "gif" } else { "png" }
+        )
+    }
+}
+
+impl Snowflakable for CustomEmoji {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+impl Mentionable for CustomEmoji {
+    fn mention(&self) -> Mention {
+        Mention::Emoji {
+            name: self.name.clone(),
+            id: self.id,
+            animated: self.animated,
+        }
+    }
+}
+
+/// A builder for uploading a custom emoji to a guild, this requires `MANAGE_GUILD_EXPRESSIONS`.
+///
+/// [Read more](https://discord.com/developers/docs/resources/emoji#create-guild-emoji)
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateEmoji {
+    name: String,
+    image: ImageData,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    roles: Vec<Snowflake<Role>>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl CreateEmoji {
+    /// Creates the emoji from its name and image, which is at most 256 KiB.
+    pub fn new(name: impl Into<String>, image: ImageData) -> Self {
+        Self {
+            name: name.into(),
+            image,
+            roles: Vec::new(),
+            reason: None,
+        }
+    }
+    /// Only lets members with a role use the emoji.
+    pub fn role(mut self, role: Snowflake<Role>) -> Self {
+        self.roles.push(role);
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+/// A builder for modifying a custom emoji. Only the fields that are set are changed.
+///
+/// [Read more](https://discord.com/developers/docs/resources/emoji#modify-guild-emoji)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditEmoji {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roles: Option<Vec<Snowflake<Role>>>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl EditEmoji {
+    /// Creates an edit which doesn't change anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Renames the emoji.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    /// Sets the roles allowed to use the emoji, an empty list lets everyone use it.
+    pub fn roles(mut self, roles: Vec<Snowflake<Role>>) -> Self {
+        self.roles = Some(roles);
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CustomEmoji, Emoji};
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<Emoji>(r#"{"id": null, "name": "🔥"}"#).unwrap(),
+            Emoji::Unicode("🔥".to_string())
+        );
+        let emoji = serde_json::from_str::<Emoji>(
+            r#"{"id": "41771983429993937", "name": "LUL", "roles": ["41771983429993000"], "animated": true}"#,
+        )
+        .unwrap();
+        match &emoji {
+            Emoji::Custom(custom) => {
+                assert_eq!(custom.roles.len(), 1);
+                assert!(custom.animated && custom.available);
+            }
+            emoji => panic!("expected a custom emoji, got {:?}", emoji),
+        }
+        assert_eq!(emoji.to_string(), "<a:LUL:41771983429993937>");
+        let deleted =
+            serde_json::from_str::<Emoji>(r#"{"id": "41771983429993937", "name": null}"#).unwrap();
+        assert_eq!(deleted.name(), "");
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&Emoji::from('🔥')).unwrap(),
+            r#"{"id":null,"name":"🔥"}"#
+        );
+        let emoji = Emoji::from("<:rust:123>");
+        let json = serde_json::to_string(&emoji).unwrap();
+        assert_eq!(serde_json::from_str::<Emoji>(&json).unwrap(), emoji);
+    }
+
+    #[test]
+    fn reaction() {
+        assert_eq!(
+            Emoji::from("<a:rust_crab:123>").to_reaction(),
+            "rust%5Fcrab%3A123"
+        );
+        assert!(Emoji::from("<:ab:1>").matches(&Emoji::Custom(CustomEmoji::new(1.into(), "b"))));
+        assert!(!Emoji::from("🔥").matches(&Emoji::from("<:ab:1>")));
+        // the form used for reactions is parsed as a custom emoji too
+        let emoji = Emoji::from("rust:123");
+        assert_eq!(emoji.id(), Some(123.into()));
+        assert_eq!(emoji.to_reaction(), "rust%3A123");
+        assert_eq!(Emoji::from("<:rust:123>").id(), Some(123.into()));
+        assert_eq!(Emoji::from("rust:abc").id(), None);
+        assert_eq!(Emoji::from(":)").id(), None);
+    }
+}
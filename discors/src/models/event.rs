@@ -0,0 +1,130 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::models::{
+    application::interaction::Interaction,
+    channel::{
+        message::Message,
+        reaction::{ReactionAdd, ReactionRemove},
+        thread::{ThreadDelete, ThreadListSync, ThreadMember, ThreadMembersUpdate},
+        Channel,
+    },
+    guild::Guild,
+};
+
+/// An event sent by discord's gateway.
+///
+/// Events this library does not have a model for yet are kept as raw json in [`Event::Unknown`].
+///
+/// [Read more](https://discord.com/developers/docs/topics/gateway-events#receive-events)
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Event {
+    GuildCreate(Box<Guild>),
+    InteractionCreate(Box<Interaction>),
+    MessageCreate(Box<Message>),
+    ReactionAdd(Box<ReactionAdd>),
+    ReactionRemove(Box<ReactionRemove>),
+    ThreadCreate(Box<Channel>),
+    ThreadUpdate(Box<Channel>),
+    ThreadDelete(Box<ThreadDelete>),
+    ThreadListSync(Box<ThreadListSync>),
+    // the bot's own thread member was updated
+    ThreadMemberUpdate(Box<ThreadMember>),
+    ThreadMembersUpdate(Box<ThreadMembersUpdate>),
+    Unknown {
+        // the name of the event, like `MESSAGE_DELETE`
+        kind: String,
+        data: serde_json::Value,
+    },
+}
+
+impl Event {
+    /// Parses the data of a dispatch from the gateway, `kind` is the name of the event (the `t` field).
+    pub fn from_dispatch(kind: &str, data: serde_json::Value) -> Result<Self, serde_json::Error> {
+        fn parse<T>(data: serde_json::Value) -> Result<Box<T>, serde_json::Error>
+        where
+            T: DeserializeOwned,
+        {
+            serde_json::from_value(data).map(Box::new)
+        }
+        Ok(match kind {
+            "GUILD_CREATE" => Event::GuildCreate(parse(data)?),
+            "INTERACTION_CREATE" => Event::InteractionCreate(parse(data)?),
+            "MESSAGE_CREATE" => Event::MessageCreate(parse(data)?),
+            "MESSAGE_REACTION_ADD" => Event::ReactionAdd(parse(data)?),
+            "MESSAGE_REACTION_REMOVE" => Event::ReactionRemove(parse(data)?),
+            "THREAD_CREATE" => Event::ThreadCreate(parse(data)?),
+            "THREAD_UPDATE" => Event::ThreadUpdate(parse(data)?),
+            "THREAD_DELETE" => Event::ThreadDelete(parse(data)?),
+            "THREAD_LIST_SYNC" => Event::ThreadListSync(parse(data)?),
+            "THREAD_MEMBER_UPDATE" => Event::ThreadMemberUpdate(parse(data)?),
+            "THREAD_MEMBERS_UPDATE" => Event::ThreadMembersUpdate(parse(data)?),
+            kind => Event::Unknown {
+                kind: kind.to_string(),
+                data,
+            },
+        })
+    }
+}
+
+// the shape of a dispatch, which is what webhooks and the gateway send
+#[derive(Deserialize, Serialize)]
+struct Dispatch {
+    t: String,
+    d: serde_json::Value,
+}
+
+impl<'de> Deserialize<'de> for Event {
+    /// Deserializes a gateway dispatch payload, like `{"t": "MESSAGE_CREATE", "d": {...}}`.
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let Dispatch { t, d: data } = Dispatch::deserialize(d)?;
+        Event::from_dispatch(&t, data).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Event;
+    use crate::models::emoji::Emoji;
+
+    #[test]
+    fn from_dispatch() {
+        let event = serde_json::from_str::<Event>(
+            r#"{"t": "MESSAGE_REACTION_ADD", "d": {
+                "user_id": "1", "channel_id": "2", "message_id": "3", "guild_id": "4",
+                "emoji": {"id": null, "name": "🔥"}, "burst": false
+            }}"#,
+        )
+        .unwrap();
+        match event {
+            Event::ReactionAdd(reaction) => {
+                assert_eq!(reaction.message_id, 3.into());
+                assert_eq!(reaction.emoji, Emoji::from("🔥"));
+            }
+            event => panic!("expected a reaction, got {:?}", event),
+        }
+        assert!(matches!(
+            Event::from_dispatch(
+                "THREAD_DELETE",
+                serde_json::json!({"id": "1", "guild_id": "2", "parent_id": "3", "type": 11})
+            )
+            .unwrap(),
+            Event::ThreadDelete(thread) if thread.kind.is_thread()
+        ));
+        assert!(matches!(
+            Event::from_dispatch(
+                "INTERACTION_CREATE",
+                serde_json::json!({"id": "1", "application_id": "2", "type": 1, "token": "t", "version": 1})
+            )
+            .unwrap(),
+            Event::InteractionCreate(interaction) if interaction.data.is_none()
+        ));
+        assert!(matches!(
+            Event::from_dispatch("TYPING_START", serde_json::json!({})).unwrap(),
+            Event::Unknown { kind, .. } if kind == "TYPING_START"
+        ));
+    }
+}
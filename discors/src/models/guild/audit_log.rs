@@ -0,0 +1,242 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    int_enum,
+    models::{channel::Channel, user::User},
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// The most characters an audit log reason can have.
+pub const REASON_LIMIT: usize = 512;
+
+/// The audit log of a guild, with the users and threads its entries refer to.
+///
+/// [Read more](https://discord.com/developers/docs/resources/audit-log#audit-log-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLog {
+    #[serde(rename = "audit_log_entries")]
+    pub entries: Vec<AuditLogEntry>,
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(default)]
+    pub threads: Vec<Channel>,
+}
+
+impl AuditLog {
+    /// Gets a user the entries refer to.
+    pub fn user(&self, id: Snowflake<User>) -> Option<&User> {
+        self.users.iter().find(|u| u.id == id)
+    }
+}
+
+/// An action taken in a guild.
+///
+/// [Read more](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub id: Snowflake<AuditLogEntry>,
+    // the id of what was changed, this can be a user, channel, role or other id depending on the action
+    pub target_id: Option<String>,
+    #[serde(default)]
+    pub changes: Vec<AuditLogChange>,
+    // who made the change
+    pub user_id: Option<Snowflake<User>>,
+    pub action_type: AuditLogEvent,
+    // more information for some actions
+    #[serde(default)]
+    pub options: Option<AuditLogEntryInfo>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl Snowflakable for AuditLogEntry {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+/// A change to a field made by an [`AuditLogEntry`], the values are raw json as their type depends on the key.
+///
+/// [Read more](https://discord.com/developers/docs/resources/audit-log#audit-log-change-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogChange {
+    // the name of the changed field, like `nick`
+    pub key: String,
+    #[serde(default)]
+    pub old_value: Option<serde_json::Value>,
+    #[serde(default)]
+    pub new_value: Option<serde_json::Value>,
+}
+
+impl AuditLogChange {
+    /// Deserializes the value before the change, `None` if the field was not set.
+    pub fn old_as<T>(&self) -> Option<Result<T, serde_json::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.old_value.clone().map(serde_json::from_value)
+    }
+    /// Deserializes the value after the change, `None` if the field was removed.
+    pub fn new_as<T>(&self) -> Option<Result<T, serde_json::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.new_value.clone().map(serde_json::from_value)
+    }
+}
+
+/// More information on some [`AuditLogEntry`]s, which fields are set depends on the action.
+///
+/// [Read more](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-optional-audit-entry-info)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditLogEntryInfo {
+    pub application_id: Option<String>,
+    pub auto_moderation_rule_name: Option<String>,
+    pub auto_moderation_rule_trigger_type: Option<String>,
+    pub channel_id: Option<Snowflake<Channel>>,
+    // the amount of entities targeted, as a string
+    pub count: Option<String>,
+    pub delete_member_days: Option<String>,
+    // the id of the overwritten entity
+    pub id: Option<String>,
+    pub members_removed: Option<String>,
+    pub message_id: Option<String>,
+    pub role_name: Option<String>,
+    // the type of the overwritten entity, "0" for roles and "1" for members
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub integration_type: Option<String>,
+}
+
+int_enum! {
+    /// The kind of action an [`AuditLogEntry`] is.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events)
+    AuditLogEvent: u16 {
+        GuildUpdate = 1,
+        ChannelCreate = 10,
+        ChannelUpdate = 11,
+        ChannelDelete = 12,
+        ChannelOverwriteCreate = 13,
+        ChannelOverwriteUpdate = 14,
+        ChannelOverwriteDelete = 15,
+        MemberKick = 20,
+        MemberPrune = 21,
+        MemberBanAdd = 22,
+        MemberBanRemove = 23,
+        MemberUpdate = 24,
+        MemberRoleUpdate = 25,
+        MemberMove = 26,
+        MemberDisconnect = 27,
+        BotAdd = 28,
+        RoleCreate = 30,
+        RoleUpdate = 31,
+        RoleDelete = 32,
+        InviteCreate = 40,
+        InviteUpdate = 41,
+        InviteDelete = 42,
+        WebhookCreate = 50,
+        WebhookUpdate = 51,
+        WebhookDelete = 52,
+        EmojiCreate = 60,
+        EmojiUpdate = 61,
+        EmojiDelete = 62,
+        MessageDelete = 72,
+        MessageBulkDelete = 73,
+        MessagePin = 74,
+        MessageUnpin = 75,
+        IntegrationCreate = 80,
+        IntegrationUpdate = 81,
+        IntegrationDelete = 82,
+        StageInstanceCreate = 83,
+        StageInstanceUpdate = 84,
+        StageInstanceDelete = 85,
+        StickerCreate = 90,
+        StickerUpdate = 91,
+        StickerDelete = 92,
+        GuildScheduledEventCreate = 100,
+        GuildScheduledEventUpdate = 101,
+        GuildScheduledEventDelete = 102,
+        ThreadCreate = 110,
+        ThreadUpdate = 111,
+        ThreadDelete = 112,
+        ApplicationCommandPermissionUpdate = 121,
+        AutoModerationRuleCreate = 140,
+        AutoModerationRuleUpdate = 141,
+        AutoModerationRuleDelete = 142,
+        AutoModerationBlockMessage = 143,
+        AutoModerationFlagToChannel = 144,
+        AutoModerationUserCommunicationDisabled = 145,
+        CreatorMonetizationRequestCreated = 150,
+        CreatorMonetizationTermsAccepted = 151,
+        OnboardingPromptCreate = 163,
+        OnboardingPromptUpdate = 164,
+        OnboardingPromptDelete = 165,
+        OnboardingCreate = 166,
+        OnboardingUpdate = 167,
+        HomeSettingsCreate = 190,
+        HomeSettingsUpdate = 191,
+    }
+}
+
+/// The error given when an audit log reason is longer than [`REASON_LIMIT`] characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReasonTooLong(pub usize);
+
+impl Display for ReasonTooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "audit log reason is {} characters long, the limit is {}",
+            self.0, REASON_LIMIT
+        )
+    }
+}
+
+impl StdError for ReasonTooLong {}
+
+#[cfg(test)]
+mod test {
+    use super::{AuditLog, AuditLogEvent};
+
+    const AUDIT_LOG: &str = r#"{
+        "audit_log_entries": [{
+            "id": "1067182217233870849",
+            "target_id": "80351110224678912",
+            "user_id": "53908232506183680",
+            "action_type": 24,
+            "reason": "bad name",
+            "changes": [{"key": "nick", "old_value": "nelly", "new_value": "Nelly"}]
+        }, {
+            "id": "1067182217233870850",
+            "target_id": null,
+            "user_id": "53908232506183680",
+            "action_type": 73,
+            "options": {"channel_id": "290926798999357250", "count": "5"}
+        }],
+        "users": [{"id": "53908232506183680", "username": "Mason", "discriminator": "9999", "avatar": null}],
+        "integrations": [],
+        "webhooks": []
+    }"#;
+
+    #[test]
+    fn deserialize() {
+        let log = serde_json::from_str::<AuditLog>(AUDIT_LOG).unwrap();
+        let entry = &log.entries[0];
+        assert_eq!(entry.action_type, AuditLogEvent::MemberUpdate);
+        assert_eq!(
+            entry.changes[0].old_as::<String>().unwrap().unwrap(),
+            "nelly"
+        );
+        assert_eq!(log.user(entry.user_id.unwrap()).unwrap().name, "Mason");
+        let options = log.entries[1].options.as_ref().unwrap();
+        assert_eq!(log.entries[1].action_type, AuditLogEvent::MessageBulkDelete);
+        assert_eq!(options.count.as_deref(), Some("5"));
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{models::user::User, snowflake::Snowflake};
+
+/// A ban of a user from a guild.
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#ban-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ban {
+    pub reason: Option<String>,
+    pub user: User,
+}
+
+/// The limits on banning users.
+pub mod ban_limits {
+    /// The most seconds of messages that can be deleted with a ban, 7 days.
+    pub const DELETE_MESSAGE_SECONDS: u32 = 7 * 24 * 60 * 60;
+    /// The most users that can be banned at once.
+    pub const BULK_BAN_USERS: usize = 200;
+}
+
+/// A builder for banning a user.
+///
+/// ```rust
+/// use discors::models::guild::ban::CreateBan;
+///
+/// let ban = CreateBan::new().delete_message_seconds(3600).reason("spam");
+/// assert!(ban.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#create-guild-ban)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateBan {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_message_seconds: Option<u32>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl CreateBan {
+    /// Creates a ban which does not delete any messages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Deletes the messages the user sent in the last seconds, at most 7 days.
+    pub fn delete_message_seconds(mut self, seconds: u32) -> Self {
+        self.delete_message_seconds = Some(seconds);
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+    /// Checks the ban is within discord's limits.
+    pub fn validate(&self) -> Result<(), BanError> {
+        match self.delete_message_seconds {
+            Some(seconds) if seconds > ban_limits::DELETE_MESSAGE_SECONDS => {
+                Err(BanError::DeleteMessageSecondsTooLong(seconds))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A builder for banning many users at once, this requires `BAN_MEMBERS` and `MANAGE_GUILD`.
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#bulk-guild-ban)
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkBan {
+    user_ids: Vec<Snowflake<User>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_message_seconds: Option<u32>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl BulkBan {
+    /// Creates a ban of up to 200 users.
+    pub fn new(users: Vec<Snowflake<User>>) -> Self {
+        Self {
+            user_ids: users,
+            delete_message_seconds: None,
+            reason: None,
+        }
+    }
+    /// Deletes the messages the users sent in the last seconds, at most 7 days.
+    pub fn delete_message_seconds(mut self, seconds: u32) -> Self {
+        self.delete_message_seconds = Some(seconds);
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+    /// Checks the ban is within discord's limits.
+    pub fn validate(&self) -> Result<(), BanError> {
+        if self.user_ids.is_empty() {
+            return Err(BanError::NoUsers);
+        }
+        if self.user_ids.len() > ban_limits::BULK_BAN_USERS {
+            return Err(BanError::TooManyUsers(self.user_ids.len()));
+        }
+        match self.delete_message_seconds {
+            Some(seconds) if seconds > ban_limits::DELETE_MESSAGE_SECONDS => {
+                Err(BanError::DeleteMessageSecondsTooLong(seconds))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The users which were and were not banned by a [`BulkBan`].
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#bulk-guild-ban-bulk-ban-response)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkBanResponse {
+    pub banned_users: Vec<Snowflake<User>>,
+    // users which could not be banned, like users which were already banned
+    pub failed_users: Vec<Snowflake<User>>,
+}
+
+/// The error given when a ban is outside of discord's limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BanError {
+    DeleteMessageSecondsTooLong(u32),
+    TooManyUsers(usize),
+    NoUsers,
+}
+
+impl Display for BanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BanError::DeleteMessageSecondsTooLong(seconds) => write!(
+                f,
+                "{} seconds of messages can not be deleted, the limit is {}",
+                seconds,
+                ban_limits::DELETE_MESSAGE_SECONDS
+            ),
+            BanError::TooManyUsers(len) => write!(
+                f,
+                "{} users can not be banned at once, the limit is {}",
+                len,
+                ban_limits::BULK_BAN_USERS
+            ),
+            BanError::NoUsers => f.write_str("a bulk ban needs at least one user"),
+        }
+    }
+}
+
+impl StdError for BanError {}
+
+#[cfg(test)]
+mod test {
+    use super::{Ban, BanError, BulkBan, CreateBan};
+
+    #[test]
+    fn deserialize() {
+        let ban = serde_json::from_str::<Ban>(
+            r#"{"reason": "mentioning b1nzy", "user": {"id": "53908232506183680", "username": "Mason", "discriminator": "9999", "avatar": null}}"#,
+        )
+        .unwrap();
+        assert_eq!(ban.reason.as_deref(), Some("mentioning b1nzy"));
+        assert_eq!(ban.user.name, "Mason");
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            CreateBan::new().delete_message_seconds(604801).validate(),
+            Err(BanError::DeleteMessageSecondsTooLong(604801))
+        );
+        assert_eq!(BulkBan::new(Vec::new()).validate(), Err(BanError::NoUsers));
+        let users = (0..201).map(Into::into).collect::<Vec<_>>();
+        assert_eq!(
+            BulkBan::new(users.clone()).validate(),
+            Err(BanError::TooManyUsers(201))
+        );
+        let ban = BulkBan::new(users[..200].to_vec())
+            .delete_message_seconds(60)
+            .reason("raid");
+        assert!(ban.validate().is_ok());
+        // the reason is sent as a header
+        assert!(!serde_json::to_string(&ban).unwrap().contains("raid"));
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bitflags, flags,
+    models::{channel::Channel, guild::role::Role, user::User},
+    permissions::Permissions,
+    snowflake::Snowflake,
+    timestamp::Timestamp,
+};
+
+/// A user's membership in a guild.
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#guild-member-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Member {
+    // not given in the member of a message event
+    #[serde(default)]
+    pub user: Option<User>,
+    // the nickname in the guild
+    #[serde(default)]
+    pub nick: Option<String>,
+    #[serde(default)]
+    pub avatar: Option<String>, // guild avatar hash
+    pub roles: Vec<Snowflake<Role>>,
+    pub joined_at: Timestamp,
+    // when the member started boosting the guild
+    #[serde(default)]
+    pub premium_since: Option<Timestamp>,
+    #[serde(default)]
+    pub deaf: bool,
+    #[serde(default)]
+    pub mute: bool,
+    #[serde(default)]
+    pub flags: GuildMemberFlags,
+    // whether the member has not passed the membership screening yet
+    #[serde(default)]
+    pub pending: bool,
+    // the permissions of the member in the channel, only given in interactions
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
+    // when the member's timeout ends, this can be in the past when the timeout is over
+    #[serde(default)]
+    pub communication_disabled_until: Option<Timestamp>,
+}
+
+impl Member {
+    /// The longest a member can be timed out for, 28 days.
+    pub const MAX_TIMEOUT: Duration = Duration::from_secs(28 * 24 * 60 * 60);
+
+    /// The name of the member shown in the guild, this is their nickname, display name or username.
+    pub fn display_name(&self) -> Option<&str> {
+        self.nick.as_deref().or_else(|| {
+            let user = self.user.as_ref()?;
+            Some(user.global_name.as_deref().unwrap_or(&user.name))
+        })
+    }
+    /// Checks whether the member has a role.
+    pub fn has_role(&self, role: Snowflake<Role>) -> bool {
+        self.roles.contains(&role)
+    }
+    /// How long is left of the member's timeout, or `None` if they are not timed out.
+    pub fn timeout_remaining(&self) -> Option<Duration> {
+        self.communication_disabled_until?.until()
+    }
+    /// Checks whether the member is timed out.
+    pub fn is_timed_out(&self) -> bool {
+        self.timeout_remaining().is_some()
+    }
+}
+
+bitflags!("The flags of a [`Member`].", "", "[Read more](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags)"; GuildMemberFlags: u32: "crate::utilities::serde::deserialize_u32", "crate::utilities::serde::serialize_u32");
+
+flags! {
+    GuildMemberFlags:
+    DID_REJOIN = 0; "The member has left and rejoined the guild.",
+    COMPLETED_ONBOARDING = 1; "The member has completed onboarding.",
+    BYPASSES_VERIFICATION = 2; "The member is exempt from the guild's verification requirements.",
+    STARTED_ONBOARDING = 3; "The member has started onboarding.",
+}
+
+/// The error given when the bot refuses to moderate a member, because discord would refuse it too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ModerationError {
+    /// The timeout is longer than [`Member::MAX_TIMEOUT`].
+    TimeoutTooLong(Duration),
+    /// The member owns the guild.
+    Owner,
+    /// The member has the `ADMINISTRATOR` permission.
+    Administrator,
+    /// The member's top role is not below the bot's top role.
+    RoleHierarchy,
+    /// The moderator does not have the `MODERATE_MEMBERS` permission.
+    MissingPermission,
+    /// The member was given without a user, so it is not known who it is.
+    MissingUser,
+}
+
+impl Display for ModerationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModerationError::TimeoutTooLong(duration) => write!(
+                f,
+                "a timeout of {} seconds is longer than the limit of 28 days",
+                duration.as_secs()
+            ),
+            ModerationError::Owner => f.write_str("the guild owner can not be moderated"),
+            ModerationError::Administrator => {
+                f.write_str("members with the administrator permission can not be moderated")
+            }
+            ModerationError::RoleHierarchy => {
+                f.write_str("the member's top role is not below the bot's top role")
+            }
+            ModerationError::MissingPermission => {
+                f.write_str("the moderator does not have the moderate members permission")
+            }
+            ModerationError::MissingUser => f.write_str("the member was given without a user"),
+        }
+    }
+}
+
+impl StdError for ModerationError {}
+
+/// A builder for adding a user to a guild with an OAuth2 access token, which has the `guilds.join` scope.
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#add-guild-member)
+#[derive(Debug, Clone, Serialize)]
+pub struct AddMember {
+    access_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    roles: Vec<Snowflake<Role>>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    mute: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deaf: bool,
+}
+
+impl AddMember {
+    /// Creates the builder from the user's access token.
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Self {
+            access_token: access_token.into(),
+            nick: None,
+            roles: Vec::new(),
+            mute: false,
+            deaf: false,
+        }
+    }
+    /// Sets the nickname of the member, this requires `MANAGE_NICKNAMES`.
+    pub fn nick(mut self, nick: impl Into<String>) -> Self {
+        self.nick = Some(nick.into());
+        self
+    }
+    /// Adds a role to the member, this requires `MANAGE_ROLES`.
+    pub fn role(mut self, role: Snowflake<Role>) -> Self {
+        self.roles.push(role);
+        self
+    }
+    /// Sets whether the member is muted in voice channels, this requires `MUTE_MEMBERS`.
+    pub fn mute(mut self, mute: bool) -> Self {
+        self.mute = mute;
+        self
+    }
+    /// Sets whether the member is deafened in voice channels, this requires `DEAFEN_MEMBERS`.
+    pub fn deaf(mut self, deaf: bool) -> Self {
+        self.deaf = deaf;
+        self
+    }
+}
+
+/// A builder for modifying a member. Only the fields that are set are changed.
+///
+/// ```rust
+/// use discors::models::guild::member::EditMember;
+///
+/// let edit = EditMember::new().nick(None).mute(true);
+/// assert_eq!(serde_json::to_string(&edit).unwrap(), r#"{"nick":null,"mute":true}"#);
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#modify-guild-member)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditMember {
+    // `Some(None)` is sent as null, which resets the field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roles: Option<Vec<Snowflake<Role>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mute: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deaf: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<Option<Snowflake<Channel>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    communication_disabled_until: Option<Option<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<GuildMemberFlags>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl EditMember {
+    /// Creates an edit which doesn't change anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the nickname of the member, `None` removes it. This requires `MANAGE_NICKNAMES`.
+    pub fn nick(mut self, nick: Option<String>) -> Self {
+        self.nick = Some(nick);
+        self
+    }
+    /// Replaces the roles of the member, this requires `MANAGE_ROLES`.
+    pub fn roles(mut self, roles: Vec<Snowflake<Role>>) -> Self {
+        self.roles = Some(roles);
+        self
+    }
+    /// Sets whether the member is muted in voice channels, this requires `MUTE_MEMBERS`.
+    pub fn mute(mut self, mute: bool) -> Self {
+        self.mute = Some(mute);
+        self
+    }
+    /// Sets whether the member is deafened in voice channels, this requires `DEAFEN_MEMBERS`.
+    pub fn deaf(mut self, deaf: bool) -> Self {
+        self.deaf = Some(deaf);
+        self
+    }
+    /// Moves the member to a voice channel, `None` disconnects them. This requires `MOVE_MEMBERS`.
+    pub fn move_to(mut self, channel: Option<Snowflake<Channel>>) -> Self {
+        self.channel_id = Some(channel);
+        self
+    }
+    /// Times the member out until a time, at most 28 days in the future, `None` removes the timeout. This requires `MODERATE_MEMBERS`.
+    pub fn timeout_until(mut self, until: Option<Timestamp>) -> Self {
+        self.communication_disabled_until = Some(until);
+        self
+    }
+    /// Sets the flags of the member, only [`GuildMemberFlags::BYPASSES_VERIFICATION`] can be changed.
+    pub fn flags(mut self, flags: GuildMemberFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{EditMember, GuildMemberFlags, Member};
+    use crate::{models::traits::BitFlag, timestamp::Timestamp};
+
+    const MEMBER: &str = r#"{
+        "user": {
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": "8342729096ea3675442027381ff50dfe"
+        },
+        "nick": null,
+        "avatar": null,
+        "roles": ["197038439483310086"],
+        "joined_at": "2015-04-26T06:26:56.936000+00:00",
+        "deaf": false,
+        "mute": false,
+        "flags": 3,
+        "communication_disabled_until": "2023-01-01T00:00:00.000000+00:00"
+    }"#;
+
+    #[test]
+    fn deserialize() {
+        let member = serde_json::from_str::<Member>(MEMBER).unwrap();
+        assert_eq!(member.display_name(), Some("Nelly"));
+        assert!(member.has_role(197038439483310086.into()));
+        assert!(member
+            .flags
+            .includes(GuildMemberFlags::COMPLETED_ONBOARDING));
+        assert_eq!(
+            member
+                .communication_disabled_until
+                .unwrap()
+                .to_unix_seconds(),
+            1672531200
+        );
+    }
+
+    #[test]
+    fn edit() {
+        let edit = EditMember::new()
+            .roles(vec![1.into()])
+            .move_to(None)
+            .timeout_until(None);
+        assert_eq!(
+            serde_json::to_string(&edit).unwrap(),
+            r#"{"roles":["1"],"channel_id":null,"communication_disabled_until":null}"#
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let mut member = serde_json::from_str::<Member>(MEMBER).unwrap();
+        // the timeout in the example is over
+        assert!(!member.is_timed_out());
+        member.communication_disabled_until = Some(Timestamp::now() + Duration::from_secs(60));
+        let remaining = member.timeout_remaining().unwrap();
+        assert!(remaining <= Duration::from_secs(60) && remaining > Duration::from_secs(50));
+        member.communication_disabled_until = None;
+        assert_eq!(member.timeout_remaining(), None);
+    }
+}
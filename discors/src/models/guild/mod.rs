@@ -0,0 +1,506 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bitflags, flags, int_enum,
+    locale::Locale,
+    models::{
+        application::Application, channel::Channel, emoji::CustomEmoji, sticker::Sticker,
+        traits::BitFlag, user::User,
+    },
+    permissions::Permissions,
+    snowflake::{Snowflakable, Snowflake},
+    string_enum,
+    timestamp::Timestamp,
+};
+
+use self::{
+    member::{Member, ModerationError},
+    role::Role,
+};
+
+pub mod audit_log;
+pub mod ban;
+pub mod member;
+pub mod role;
+
+/// A guild, which is known as a server on the client-side.
+///
+/// This is given by the REST api and in the `GUILD_CREATE` event, the fields at the end are only given in `GUILD_CREATE`.
+///
+/// [Read more](https://discord.com/developers/docs/resources/guild#guild-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Guild {
+    pub id: Snowflake<Guild>,
+    pub name: String,
+    pub icon: Option<String>, // icon hash
+    #[serde(default)]
+    pub splash: Option<String>, // splash hash
+    #[serde(default)]
+    pub discovery_splash: Option<String>, // discovery splash hash, only for discoverable guilds
+    pub owner_id: Snowflake<User>,
+    #[serde(default)]
+    pub afk_channel_id: Option<Snowflake<Channel>>,
+    // in seconds
+    pub afk_timeout: u32,
+    #[serde(default)]
+    pub widget_enabled: bool,
+    #[serde(default)]
+    pub widget_channel_id: Option<Snowflake<Channel>>,
+    pub verification_level: VerificationLevel,
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+    pub explicit_content_filter: ExplicitContentFilterLevel,
+    pub roles: Vec<Role>,
+    pub emojis: Vec<CustomEmoji>,
+    pub features: Vec<GuildFeature>,
+    pub mfa_level: MfaLevel,
+    // the application that created the guild, if it was created by a bot
+    #[serde(default)]
+    pub application_id: Option<Snowflake<Application>>,
+    // where welcome and boost messages are sent
+    #[serde(default)]
+    pub system_channel_id: Option<Snowflake<Channel>>,
+    pub system_channel_flags: SystemChannelFlags,
+    // only for community guilds
+    #[serde(default)]
+    pub rules_channel_id: Option<Snowflake<Channel>>,
+    #[serde(default)]
+    pub max_presences: Option<u32>,
+    #[serde(default)]
+    pub max_members: Option<u32>,
+    #[serde(default)]
+    pub vanity_url_code: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub banner: Option<String>, // banner hash
+    pub premium_tier: PremiumTier,
+    // the amount of boosts
+    #[serde(default)]
+    pub premium_subscription_count: Option<u32>,
+    // used for discovery and notices from discord, "en-US" by default
+    pub preferred_locale: Locale,
+    // where community guilds get notices from discord
+    #[serde(default)]
+    pub public_updates_channel_id: Option<Snowflake<Channel>>,
+    #[serde(default)]
+    pub max_video_channel_users: Option<u32>,
+    #[serde(default)]
+    pub max_stage_video_channel_users: Option<u32>,
+    // only given when fetched with `with_counts`
+    #[serde(default)]
+    pub approximate_member_count: Option<u32>,
+    #[serde(default)]
+    pub approximate_presence_count: Option<u32>,
+    pub nsfw_level: NsfwLevel,
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
+    #[serde(default)]
+    pub premium_progress_bar_enabled: bool,
+    #[serde(default)]
+    pub safety_alerts_channel_id: Option<Snowflake<Channel>>,
+    // when the bot joined the guild
+    #[serde(default)]
+    pub joined_at: Option<Timestamp>,
+    // whether the guild has more members than the large threshold, offline members are not sent for large guilds
+    #[serde(default)]
+    pub large: bool,
+    // whether the guild is unavailable because of an outage
+    #[serde(default)]
+    pub unavailable: bool,
+    #[serde(default)]
+    pub member_count: Option<u32>,
+    // only the bot and members in voice channels for large guilds, with the `GUILD_MEMBERS` intent all members
+    #[serde(default)]
+    pub members: Vec<Member>,
+    #[serde(default)]
+    pub channels: Vec<Channel>,
+    // the active threads the bot can see
+    #[serde(default)]
+    pub threads: Vec<Channel>,
+}
+
+impl Guild {
+    /// Gets a role of the guild by its id.
+    pub fn role(&self, id: Snowflake<Role>) -> Option<&Role> {
+        self.roles.iter().find(|r| r.id == id)
+    }
+    /// The `@everyone` role, which has the same id as the guild.
+    pub fn everyone_role(&self) -> Option<&Role> {
+        self.role(self.id.convert())
+    }
+    /// The permissions a member has from their roles, ignoring channel overwrites.
+    pub fn member_permissions(&self, member: &Member) -> Permissions {
+        if member.user.as_ref().map(|u| u.id) == Some(self.owner_id) {
+            return Permissions::ALL;
+        }
+        let mut permissions = self
+            .everyone_role()
+            .map_or(Permissions::EMPTY, |r| r.permissions);
+        for role in member.roles.iter().filter_map(|&r| self.role(r)) {
+            permissions |= role.permissions;
+        }
+        if permissions.includes(Permissions::ADMINISTRATOR) {
+            return Permissions::ALL;
+        }
+        permissions
+    }
+    /// The highest role of a member, roles with the same position are ordered by their ids.
+    pub fn top_role(&self, member: &Member) -> Option<&Role> {
+        member
+            .roles
+            .iter()
+            .filter_map(|&r| self.role(r))
+            .max_by_key(|r| (r.position, std::cmp::Reverse(r.id)))
+    }
+    /// Checks whether a moderator can moderate (like time out) another member.
+    ///
+    /// Discord refuses to moderate the owner and administrators, and members whose top role is not below the moderator's.
+    /// The moderator also needs the `MODERATE_MEMBERS` permission.
+    pub fn can_moderate(&self, moderator: &Member, target: &Member) -> Result<(), ModerationError> {
+        let target_id = target.user.as_ref().ok_or(ModerationError::MissingUser)?.id;
+        if target_id == self.owner_id {
+            return Err(ModerationError::Owner);
+        }
+        if self
+            .member_permissions(target)
+            .includes(Permissions::ADMINISTRATOR)
+        {
+            return Err(ModerationError::Administrator);
+        }
+        if moderator.user.as_ref().map(|u| u.id) == Some(self.owner_id) {
+            return Ok(());
+        }
+        if !self
+            .member_permissions(moderator)
+            .includes(Permissions::MODERATE_MEMBERS)
+        {
+            return Err(ModerationError::MissingPermission);
+        }
+        let position = |member| {
+            self.top_role(member)
+                .map(|r| (r.position, std::cmp::Reverse(r.id)))
+        };
+        if position(target) >= position(moderator) {
+            return Err(ModerationError::RoleHierarchy);
+        }
+        Ok(())
+    }
+    /// Checks whether the guild has a feature.
+    pub fn has_feature(&self, feature: &GuildFeature) -> bool {
+        self.features.contains(feature)
+    }
+}
+
+impl Snowflakable for Guild {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+/// A partial guild, given when listing the guilds of the current user.
+///
+/// [Read more](https://discord.com/developers/docs/resources/user#get-current-user-guilds)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartialGuild {
+    pub id: Snowflake<Guild>,
+    pub name: String,
+    pub icon: Option<String>, // icon hash
+    // whether the current user owns the guild
+    #[serde(default)]
+    pub owner: bool,
+    // the permissions of the current user in the guild
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub features: Vec<GuildFeature>,
+    #[serde(default)]
+    pub approximate_member_count: Option<u32>,
+    #[serde(default)]
+    pub approximate_presence_count: Option<u32>,
+}
+
+impl From<Guild> for PartialGuild {
+    fn from(guild: Guild) -> Self {
+        Self {
+            id: guild.id,
+            name: guild.name,
+            icon: guild.icon,
+            owner: false,
+            permissions: None,
+            features: guild.features,
+            approximate_member_count: guild.approximate_member_count,
+            approximate_presence_count: guild.approximate_presence_count,
+        }
+    }
+}
+
+string_enum! {
+    /// A feature a [`Guild`] has enabled.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/guild#guild-object-guild-features)
+    GuildFeature {
+        AnimatedBanner = "ANIMATED_BANNER",
+        AnimatedIcon = "ANIMATED_ICON",
+        ApplicationCommandPermissionsV2 = "APPLICATION_COMMAND_PERMISSIONS_V2",
+        AutoModeration = "AUTO_MODERATION",
+        Banner = "BANNER",
+        Community = "COMMUNITY",
+        CreatorMonetizableProvisional = "CREATOR_MONETIZABLE_PROVISIONAL",
+        CreatorStorePage = "CREATOR_STORE_PAGE",
+        DeveloperSupportServer = "DEVELOPER_SUPPORT_SERVER",
+        Discoverable = "DISCOVERABLE",
+        Featurable = "FEATURABLE",
+        InvitesDisabled = "INVITES_DISABLED",
+        InviteSplash = "INVITE_SPLASH",
+        MemberVerificationGateEnabled = "MEMBER_VERIFICATION_GATE_ENABLED",
+        MoreStickers = "MORE_STICKERS",
+        News = "NEWS",
+        Partnered = "PARTNERED",
+        PreviewEnabled = "PREVIEW_ENABLED",
+        RaidAlertsDisabled = "RAID_ALERTS_DISABLED",
+        RoleIcons = "ROLE_ICONS",
+        RoleSubscriptionsAvailableForPurchase = "ROLE_SUBSCRIPTIONS_AVAILABLE_FOR_PURCHASE",
+        RoleSubscriptionsEnabled = "ROLE_SUBSCRIPTIONS_ENABLED",
+        TicketedEventsEnabled = "TICKETED_EVENTS_ENABLED",
+        VanityUrl = "VANITY_URL",
+        Verified = "VERIFIED",
+        VipRegions = "VIP_REGIONS",
+        WelcomeScreenEnabled = "WELCOME_SCREEN_ENABLED",
+    }
+}
+
+int_enum! {
+    /// What members need before they can talk in a [`Guild`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/guild#guild-object-verification-level)
+    VerificationLevel: u8 {
+        /// Unrestricted.
+        None = 0,
+        /// A verified email.
+        Low = 1,
+        /// Registered on discord for longer than 5 minutes.
+        Medium = 2,
+        /// A member of the guild for longer than 10 minutes.
+        High = 3,
+        /// A verified phone number.
+        VeryHigh = 4,
+    }
+}
+
+int_enum! {
+    /// Which messages members are notified for by default.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/guild#guild-object-default-message-notification-level)
+    DefaultMessageNotificationLevel: u8 {
+        AllMessages = 0,
+        OnlyMentions = 1,
+    }
+}
+
+int_enum! {
+    /// Whose messages are scanned for explicit media.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/guild#guild-object-explicit-content-filter-level)
+    ExplicitContentFilterLevel: u8 {
+        Disabled = 0,
+        MembersWithoutRoles = 1,
+        AllMembers = 2,
+    }
+}
+
+int_enum! {
+    /// Whether moderators need two factor authentication for moderation actions.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/guild#guild-object-mfa-level)
+    MfaLevel: u8 {
+        None = 0,
+        Elevated = 1,
+    }
+}
+
+int_enum! {
+    /// The age rating of a [`Guild`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/guild#guild-object-guild-nsfw-level)
+    NsfwLevel: u8 {
+        Default = 0,
+        Explicit = 1,
+        Safe = 2,
+        AgeRestricted = 3,
+    }
+}
+
+int_enum! {
+    /// The boost level of a [`Guild`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/guild#guild-object-premium-tier)
+    PremiumTier: u8 {
+        None = 0,
+        Tier1 = 1,
+        Tier2 = 2,
+        Tier3 = 3,
+    }
+}
+
+bitflags!("The flags of a [`Guild`]'s system channel, which turn off the messages sent there.", "", "[Read more](https://discord.com/developers/docs/resources/guild#guild-object-system-channel-flags)"; SystemChannelFlags: u32: "crate::utilities::serde::deserialize_u32", "crate::utilities::serde::serialize_u32");
+
+flags! {
+    SystemChannelFlags:
+    SUPPRESS_JOIN_NOTIFICATIONS = 0; "Turns off member join messages.",
+    SUPPRESS_PREMIUM_SUBSCRIPTIONS = 1; "Turns off boost messages.",
+    SUPPRESS_GUILD_REMINDER_NOTIFICATIONS = 2; "Turns off setup tips.",
+    SUPPRESS_JOIN_NOTIFICATION_REPLIES = 3; "Hides the sticker reply buttons on join messages.",
+    SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATIONS = 4; "Turns off role subscription purchase messages.",
+    SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATION_REPLIES = 5; "Hides the sticker reply buttons on role subscription purchase messages.",
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        member::{Member, ModerationError},
+        Guild, GuildFeature, PartialGuild, SystemChannelFlags, VerificationLevel,
+    };
+    use crate::{
+        color::Color,
+        locale::Locale,
+        models::{guild::role::Role, traits::BitFlag},
+        permissions::Permissions,
+    };
+
+    const GUILD: &str = r#"{
+        "id": "197038439483310086",
+        "name": "Discord Testers",
+        "icon": "f64c482b807da4f539cff778d174971c",
+        "description": "The official place to report Discord Bugs!",
+        "splash": null,
+        "discovery_splash": null,
+        "features": ["ANIMATED_ICON", "VERIFIED", "NEWS", "SOME_NEW_FEATURE"],
+        "emojis": [],
+        "banner": "9b6439a7de04f1d26af92f84ac9e1e4a",
+        "owner_id": "73193882359173120",
+        "application_id": null,
+        "region": null,
+        "afk_channel_id": null,
+        "afk_timeout": 300,
+        "system_channel_id": null,
+        "widget_enabled": true,
+        "widget_channel_id": null,
+        "verification_level": 3,
+        "roles": [{
+            "id": "197038439483310086",
+            "name": "@everyone",
+            "color": 0,
+            "hoist": false,
+            "position": 0,
+            "permissions": "104324673",
+            "managed": false,
+            "mentionable": false
+        }],
+        "default_message_notifications": 1,
+        "mfa_level": 1,
+        "explicit_content_filter": 2,
+        "max_presences": 40000,
+        "max_members": 250000,
+        "vanity_url_code": "discord-testers",
+        "premium_tier": 3,
+        "premium_subscription_count": 33,
+        "system_channel_flags": 5,
+        "preferred_locale": "en-US",
+        "rules_channel_id": "441688182833020939",
+        "public_updates_channel_id": "281283303326089216",
+        "nsfw_level": 0,
+        "joined_at": "2016-06-30T12:15:44.305000+00:00",
+        "large": true,
+        "member_count": 250000
+    }"#;
+
+    #[test]
+    fn deserialize() {
+        let guild = serde_json::from_str::<Guild>(GUILD).unwrap();
+        assert_eq!(guild.id, 197038439483310086.into());
+        assert_eq!(guild.verification_level, VerificationLevel::High);
+        assert_eq!(guild.preferred_locale, Locale::EnglishUS);
+        assert!(guild.has_feature(&GuildFeature::Verified));
+        assert_eq!(
+            guild.features[3],
+            GuildFeature::Unknown("SOME_NEW_FEATURE".to_string())
+        );
+        assert!(guild.system_channel_flags.includes(
+            SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS
+                | SystemChannelFlags::SUPPRESS_GUILD_REMINDER_NOTIFICATIONS
+        ));
+        assert_eq!(guild.everyone_role().unwrap().name, "@everyone");
+        assert!(guild.large);
+        assert!(guild.channels.is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        let guild = serde_json::from_str::<Guild>(GUILD).unwrap();
+        let json = serde_json::to_string(&guild).unwrap();
+        assert_eq!(serde_json::from_str::<Guild>(&json).unwrap(), guild);
+        assert_eq!(
+            serde_json::to_string(&GuildFeature::Unknown("NEW".to_string())).unwrap(),
+            r#""NEW""#
+        );
+        let partial = PartialGuild::from(guild);
+        assert_eq!(partial.name, "Discord Testers");
+    }
+
+    fn member(id: u64, roles: &[u64]) -> Member {
+        serde_json::from_value(serde_json::json!({
+            "user": {"id": id.to_string(), "username": "a", "discriminator": "0", "avatar": null},
+            "roles": roles.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            "joined_at": "2015-04-26T06:26:56.936000+00:00"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn moderation() {
+        let mut guild = serde_json::from_str::<Guild>(GUILD).unwrap();
+        let role = |id: u64, position, permissions| Role {
+            id: id.into(),
+            name: String::new(),
+            color: Color(0),
+            hoist: false,
+            icon: None,
+            unicode_emoji: None,
+            position,
+            permissions,
+            managed: false,
+            mentionable: false,
+        };
+        guild.roles.push(role(1, 1, Permissions::MODERATE_MEMBERS));
+        guild.roles.push(role(2, 2, Permissions::MODERATE_MEMBERS));
+        guild.roles.push(role(3, 1, Permissions::ADMINISTRATOR));
+        let (bot, user) = (member(10, &[2]), member(11, &[1]));
+        assert_eq!(guild.top_role(&bot).unwrap().id, 2.into());
+        assert!(guild
+            .member_permissions(&bot)
+            .includes(Permissions::MODERATE_MEMBERS));
+        assert_eq!(guild.can_moderate(&bot, &user), Ok(()));
+        assert_eq!(
+            guild.can_moderate(&user, &bot),
+            Err(ModerationError::RoleHierarchy)
+        );
+        assert_eq!(
+            guild.can_moderate(&user, &member(12, &[1])),
+            Err(ModerationError::RoleHierarchy)
+        );
+        assert_eq!(
+            guild.can_moderate(&bot, &member(12, &[3])),
+            Err(ModerationError::Administrator)
+        );
+        assert_eq!(
+            guild.can_moderate(&bot, &member(73193882359173120, &[])),
+            Err(ModerationError::Owner)
+        );
+        // a higher role is not enough without the permission
+        guild.roles.push(role(4, 3, Permissions::EMPTY));
+        assert_eq!(
+            guild.can_moderate(&member(13, &[4]), &user),
+            Err(ModerationError::MissingPermission)
+        );
+    }
+}
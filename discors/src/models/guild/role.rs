@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    color::Color,
+    mention::Mention,
+    models::traits::Mentionable,
+    permissions::Permissions,
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// A role in a guild, which grants its members a set of [`Permissions`].
+///
+/// [Read more](https://discord.com/developers/docs/topics/permissions#role-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Role {
+    pub id: Snowflake<Role>,
+    pub name: String,
+    pub color: Color,
+    // whether the role is displayed seperately in the member list.
+    pub hoist: bool,
+    #[serde(default)]
+    pub icon: Option<String>, // icon hash
+    #[serde(default)]
+    pub unicode_emoji: Option<String>,
+    pub position: i32,
+    pub permissions: Permissions,
+    // whether this role is managed by an integration (e.g. a bot's role)
+    pub managed: bool,
+    pub mentionable: bool,
+}
+
+impl Snowflakable for Role {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+impl Mentionable for Role {
+    fn mention(&self) -> Mention {
+        self.id.mention()
+    }
+}
+
+impl Mentionable for Snowflake<Role> {
+    fn mention(&self) -> Mention {
+        Mention::Role(*self)
+    }
+}
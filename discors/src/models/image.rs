@@ -0,0 +1,89 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Serialize;
+
+/// Image data to upload, like an emoji or a guild icon, which discord takes as a data URI.
+///
+/// ```rust
+/// use discors::models::image::ImageData;
+///
+/// let image = ImageData::from_bytes(b"GIF89a").unwrap();
+/// assert_eq!(image.as_str(), "data:image/gif;base64,R0lGODlh");
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/reference#image-data)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImageData(String);
+
+impl ImageData {
+    /// Builds the data URI from an image, the type of the image (png, jpeg, gif or webp) is found from its contents.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ImageError> {
+        let content_type = if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            "image/png"
+        } else if bytes.starts_with(b"\xff\xd8\xff") {
+            "image/jpeg"
+        } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            "image/gif"
+        } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            "image/webp"
+        } else {
+            return Err(ImageError::UnknownFormat);
+        };
+        Ok(Self::with_content_type(bytes, content_type))
+    }
+    /// Builds the data URI from an image with a known media type, like `image/png`.
+    pub fn with_content_type(bytes: &[u8], content_type: &str) -> Self {
+        Self(format!(
+            "data:{};base64,{}",
+            content_type,
+            STANDARD.encode(bytes)
+        ))
+    }
+    /// The data URI.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The error given when the type of an image could not be found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ImageError {
+    /// The image is not a png, jpeg, gif or webp.
+    UnknownFormat,
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::UnknownFormat => f.write_str("the image is not a png, jpeg, gif or webp"),
+        }
+    }
+}
+
+impl StdError for ImageError {}
+
+#[cfg(test)]
+mod test {
+    use super::{ImageData, ImageError};
+
+    #[test]
+    fn from_bytes() {
+        assert!(ImageData::from_bytes(b"\x89PNG\r\n\x1a\n")
+            .unwrap()
+            .as_str()
+            .starts_with("data:image/png;base64,"));
+        assert!(ImageData::from_bytes(b"RIFF\0\0\0\0WEBPVP8 ")
+            .unwrap()
+            .as_str()
+            .starts_with("data:image/webp;base64,"));
+        assert_eq!(
+            ImageData::from_bytes(b"hello"),
+            Err(ImageError::UnknownFormat)
+        );
+    }
+}
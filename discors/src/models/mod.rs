@@ -1,3 +1,9 @@
+pub mod application;
 pub mod channel;
+pub mod emoji;
+pub mod event;
+pub mod guild;
+pub mod image;
+pub mod sticker;
 pub mod traits;
 pub mod user;
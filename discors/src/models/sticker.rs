@@ -0,0 +1,324 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    int_enum,
+    models::{channel::attachment::AttachmentFile, guild::Guild, user::User},
+    snowflake::{Snowflakable, Snowflake},
+};
+
+/// A sticker that can be sent in messages.
+///
+/// [Read more](https://discord.com/developers/docs/resources/sticker#sticker-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sticker {
+    pub id: Snowflake<Sticker>,
+    // the pack of a standard sticker
+    #[serde(default)]
+    pub pack_id: Option<Snowflake<StickerPack>>,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    // autocomplete and suggestion tags, seperated by commas
+    #[serde(default)]
+    pub tags: String,
+    #[serde(rename = "type")]
+    pub kind: StickerType,
+    pub format_type: StickerFormat,
+    // stickers can be unavailable when the guild loses boosts
+    #[serde(default = "default_true")]
+    pub available: bool,
+    #[serde(default)]
+    pub guild_id: Option<Snowflake<Guild>>,
+    // who uploaded the sticker, only given with `MANAGE_GUILD_EXPRESSIONS`
+    #[serde(default)]
+    pub user: Option<User>,
+    // the order of a standard sticker in its pack
+    #[serde(default)]
+    pub sort_value: Option<u32>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Sticker {
+    /// The URL of the sticker's image, lottie stickers are json.
+    pub fn url(&self) -> String {
+        let extension = match self.format_type {
+            StickerFormat::Lottie => "json",
+            StickerFormat::Gif => "gif",
+            _ => "png",
+        };
+        format!(
+            "https://media.discordapp.net/stickers/{}.{}",
+            self.id, extension
+        )
+    }
+}
+
+impl Snowflakable for Sticker {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+/// A pack of standard stickers.
+///
+/// [Read more](https://discord.com/developers/docs/resources/sticker#sticker-pack-object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StickerPack {
+    pub id: Snowflake<StickerPack>,
+    pub stickers: Vec<Sticker>,
+    pub name: String,
+    pub description: String,
+}
+
+impl Snowflakable for StickerPack {
+    fn id(&self) -> Snowflake<Self> {
+        self.id
+    }
+}
+
+int_enum! {
+    /// Where a [`Sticker`] comes from.
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types)
+    StickerType: u8 {
+        /// A sticker in a pack, which everyone can use.
+        Standard = 1,
+        /// A sticker uploaded to a guild.
+        Guild = 2,
+    }
+}
+
+int_enum! {
+    /// The image format of a [`Sticker`].
+    ///
+    /// [Read more](https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types)
+    StickerFormat: u8 {
+        Png = 1,
+        Apng = 2,
+        Lottie = 3,
+        Gif = 4,
+    }
+}
+
+/// The limits on uploading stickers.
+pub mod sticker_limits {
+    use std::ops::RangeInclusive;
+
+    /// How many characters a sticker name can have.
+    pub const NAME: RangeInclusive<usize> = 2..=30;
+    /// How many characters a sticker description can have, it can also be empty.
+    pub const DESCRIPTION: RangeInclusive<usize> = 2..=100;
+    /// The most characters the tags can have.
+    pub const TAGS: usize = 200;
+}
+
+/// A builder for uploading a sticker to a guild, this requires `MANAGE_GUILD_EXPRESSIONS`.
+///
+/// The file is a png, apng, gif or lottie json file of at most 512 KiB.
+///
+/// ```rust
+/// use discors::models::{channel::attachment::AttachmentFile, sticker::CreateSticker};
+///
+/// let sticker = CreateSticker::new("crab", "🦀", AttachmentFile::from_path("crab.png"))
+///     .description("a crab");
+/// assert!(sticker.validate().is_ok());
+/// ```
+///
+/// [Read more](https://discord.com/developers/docs/resources/sticker#create-guild-sticker)
+#[derive(Debug, Clone)]
+pub struct CreateSticker {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) tags: String,
+    pub(crate) file: AttachmentFile,
+    pub(crate) reason: Option<String>,
+}
+
+impl CreateSticker {
+    /// Creates the sticker from its name, tags (the name of a related unicode emoji) and file.
+    pub fn new(name: impl Into<String>, tags: impl Into<String>, file: AttachmentFile) -> Self {
+        Self {
+            name: name.into(),
+            description: String::new(),
+            tags: tags.into(),
+            file,
+            reason: None,
+        }
+    }
+    /// Sets the description of the sticker.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+    /// Checks the sticker is within discord's limits.
+    pub fn validate(&self) -> Result<(), StickerError> {
+        validate(Some(&self.name), Some(&self.description), Some(&self.tags))
+    }
+}
+
+/// A builder for modifying a guild sticker. Only the fields that are set are changed.
+///
+/// [Read more](https://discord.com/developers/docs/resources/sticker#modify-guild-sticker)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditSticker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<String>,
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl EditSticker {
+    /// Creates an edit which doesn't change anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Renames the sticker.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    /// Sets the description, an empty string removes it.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+    /// Sets the tags of the sticker.
+    pub fn tags(mut self, tags: impl Into<String>) -> Self {
+        self.tags = Some(tags.into());
+        self
+    }
+    /// Sets the reason shown in the audit log.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+    /// Checks the edit is within discord's limits.
+    pub fn validate(&self) -> Result<(), StickerError> {
+        validate(
+            self.name.as_deref(),
+            self.description.as_deref(),
+            self.tags.as_deref(),
+        )
+    }
+}
+
+fn validate(
+    name: Option<&str>,
+    description: Option<&str>,
+    tags: Option<&str>,
+) -> Result<(), StickerError> {
+    if let Some(name) = name {
+        let len = name.chars().count();
+        if !sticker_limits::NAME.contains(&len) {
+            return Err(StickerError::Name(len));
+        }
+    }
+    if let Some(description) = description {
+        let len = description.chars().count();
+        if len != 0 && !sticker_limits::DESCRIPTION.contains(&len) {
+            return Err(StickerError::Description(len));
+        }
+    }
+    if let Some(tags) = tags {
+        let len = tags.chars().count();
+        if len > sticker_limits::TAGS {
+            return Err(StickerError::Tags(len));
+        }
+    }
+    Ok(())
+}
+
+/// The error given when a sticker is outside of discord's limits, with the length that was given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StickerError {
+    Name(usize),
+    Description(usize),
+    Tags(usize),
+}
+
+impl Display for StickerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StickerError::Name(len) => write!(
+                f,
+                "sticker name is {} characters long, it must be 2 to 30",
+                len
+            ),
+            StickerError::Description(len) => write!(
+                f,
+                "sticker description is {} characters long, it must be empty or 2 to 100",
+                len
+            ),
+            StickerError::Tags(len) => write!(
+                f,
+                "sticker tags are {} characters long, the limit is {}",
+                len,
+                sticker_limits::TAGS
+            ),
+        }
+    }
+}
+
+impl StdError for StickerError {}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateSticker, EditSticker, Sticker, StickerError, StickerFormat, StickerType};
+    use crate::models::channel::attachment::AttachmentFile;
+
+    #[test]
+    fn deserialize() {
+        let sticker = serde_json::from_str::<Sticker>(
+            r#"{
+                "id": "749054660769218631",
+                "name": "Wave",
+                "tags": "wumpus, hello, sup, hi, oi, heyo, heya, yo, wave",
+                "type": 1,
+                "format_type": 3,
+                "description": "Wumpus waves hello",
+                "asset": "",
+                "pack_id": "847199849233514549",
+                "sort_value": 12
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(sticker.kind, StickerType::Standard);
+        assert_eq!(sticker.format_type, StickerFormat::Lottie);
+        assert!(sticker.available);
+        assert!(sticker.url().ends_with("749054660769218631.json"));
+    }
+
+    #[test]
+    fn validate() {
+        let file = AttachmentFile::from_bytes("a.png", "a");
+        assert_eq!(
+            CreateSticker::new("a", "🦀", file.clone()).validate(),
+            Err(StickerError::Name(1))
+        );
+        assert_eq!(
+            CreateSticker::new("ab", "🦀", file)
+                .description("a")
+                .validate(),
+            Err(StickerError::Description(1))
+        );
+        assert!(EditSticker::new().description("").validate().is_ok());
+    }
+}
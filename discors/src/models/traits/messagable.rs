@@ -1,6 +1,73 @@
 use async_trait::async_trait;
 
+use crate::{
+    error::Error,
+    http::Http,
+    models::{
+        channel::{
+            message::{CreateMessage, Message},
+            Channel,
+        },
+        user::User,
+    },
+    snowflake::Snowflake,
+};
+
+/// Something messages can be sent to, like a channel or a user.
+///
+/// ```rust,no_run
+/// # async fn run(http: discors::http::Http, channel: discors::models::channel::Channel) -> Result<(), discors::error::Error> {
+/// use discors::models::{channel::message::CreateMessage, traits::messagable::Messagable};
+///
+/// channel.send(&http, CreateMessage::new().content("Hello!")).await?;
+/// # Ok(())
+/// # }
+/// ```
 #[async_trait]
 pub trait Messagable {
-    async fn send();
+    /// Sends a message, giving the message that was created.
+    async fn send(&self, http: &Http, message: CreateMessage) -> Result<Message, Error>;
+
+    /// Sends a message with only text.
+    async fn say(&self, http: &Http, content: &str) -> Result<Message, Error> {
+        self.send(http, CreateMessage::new().content(content)).await
+    }
+}
+
+#[async_trait]
+impl Messagable for Snowflake<Channel> {
+    async fn send(&self, http: &Http, message: CreateMessage) -> Result<Message, Error> {
+        http.create_message(*self, &message).await
+    }
+}
+
+#[async_trait]
+impl Messagable for Channel {
+    async fn send(&self, http: &Http, message: CreateMessage) -> Result<Message, Error> {
+        self.id.send(http, message).await
+    }
+}
+
+/// Sends a direct message, opening a direct message channel with the user first.
+#[async_trait]
+impl Messagable for Snowflake<User> {
+    async fn send(&self, http: &Http, message: CreateMessage) -> Result<Message, Error> {
+        http.create_dm(*self).await?.send(http, message).await
+    }
+}
+
+/// Sends a direct message, opening a direct message channel with the user first.
+#[async_trait]
+impl Messagable for User {
+    async fn send(&self, http: &Http, message: CreateMessage) -> Result<Message, Error> {
+        self.id.send(http, message).await
+    }
+}
+
+/// Replies to the message.
+#[async_trait]
+impl Messagable for Message {
+    async fn send(&self, http: &Http, message: CreateMessage) -> Result<Message, Error> {
+        self.channel_id.send(http, message.reference(self)).await
+    }
 }
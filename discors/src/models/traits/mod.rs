@@ -3,6 +3,8 @@ use std::{
     ops::{BitAnd, BitOr, BitXor, Not},
 };
 
+use crate::mention::Mention;
+
 pub mod messagable;
 
 /// The bit flag trait contains methods which can be used on bitflags objects, such as gateway intents, permissions, etc.
@@ -12,3 +14,17 @@ pub trait BitFlag: PartialEq + Copy + Debug + BitOr + BitAnd + BitXor + Not {
     /// Checks if a set of bitflags includes at least one of the other set. (Note: 0 includes 0)
     fn includes_any(self, other: Self) -> bool;
 }
+
+/// Something that can be mentioned in a message, like a user, channel or role.
+pub trait Mentionable {
+    /// Gives the [`Mention`] of this object, which formats into the mention syntax discord uses.
+    ///
+    /// ```rust
+    /// use discors::{models::{traits::Mentionable, user::User}, Snowflake};
+    ///
+    /// let user = Snowflake::<User>::new(445556389532925952);
+    ///
+    /// assert_eq!(user.mention().to_string(), "<@445556389532925952>");
+    /// ```
+    fn mention(&self) -> Mention;
+}
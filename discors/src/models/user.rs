@@ -1,31 +1,53 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{
     bitflags,
     color::Color,
+    int_enum,
     locale::Locale,
+    mention::Mention,
+    models::traits::Mentionable,
     optional_default,
     snowflake::{Snowflakable, Snowflake},
 };
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: Snowflake<User>,
-    // rename username
+    #[serde(rename = "username")]
     pub name: String,
-    // Shown as username#discriminator in app.
+    // Shown as username#discriminator in app. Users that have moved to unique usernames have a discriminator of 0.
+    #[serde(
+        deserialize_with = "crate::utilities::serde::deserialize_discriminator",
+        serialize_with = "crate::utilities::serde::serialize_discriminator"
+    )]
     pub discriminator: u16,
+    // the display name, if it is different to the username
+    #[serde(default)]
+    pub global_name: Option<String>,
     pub avatar: Option<String>, // avatar hash
+    #[serde(default)]
     pub bot: bool,
+    #[serde(default)]
     pub system: bool,
+    #[serde(default)]
     pub mfa_enabled: bool,
+    #[serde(default)]
     pub banner: Option<String>,
+    #[serde(default)]
     pub accent_color: Option<Color>,
-    pub locale: Locale,
-    // rename verified
+    // only given with the identify OAuth2 scope
+    #[serde(default)]
+    pub locale: Option<Locale>,
+    #[serde(default, rename = "verified")]
     pub email_verified: bool,
+    #[serde(default)]
     pub email: Option<String>,
+    #[serde(default, alias = "public_flags")]
     pub flags: Userflags,
-    // rename premium_type
-    pub nitro_subscription: NitroSubscription,
+    // only given with the identify OAuth2 scope
+    #[serde(default, rename = "premium_type")]
+    pub nitro_subscription: Option<NitroSubscription>,
 }
 
 bitflags!("The userflags for a [`User`] has. On the client-side, this refers to the badges.", "", "[Read more](https://discord.com/developers/docs/resources/user#user-object-user-flags)"; Userflags: u32: "crate::utilities::serde::deserialize_u32", "crate::utilities::serde::serialize_u32");
@@ -74,15 +96,37 @@ userflags! {
     BOT_HTTP_INTERACTIONS = 19,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub enum NitroSubscription {
-    None = 0,
-    Classic = 1,
-    Nitro = 2,
+int_enum! {
+    /// The type of nitro subscription a [`User`] has.
+    NitroSubscription: u8 {
+        None = 0,
+        Classic = 1,
+        Nitro = 2,
+        Basic = 3,
+    }
+}
+
+impl Default for Userflags {
+    /// Gives an empty set of userflags.
+    fn default() -> Self {
+        Userflags::EMPTY
+    }
 }
 
 impl Snowflakable for User {
     fn id(&self) -> Snowflake<Self> {
-        self.id.clone()
+        self.id
+    }
+}
+
+impl Mentionable for User {
+    fn mention(&self) -> Mention {
+        self.id.mention()
+    }
+}
+
+impl Mentionable for Snowflake<User> {
+    fn mention(&self) -> Mention {
+        Mention::User(*self)
     }
 }
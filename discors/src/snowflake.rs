@@ -1,16 +1,36 @@
-use std::{cmp::Ordering, fmt::Display, marker::PhantomData, str::FromStr};
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
 
-use crate::timestamp::Timestamp;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{timestamp::Timestamp, utilities::serde::deserialize_u64};
 
 /// A discord snowflake, represents any ID on discord.
 /// These IDs are guaranteed to be unique across all of Discord, except in some unique scenarios in which child objects share their parent's ID.
 /// Intern they are stored in 64 bit integers, the (de-) serialization of which is done internally.
-///
-#[derive(Debug, Clone, Copy)]
+/// They are ordered by the time they were created at.
+#[derive(Debug)]
 pub struct Snowflake<S>(u64, PhantomData<S>)
 where
     S: Snowflakable;
 
+// derives would require `S` to be Clone and Copy, which the snowflakables themselves are not.
+impl<T> Clone for Snowflake<T>
+where
+    T: Snowflakable,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Snowflake<T> where T: Snowflakable {}
+
 impl<T> From<u64> for Snowflake<T>
 where
     T: Snowflakable,
@@ -70,6 +90,10 @@ where
         // https://discord.com/developers/docs/reference#snowflakes-snowflake-id-format-structure-left-to-right
         Timestamp::new((self.0 >> 22) + 1420070400000)
     }
+    /// The smallest snowflake created at a time, useful as a bound when paginating by time.
+    pub const fn from_time(time: Timestamp) -> Self {
+        Self::new(time.to_unix_millis().saturating_sub(1420070400000) << 22)
+    }
     /// Converts the given snowflake into a new type. The generic type parameter `U` specifies the snowflakable it should be of afterwards.
     pub fn convert<U>(&self) -> Snowflake<U>
     where
@@ -88,18 +112,57 @@ where
     }
 }
 
+impl<T> Eq for Snowflake<T> where T: Snowflakable {}
+
 impl<T> PartialOrd for Snowflake<T>
 where
     T: Snowflakable,
 {
-    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
-        if self > rhs {
-            Some(Ordering::Greater)
-        } else if self == rhs {
-            Some(Ordering::Equal)
-        } else {
-            Some(Ordering::Less)
-        }
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<T> Ord for Snowflake<T>
+where
+    T: Snowflakable,
+{
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.cmp(&rhs.0)
+    }
+}
+
+impl<T> Hash for Snowflake<T>
+where
+    T: Snowflakable,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T> Serialize for Snowflake<T>
+where
+    T: Snowflakable,
+{
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // discord always sends snowflakes as strings, as they do not fit in a javascript number
+        s.collect_str(&self.0)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Snowflake<T>
+where
+    T: Snowflakable,
+{
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_u64(d).map(Snowflake::new)
     }
 }
 
@@ -110,6 +173,16 @@ pub trait Snowflakable {
         Self: Sized;
 }
 
+/// A snowflake which can be the id of more than one kind of object, [`Snowflake::convert`] gives it its type.
+#[derive(Debug)]
+pub enum Generic {}
+
+impl Snowflakable for Generic {
+    fn id(&self) -> Snowflake<Self> {
+        match *self {}
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
@@ -138,6 +211,9 @@ mod test {
         assert_eq!(Timestamp::new(1526299321302), MY_SNOWFLAKE.into());
         assert_eq!(Timestamp::new(1420070400000), ANOTHER_SNOWFLAKE.into());
         assert_eq!(Timestamp::new(5818116911103), LAST_SNOWFLAKE.to_time());
+        let earliest = Snowflake::<User>::from_time(MY_SNOWFLAKE.to_time());
+        assert!(earliest <= MY_SNOWFLAKE);
+        assert_eq!(earliest.to_time(), MY_SNOWFLAKE.to_time());
     }
 
     #[test]
@@ -163,4 +239,27 @@ mod test {
         assert_eq!(format!("{}", LAST_SNOWFLAKE), u64::MAX.to_string());
         assert_eq!(format!("{:#}", ANOTHER_SNOWFLAKE), "0000000000000000000");
     }
+
+    #[test]
+    fn ordering() {
+        assert!(ANOTHER_SNOWFLAKE < MY_SNOWFLAKE);
+        assert!(LAST_SNOWFLAKE > MY_SNOWFLAKE);
+        assert_eq!(MY_SNOWFLAKE.max(LAST_SNOWFLAKE), LAST_SNOWFLAKE);
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(
+            serde_json::to_string(&MY_SNOWFLAKE).unwrap(),
+            "\"445556389532925952\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Snowflake<User>>("\"445556389532925952\"").unwrap(),
+            MY_SNOWFLAKE
+        );
+        assert_eq!(
+            serde_json::from_str::<Snowflake<User>>("0").unwrap(),
+            ANOTHER_SNOWFLAKE
+        );
+    }
 }
@@ -1,8 +1,20 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display},
+    ops::Add,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::snowflake::{Snowflakable, Snowflake};
 
 /// A timestamp, representes a point in time, represented by a positive number of milliseconds since Janurary 1st 1970 UTC.
 /// This is known as "unix milliseconds".
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+///
+/// Discord sends these as ISO8601 strings, so that is what this (de-) serializes as, and what it displays and parses from.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Timestamp(u64); // an amount of unix miliseconds seconds
 
 impl Timestamp {
@@ -14,6 +26,15 @@ impl Timestamp {
     pub const fn from_unix_secs(s: u64) -> Self {
         Self(s * 1000)
     }
+    /// Construct a timestamp of the current time.
+    pub fn now() -> Self {
+        // the clock can only be before 1970 if it is very wrong
+        Self(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as u64),
+        )
+    }
     /// Retrive a timestamp's unix milliseconds
     pub const fn to_unix_millis(&self) -> u64 {
         self.0
@@ -22,6 +43,28 @@ impl Timestamp {
     pub const fn to_unix_seconds(&self) -> u64 {
         self.0 / 1000
     }
+    /// How long it is until this timestamp, or `None` if it is in the past.
+    pub fn until(&self) -> Option<Duration> {
+        self.0
+            .checked_sub(Self::now().0)
+            .filter(|&millis| millis > 0)
+            .map(Duration::from_millis)
+    }
+    /// Adds a duration to this timestamp, or `None` if the result does not fit.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        u64::try_from(duration.as_millis())
+            .ok()
+            .and_then(|millis| self.0.checked_add(millis))
+            .map(Self)
+    }
+}
+
+// saturates at the latest timestamp instead of overflowing, use `checked_add` to tell
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+    fn add(self, duration: Duration) -> Self::Output {
+        self.checked_add(duration).unwrap_or(Self(u64::MAX))
+    }
 }
 
 impl<T> From<Snowflake<T>> for Timestamp
@@ -32,3 +75,262 @@ where
         s.to_time()
     }
 }
+
+impl Display for Timestamp {
+    /// Formats the timestamp as ISO8601, the way discord does.
+    ///
+    /// ```rust
+    /// use discors::timestamp::Timestamp;
+    ///
+    /// assert_eq!(Timestamp::new(1526299321302).to_string(), "2018-05-14T12:02:01.302+00:00");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0 / 1000;
+        let (year, month, day) = civil_from_days((secs / 86400) as i64);
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}+00:00",
+            year,
+            month,
+            day,
+            secs % 86400 / 3600,
+            secs % 3600 / 60,
+            secs % 60,
+            self.0 % 1000
+        )
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ParseTimestampError;
+    /// Parses an ISO8601 timestamp, like `2018-05-14T12:02:01.302000+00:00`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |s: &str| -> Result<i64, ParseTimestampError> {
+            if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseTimestampError);
+            }
+            s.parse().map_err(|_| ParseTimestampError)
+        };
+        let part = |range: std::ops::Range<usize>| s.get(range).ok_or(ParseTimestampError);
+        if part(4..5)? != "-" || part(7..8)? != "-" || !matches!(part(10..11)?, "T" | " ") {
+            return Err(ParseTimestampError);
+        }
+        if part(13..14)? != ":" || part(16..17)? != ":" {
+            return Err(ParseTimestampError);
+        }
+        let (year, month, day) = (
+            number(part(0..4)?)?,
+            number(part(5..7)?)?,
+            number(part(8..10)?)?,
+        );
+        let (hour, minute, second) = (
+            number(part(11..13)?)?,
+            number(part(14..16)?)?,
+            number(part(17..19)?)?,
+        );
+        if !(1..=12).contains(&month)
+            || !(1..=31).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 60
+        {
+            return Err(ParseTimestampError);
+        }
+        let mut rest = part(19..s.len())?;
+        let mut millis = 0;
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let digits = fraction
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(fraction.len());
+            // only the first three digits matter for milliseconds
+            let padded = format!("{:0<3}", &fraction[..digits.min(3)]);
+            millis = number(&padded)?;
+            rest = &fraction[digits..];
+        }
+        let offset = match rest {
+            "Z" | "z" => 0,
+            _ => {
+                let sign = match rest.get(..1) {
+                    Some("+") => 1,
+                    Some("-") => -1,
+                    _ => return Err(ParseTimestampError),
+                };
+                if rest.len() != 6 || rest.get(3..4) != Some(":") {
+                    return Err(ParseTimestampError);
+                }
+                sign * (number(&rest[1..3])? * 60 + number(&rest[4..6])?)
+            }
+        };
+        let secs = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second
+            - offset * 60;
+        if secs < 0 {
+            return Err(ParseTimestampError);
+        }
+        Ok(Self(secs as u64 * 1000 + millis as u64))
+    }
+}
+
+/// The error given when a string could not be parsed as a [`Timestamp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseTimestampError;
+
+impl Display for ParseTimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the string provided is not a valid ISO8601 timestamp after 1970")
+    }
+}
+
+impl StdError for ParseTimestampError {}
+
+// http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "An ISO8601 timestamp, or an integer amount of unix milliseconds"
+        )
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        s.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, i: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Timestamp(i))
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(TimestampVisitor)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::Timestamp;
+
+    const DISCORD_EPOCH: Timestamp = Timestamp::new(1420070400000);
+
+    #[test]
+    fn display() {
+        assert_eq!(DISCORD_EPOCH.to_string(), "2015-01-01T00:00:00.000+00:00");
+        assert_eq!(
+            Timestamp::new(0).to_string(),
+            "1970-01-01T00:00:00.000+00:00"
+        );
+        assert_eq!(
+            Timestamp::new(951782400001).to_string(),
+            "2000-02-29T00:00:00.001+00:00"
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("2015-01-01T00:00:00+00:00".parse(), Ok(DISCORD_EPOCH));
+        assert_eq!(
+            "2015-01-01T00:00:00.000000+00:00".parse(),
+            Ok(DISCORD_EPOCH)
+        );
+        assert_eq!("2015-01-01T01:30:00+01:30".parse(), Ok(DISCORD_EPOCH));
+        assert_eq!(
+            "2018-05-14T12:02:01.3021Z".parse(),
+            Ok(Timestamp::new(1526299321302))
+        );
+        assert!("2015-01-01".parse::<Timestamp>().is_err());
+        assert!("2015-13-01T00:00:00Z".parse::<Timestamp>().is_err());
+        assert!("1969-12-31T23:59:59Z".parse::<Timestamp>().is_err());
+        assert!("2015-01-01T00:00:00+0100".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for millis in [
+            0,
+            1420070400000,
+            1526299321302,
+            4102444799999,
+            5818116911103,
+        ] {
+            let timestamp = Timestamp::new(millis);
+            assert_eq!(timestamp.to_string().parse(), Ok(timestamp));
+        }
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            DISCORD_EPOCH + Duration::from_secs(1),
+            Timestamp::new(1420070401000)
+        );
+        assert_eq!(DISCORD_EPOCH.checked_add(Duration::MAX), None);
+        assert_eq!(DISCORD_EPOCH + Duration::MAX, Timestamp::new(u64::MAX));
+        assert_eq!(
+            Timestamp::new(u64::MAX - 1).checked_add(Duration::from_millis(2)),
+            None
+        );
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(
+            serde_json::to_string(&DISCORD_EPOCH).unwrap(),
+            "\"2015-01-01T00:00:00.000+00:00\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Timestamp>("\"2015-01-01T00:00:00.000000+00:00\"").unwrap(),
+            DISCORD_EPOCH
+        );
+        assert_eq!(
+            serde_json::from_str::<Timestamp>("1420070400000").unwrap(),
+            DISCORD_EPOCH
+        );
+    }
+}
@@ -77,6 +77,14 @@ impl<'de> Visitor<'de> for U32Visitor {
     {
         Ok(i)
     }
+
+    // json integers are always given as 64 bits
+    fn visit_u64<E>(self, i: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        u32::try_from(i).map_err(|_| E::custom(format!("integer too large: {}", i)))
+    }
 }
 
 pub fn deserialize_u64<'de, D>(d: D) -> Result<u64, D::Error>
@@ -112,3 +120,26 @@ where
     // since this is always less than 2**53, we can just serialize it as an integer
     s.serialize_u32(*i)
 }
+
+pub fn deserialize_discriminator<'de, D>(d: D) -> Result<u16, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // a string like "0001", which can fit in a u64 visitor
+    let i = d.deserialize_any(U64Visitor)?;
+    u16::try_from(i).map_err(|_| serde::de::Error::custom(format!("invalid discriminator: {}", i)))
+}
+
+pub fn serialize_discriminator<S>(i: &u16, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.serialize_str(&format!("{:04}", i))
+}
+
+pub fn serialize_u64_str<S>(i: &u64, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.collect_str(i)
+}